@@ -9,7 +9,6 @@ param(
 )
 
 $artifactPath = Join-Path -Path $PSScriptRoot -ChildPath "target/$($Configuration.ToLower())/vscode-configurator"
-$templatesArtifactPath = Join-Path -Path $PSScriptRoot -ChildPath "target/$($Configuration.ToLower())/templates"
 $installPath = Join-Path -Path ([System.Environment]::GetFolderPath([System.Environment+SpecialFolder]::UserProfile)) -ChildPath ".vscodeconfigurator/bin/"
 
 if (!(Test-Path -Path $artifactPath)) {
@@ -30,6 +29,3 @@ if (!(Test-Path -Path $installPath)) {
 
 Write-Verbose "Copying 'vscode-configurator' to: $($installPath)"
 Copy-Item -Path $artifactPath -Destination $installPath -Force -Verbose:$false
-
-Write-Verbose "Copying templates to: $($installPath)"
-Copy-Item -Path $templatesArtifactPath -Destination $installPath -Recurse -Force -Verbose:$false
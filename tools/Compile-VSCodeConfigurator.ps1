@@ -99,7 +99,6 @@ $null = New-Item -Path $targetArtifactsPath -ItemType "Directory"
 
 $compiledOutputPath = Join-Path -Path $resolvedRootDirectory -ChildPath "target/$($selectedCompilationTarget.TargetName)/release"
 
-$compiledTemplatesDirectoryPath = Join-Path -Path $compiledOutputPath -ChildPath "templates"
 $compiledBinaryPath = switch ($selectedCompilationTarget.Platform) {
     "Windows" {
         Join-Path -Path $compiledOutputPath -ChildPath "vscode-configurator.exe"
@@ -112,6 +111,5 @@ $compiledBinaryPath = switch ($selectedCompilationTarget.Platform) {
     }
 }
 
-Copy-Item -Path $compiledTemplatesDirectoryPath -Destination $targetArtifactsPath -Recurse -Force
 Copy-Item -Path $compiledBinaryPath -Destination $targetArtifactsPath -Force
 
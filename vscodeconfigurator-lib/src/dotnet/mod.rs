@@ -0,0 +1,3 @@
+pub mod msbuild;
+pub mod solution;
+mod xml;
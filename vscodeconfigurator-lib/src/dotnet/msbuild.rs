@@ -0,0 +1,621 @@
+use std::{fs, path::Path};
+
+use super::xml::{self, XmlElement, XmlTag, XmlTagKind};
+use crate::error::{CliError, CliErrorKind};
+
+/// The type of an MSBuild item that can be edited in a project or props
+/// file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MsBuildItemType {
+    /// A NuGet package that a project uses (`<PackageReference>`).
+    PackageReference,
+
+    /// The version of a NuGet package for central package management, in
+    /// `Directory.Packages.props` (`<PackageVersion>`).
+    PackageVersion,
+
+    /// Another project that a project uses (`<ProjectReference>`).
+    ProjectReference
+}
+
+impl MsBuildItemType {
+    /// Gets the name of the item's element.
+    pub fn element_name(&self) -> &'static str {
+        match self {
+            Self::PackageReference => "PackageReference",
+            Self::PackageVersion => "PackageVersion",
+            Self::ProjectReference => "ProjectReference"
+        }
+    }
+}
+
+/// An MSBuild item in a project or props file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MsBuildItem {
+    /// The `Include` attribute of the item, which is the package ID or the
+    /// path to the project.
+    pub include: String,
+
+    /// The version of the item, from its `Version` attribute or its
+    /// `<Version>` element.
+    pub version: Option<String>
+}
+
+/// An MSBuild project or props file (e.g. a `.csproj` file or
+/// `Directory.Build.props`).
+///
+/// The original text is kept, so editing a property or an item only changes
+/// the text of that property or item. Comments, whitespace and line endings
+/// are left as they were.
+///
+/// Only properties and items outside of conditions are read and edited, since
+/// the value of a conditional one depends on how the project is built.
+#[derive(Debug, Clone)]
+pub struct MsBuildFile {
+    /// The text of the file.
+    text: String,
+
+    /// The tags of the file.
+    tags: Vec<XmlTag>,
+
+    /// The elements of the file. The first one is the `<Project>` element.
+    elements: Vec<XmlElement>
+}
+
+impl MsBuildFile {
+    /// Parses an MSBuild project or props file.
+    ///
+    /// # Arguments
+    ///
+    /// - `text` - The text of the file.
+    pub fn parse(text: &str) -> Result<Self, CliError> {
+        let mut file = Self {
+            text: String::new(),
+            tags: Vec::new(),
+            elements: Vec::new()
+        };
+
+        file.set_text(text.to_string())?;
+
+        Ok(file)
+    }
+
+    /// Reads and parses an MSBuild project or props file.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_path` - The path to the file.
+    pub fn read(file_path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let text = fs::read_to_string(file_path)?;
+
+        Self::parse(&text).map_err(|e| e.in_file(&file_path.to_string_lossy()).into())
+    }
+
+    /// Gets the text of the file.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the SDK of the project, without its version (e.g.
+    /// `Microsoft.NET.Sdk.Web`).
+    pub fn sdk(&self) -> Option<String> {
+        self.tags[self.elements[0].tag_index]
+            .attribute("Sdk")
+            .map(|sdk| sdk.split('/').next().unwrap_or_default().trim().to_string())
+    }
+
+    /// Gets the value of a property (e.g. `Nullable`).
+    ///
+    /// If the property is set more than once, the last value is used, like
+    /// MSBuild does.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the property.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::msbuild::MsBuildFile;
+    ///
+    /// let project = MsBuildFile::parse(
+    ///     r#"<Project Sdk="Microsoft.NET.Sdk">
+    ///   <PropertyGroup>
+    ///     <TargetFramework>net9.0</TargetFramework>
+    ///     <Nullable>enable</Nullable>
+    ///   </PropertyGroup>
+    ///   <PropertyGroup Condition="'$(Configuration)' == 'Release'">
+    ///     <Nullable>disable</Nullable>
+    ///   </PropertyGroup>
+    /// </Project>
+    /// "#
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(project.property("TargetFramework").as_deref(), Some("net9.0"));
+    /// assert_eq!(project.property("Nullable").as_deref(), Some("enable"));
+    /// assert_eq!(project.property("LangVersion"), None);
+    /// ```
+    pub fn property(
+        &self,
+        name: &str
+    ) -> Option<String> {
+        self.find_property(name).map(|property| {
+            let content_span = self.elements[property].content_span.clone();
+
+            xml::unescape(self.text[content_span].trim())
+        })
+    }
+
+    /// Sets the value of a property (e.g. `TreatWarningsAsErrors`).
+    ///
+    /// If the property is set more than once, the last value is changed. A
+    /// property that isn't set is added to the first `<PropertyGroup>`, which
+    /// is added if there isn't one. Returns `false` if the property already
+    /// has the value.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the property.
+    /// - `value` - The value to set.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::msbuild::MsBuildFile;
+    ///
+    /// let mut project = MsBuildFile::parse(
+    ///     "<Project Sdk=\"Microsoft.NET.Sdk\">\n\n  <PropertyGroup>\n    <LangVersion>12</LangVersion>\n  </PropertyGroup>\n\n</Project>\n"
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(project.set_property("LangVersion", "latest").unwrap());
+    /// assert!(project.set_property("TreatWarningsAsErrors", "true").unwrap());
+    /// assert!(!project.set_property("TreatWarningsAsErrors", "true").unwrap());
+    ///
+    /// assert_eq!(
+    ///     project.text(),
+    ///     "<Project Sdk=\"Microsoft.NET.Sdk\">\n\n  <PropertyGroup>\n    <LangVersion>latest</LangVersion>\n    <TreatWarningsAsErrors>true</TreatWarningsAsErrors>\n  </PropertyGroup>\n\n</Project>\n"
+    /// );
+    /// ```
+    pub fn set_property(
+        &mut self,
+        name: &str,
+        value: &str
+    ) -> Result<bool, CliError> {
+        if let Some(property) = self.find_property(name) {
+            if self.property(name).as_deref() == Some(value) {
+                return Ok(false);
+            }
+
+            let element = &self.elements[property];
+
+            let mut text = self.text.clone();
+
+            match self.tags[element.tag_index].kind {
+                XmlTagKind::Empty => text.replace_range(
+                    element.span.clone(),
+                    &format!("<{}>{}</{}>", name, xml::escape(value), name)
+                ),
+                _ => text.replace_range(element.content_span.clone(), &xml::escape(value))
+            }
+
+            self.set_text(text)?;
+
+            return Ok(true);
+        }
+
+        let property_group = match self.groups("PropertyGroup").first() {
+            Some(property_group) => *property_group,
+            None => self.add_group("PropertyGroup")?
+        };
+
+        let (text, _) = xml::insert_element(
+            &self.text,
+            &self.tags,
+            &self.elements,
+            property_group,
+            None,
+            &format!("<{}>{}</{}>", name, xml::escape(value), name)
+        );
+
+        self.set_text(text)?;
+
+        Ok(true)
+    }
+
+    /// Removes a property, wherever it's set. A `<PropertyGroup>` that's left
+    /// empty is removed too.
+    ///
+    /// Returns `false` if the property isn't set.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the property.
+    pub fn remove_property(
+        &mut self,
+        name: &str
+    ) -> Result<bool, CliError> {
+        let mut is_removed = false;
+
+        while let Some(property) = self.find_property(name) {
+            self.remove_child(property)?;
+            is_removed = true;
+        }
+
+        Ok(is_removed)
+    }
+
+    /// Gets the items of a type, in the order they're listed.
+    ///
+    /// # Arguments
+    ///
+    /// - `item_type` - The type of the items.
+    pub fn items(
+        &self,
+        item_type: MsBuildItemType
+    ) -> Vec<MsBuildItem> {
+        self.item_elements(item_type)
+            .into_iter()
+            .filter_map(|item| {
+                Some(MsBuildItem {
+                    include: self.tags[self.elements[item].tag_index]
+                        .attribute("Include")?
+                        .to_string(),
+                    version: self.item_version(item)
+                })
+            })
+            .collect()
+    }
+
+    /// Adds an item, or changes the version of an item that's already there.
+    ///
+    /// A new item is added after the other items of its type, or in a new
+    /// `<ItemGroup>` if there aren't any. Package IDs are matched without
+    /// case, and project paths without the kind of separators. Returns
+    /// `false` if the item is already there with the version.
+    ///
+    /// # Arguments
+    ///
+    /// - `item_type` - The type of the item.
+    /// - `include` - The package ID or the path to the project.
+    /// - `version` - The version of the package, if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::msbuild::{MsBuildFile, MsBuildItemType};
+    ///
+    /// let mut packages_props = MsBuildFile::parse(
+    ///     "<Project>\n  <ItemGroup>\n    <PackageVersion Include=\"Serilog\" Version=\"3.1.1\" />\n  </ItemGroup>\n</Project>\n"
+    /// )
+    /// .unwrap();
+    ///
+    /// packages_props
+    ///     .add_item(MsBuildItemType::PackageVersion, "serilog", Some("4.0.0"))
+    ///     .unwrap();
+    /// packages_props
+    ///     .add_item(MsBuildItemType::PackageVersion, "xunit", Some("2.9.0"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     packages_props.text(),
+    ///     "<Project>\n  <ItemGroup>\n    <PackageVersion Include=\"Serilog\" Version=\"4.0.0\" />\n    <PackageVersion Include=\"xunit\" Version=\"2.9.0\" />\n  </ItemGroup>\n</Project>\n"
+    /// );
+    /// ```
+    pub fn add_item(
+        &mut self,
+        item_type: MsBuildItemType,
+        include: &str,
+        version: Option<&str>
+    ) -> Result<bool, CliError> {
+        if let Some(item) = self.find_item(item_type, include) {
+            let Some(version) = version else {
+                return Ok(false);
+            };
+
+            if self.item_version(item).as_deref() == Some(version) {
+                return Ok(false);
+            }
+
+            let tag = &self.tags[self.elements[item].tag_index];
+            let version_element = self.children(item).into_iter().find(|child| {
+                self.tags[self.elements[*child].tag_index].name == "Version"
+            });
+
+            let mut text = self.text.clone();
+
+            match (
+                tag.attributes
+                    .iter()
+                    .find(|attribute| attribute.name == "Version"),
+                version_element
+            ) {
+                (Some(version_attribute), _) => {
+                    text.replace_range(version_attribute.value_span.clone(), &xml::escape(version))
+                }
+                (None, Some(version_element)) => text.replace_range(
+                    self.elements[version_element].content_span.clone(),
+                    &xml::escape(version)
+                ),
+                (None, None) => {
+                    // The item is found by its `Include` attribute, so it has one.
+                    let include_end = tag
+                        .attributes
+                        .iter()
+                        .find(|attribute| attribute.name == "Include")
+                        .map(|attribute| attribute.value_span.end + 1)
+                        .unwrap_or_default();
+
+                    text.insert_str(include_end, &format!(" Version=\"{}\"", xml::escape(version)))
+                }
+            }
+
+            self.set_text(text)?;
+
+            return Ok(true);
+        }
+
+        let item_element = match version {
+            Some(version) => format!(
+                "<{} Include=\"{}\" Version=\"{}\" />",
+                item_type.element_name(),
+                xml::escape(include),
+                xml::escape(version)
+            ),
+            None => format!(
+                "<{} Include=\"{}\" />",
+                item_type.element_name(),
+                xml::escape(include)
+            )
+        };
+
+        let last_item = self.item_elements(item_type).last().copied();
+
+        let (item_group, after) = match last_item {
+            Some(last_item) => (
+                self.elements[last_item].parent.unwrap_or_default(),
+                Some(last_item)
+            ),
+            None => (self.add_group("ItemGroup")?, None)
+        };
+
+        let (text, _) = xml::insert_element(
+            &self.text,
+            &self.tags,
+            &self.elements,
+            item_group,
+            after,
+            &item_element
+        );
+
+        self.set_text(text)?;
+
+        Ok(true)
+    }
+
+    /// Removes an item. An `<ItemGroup>` that's left empty is removed too.
+    ///
+    /// Returns `false` if the item isn't there.
+    ///
+    /// # Arguments
+    ///
+    /// - `item_type` - The type of the item.
+    /// - `include` - The package ID or the path to the project.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Adding a reference and removing it again leaves the file as it was.
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::msbuild::{MsBuildFile, MsBuildItemType};
+    ///
+    /// let text = "<Project Sdk=\"Microsoft.NET.Sdk\">\r\n\r\n  <PropertyGroup>\r\n    <TargetFramework>net9.0</TargetFramework>\r\n  </PropertyGroup>\r\n\r\n</Project>\r\n";
+    ///
+    /// let mut project = MsBuildFile::parse(text).unwrap();
+    ///
+    /// project
+    ///     .add_item(MsBuildItemType::ProjectReference, "..\\ClassLib\\ClassLib.csproj", None)
+    ///     .unwrap();
+    ///
+    /// assert!(project.text().contains("\r\n\r\n  <ItemGroup>\r\n    <ProjectReference Include=\"..\\ClassLib\\ClassLib.csproj\" />\r\n  </ItemGroup>\r\n\r\n</Project>"));
+    ///
+    /// assert!(project
+    ///     .remove_item(MsBuildItemType::ProjectReference, "../ClassLib/ClassLib.csproj")
+    ///     .unwrap());
+    ///
+    /// assert_eq!(project.text(), text);
+    /// ```
+    pub fn remove_item(
+        &mut self,
+        item_type: MsBuildItemType,
+        include: &str
+    ) -> Result<bool, CliError> {
+        let Some(item) = self.find_item(item_type, include) else {
+            return Ok(false);
+        };
+
+        self.remove_child(item)?;
+
+        Ok(true)
+    }
+
+    /// Replaces the text of the file, parsing it again.
+    fn set_text(
+        &mut self,
+        text: String
+    ) -> Result<(), CliError> {
+        let tags = xml::scan_tags(&text)?;
+        let elements = xml::match_elements(&text, &tags)?;
+
+        if elements
+            .first()
+            .is_none_or(|root| tags[root.tag_index].name != "Project")
+        {
+            return Err(CliError::new(
+                "The root element isn't '<Project>'.",
+                CliErrorKind::InvalidProjectFile
+            ));
+        }
+
+        self.text = text;
+        self.tags = tags;
+        self.elements = elements;
+
+        Ok(())
+    }
+
+    /// Gets the indexes of the children of an element.
+    fn children(
+        &self,
+        parent: usize
+    ) -> Vec<usize> {
+        (parent + 1..self.elements.len())
+            .filter(|index| self.elements[*index].parent == Some(parent))
+            .collect()
+    }
+
+    /// Checks whether an element has a name and no `Condition` attribute.
+    fn is_unconditional(
+        &self,
+        element: usize,
+        name: &str
+    ) -> bool {
+        let tag = &self.tags[self.elements[element].tag_index];
+
+        tag.name == name && tag.attribute("Condition").is_none()
+    }
+
+    /// Gets the indexes of the groups (e.g. `<PropertyGroup>`) in the
+    /// `<Project>` element that don't have a condition.
+    fn groups(
+        &self,
+        group_name: &str
+    ) -> Vec<usize> {
+        self.children(0)
+            .into_iter()
+            .filter(|group| self.is_unconditional(*group, group_name))
+            .collect()
+    }
+
+    /// Finds the last element that sets a property.
+    fn find_property(
+        &self,
+        name: &str
+    ) -> Option<usize> {
+        self.groups("PropertyGroup")
+            .into_iter()
+            .flat_map(|property_group| self.children(property_group))
+            .rfind(|property| self.is_unconditional(*property, name))
+    }
+
+    /// Gets the indexes of the elements of the items of a type.
+    fn item_elements(
+        &self,
+        item_type: MsBuildItemType
+    ) -> Vec<usize> {
+        self.groups("ItemGroup")
+            .into_iter()
+            .flat_map(|item_group| self.children(item_group))
+            .filter(|item| self.is_unconditional(*item, item_type.element_name()))
+            .collect()
+    }
+
+    /// Finds the element of an item by its `Include` attribute.
+    fn find_item(
+        &self,
+        item_type: MsBuildItemType,
+        include: &str
+    ) -> Option<usize> {
+        let include = include.replace('\\', "/");
+
+        self.item_elements(item_type).into_iter().find(|item| {
+            self.tags[self.elements[*item].tag_index]
+                .attribute("Include")
+                .is_some_and(|item_include| {
+                    item_include
+                        .replace('\\', "/")
+                        .eq_ignore_ascii_case(&include)
+                })
+        })
+    }
+
+    /// Gets the version of an item, from its `Version` attribute or its
+    /// `<Version>` element.
+    fn item_version(
+        &self,
+        item: usize
+    ) -> Option<String> {
+        let tag = &self.tags[self.elements[item].tag_index];
+
+        tag.attribute("Version")
+            .map(|version| version.to_string())
+            .or_else(|| {
+                self.children(item)
+                    .into_iter()
+                    .find(|child| self.tags[self.elements[*child].tag_index].name == "Version")
+                    .map(|child| {
+                        xml::unescape(self.text[self.elements[child].content_span.clone()].trim())
+                    })
+            })
+    }
+
+    /// Adds an empty group (e.g. `<ItemGroup />`) to the `<Project>` element,
+    /// after the last group with the same name, and returns its index.
+    fn add_group(
+        &mut self,
+        group_name: &str
+    ) -> Result<usize, CliError> {
+        let last_group = self
+            .children(0)
+            .into_iter()
+            .rfind(|group| self.tags[self.elements[*group].tag_index].name == group_name);
+
+        let (text, group_start) = xml::insert_element(
+            &self.text,
+            &self.tags,
+            &self.elements,
+            0,
+            last_group,
+            &format!("<{} />", group_name)
+        );
+
+        self.set_text(text)?;
+
+        Ok(self
+            .elements
+            .iter()
+            .position(|element| element.span.start == group_start)
+            .unwrap_or_default())
+    }
+
+    /// Removes an element from its group, along with the group if nothing
+    /// else is left in it.
+    fn remove_child(
+        &mut self,
+        child: usize
+    ) -> Result<(), CliError> {
+        let child_span = self.elements[child].span.clone();
+        let group = self.elements[child].parent.unwrap_or_default();
+        let group_content_span = self.elements[group].content_span.clone();
+
+        let is_group_left_empty = group != 0
+            && self.text[group_content_span.start..child_span.start].trim().is_empty()
+            && self.text[child_span.end..group_content_span.end].trim().is_empty();
+
+        let removed_span = match is_group_left_empty {
+            true => self.elements[group].span.clone(),
+            false => child_span
+        };
+
+        self.set_text(xml::remove_element(&self.text, removed_span))
+    }
+}
@@ -0,0 +1,1007 @@
+use std::{
+    cmp::Reverse,
+    collections::hash_map::RandomState,
+    fmt,
+    fs,
+    hash::{BuildHasher, Hasher},
+    ops::Range,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use super::xml;
+use crate::error::{CliError, CliErrorKind};
+
+/// The type GUID of solution folders in a `.sln` file.
+pub const SOLUTION_FOLDER_TYPE_GUID: &str = "{2150E333-8FDC-42A3-9474-1A3956D46DE8}";
+
+/// The type GUIDs that `dotnet sln add` gives projects in a `.sln` file, by
+/// the extension of the project file. Other projects get the C# one.
+const PROJECT_TYPE_GUIDS: [(&str, &str); 3] = [
+    ("csproj", "{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}"),
+    ("fsproj", "{F2A71F9B-5D33-465A-A702-920D77279786}"),
+    ("vbproj", "{F184B08F-C81C-45F6-A57F-5ABD9991F28F}")
+];
+
+/// The configurations of a `.slnx` file that doesn't list any.
+const DEFAULT_BUILD_TYPES: [&str; 2] = ["Debug", "Release"];
+
+/// The platform of a `.slnx` file that doesn't list any.
+const DEFAULT_PLATFORM: &str = "Any CPU";
+
+/// The format of a solution file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolutionFormat {
+    /// The classic text format of `.sln` files.
+    Sln,
+
+    /// The XML format of `.slnx` files.
+    Slnx
+}
+
+impl SolutionFormat {
+    /// Gets the format of a solution file from its extension.
+    ///
+    /// Returns `None` if the file isn't a `.sln` or `.slnx` file.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_path` - The path to the solution file.
+    pub fn from_path(file_path: &Path) -> Option<Self> {
+        match file_path.extension()?.to_str()? {
+            "sln" => Some(Self::Sln),
+            "slnx" => Some(Self::Slnx),
+            _ => None
+        }
+    }
+}
+
+/// A project in a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionProject {
+    /// The name of the project.
+    pub name: String,
+
+    /// The path to the project file, relative to the solution's directory and
+    /// separated with `/`.
+    pub path: String,
+
+    /// The GUID of the project's type, if the solution has one for it.
+    pub type_guid: Option<String>,
+
+    /// The GUID of the project, if the solution has one for it.
+    pub guid: Option<String>,
+
+    /// The path of the solution folder the project is in (e.g. `/src/`), if
+    /// it's in one.
+    pub folder: Option<String>
+}
+
+/// A solution folder, which groups projects in a solution without being a
+/// directory on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionFolder {
+    /// The name of the folder.
+    pub name: String,
+
+    /// The path of the folder, with the folders it's in (e.g. `/src/tests/`).
+    pub path: String,
+
+    /// The GUID of the folder, if the solution has one for it.
+    pub guid: Option<String>
+}
+
+/// A configuration that a solution can be built in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionConfiguration {
+    /// The build type (e.g. `Debug`).
+    pub build_type: String,
+
+    /// The platform (e.g. `Any CPU`).
+    pub platform: String
+}
+
+impl fmt::Display for SolutionConfiguration {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>
+    ) -> fmt::Result {
+        write!(f, "{}|{}", self.build_type, self.platform)
+    }
+}
+
+/// A `.sln` or `.slnx` solution file.
+///
+/// The original text is kept, so adding or removing a project only changes
+/// the lines for that project. Everything else, including line endings and
+/// the byte order mark, is left as it was.
+#[derive(Debug, Clone)]
+pub struct SolutionFile {
+    /// The format of the solution file.
+    format: SolutionFormat,
+
+    /// The text of the solution file.
+    text: String,
+
+    /// The contents of the solution file, parsed from the text.
+    contents: SolutionContents
+}
+
+impl SolutionFile {
+    /// Parses a solution file.
+    ///
+    /// # Arguments
+    ///
+    /// - `text` - The text of the solution file.
+    /// - `format` - The format of the solution file.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::solution::{SolutionFile, SolutionFormat};
+    ///
+    /// let solution = SolutionFile::parse(
+    ///     r#"<Solution>
+    ///   <Folder Name="/src/">
+    ///     <Project Path="src/ConsoleApp/ConsoleApp.csproj" />
+    ///   </Folder>
+    /// </Solution>
+    /// "#,
+    ///     SolutionFormat::Slnx
+    /// )
+    /// .unwrap();
+    ///
+    /// let projects = solution.projects();
+    ///
+    /// assert_eq!(projects[0].name, "ConsoleApp");
+    /// assert_eq!(projects[0].path, "src/ConsoleApp/ConsoleApp.csproj");
+    /// assert_eq!(projects[0].folder.as_deref(), Some("/src/"));
+    /// assert_eq!(solution.configurations()[0].to_string(), "Debug|Any CPU");
+    /// ```
+    pub fn parse(
+        text: &str,
+        format: SolutionFormat
+    ) -> Result<Self, CliError> {
+        let contents = match format {
+            SolutionFormat::Sln => parse_sln(text)?,
+            SolutionFormat::Slnx => parse_slnx(text)?
+        };
+
+        Ok(Self {
+            format,
+            text: text.to_string(),
+            contents
+        })
+    }
+
+    /// Reads and parses a solution file.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_path` - The path to the `.sln` or `.slnx` file.
+    pub fn read(file_path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let format = SolutionFormat::from_path(file_path).ok_or_else(|| {
+            CliError::new(
+                "The file isn't a '.sln' or '.slnx' solution file.",
+                CliErrorKind::InvalidSolutionFile
+            )
+            .in_file(&file_path.to_string_lossy())
+        })?;
+
+        let text = fs::read_to_string(file_path)?;
+
+        Self::parse(&text, format).map_err(|e| e.in_file(&file_path.to_string_lossy()).into())
+    }
+
+    /// Gets the format of the solution file.
+    pub fn format(&self) -> SolutionFormat {
+        self.format
+    }
+
+    /// Gets the text of the solution file.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the projects in the solution, in the order they're listed.
+    pub fn projects(&self) -> Vec<SolutionProject> {
+        self.contents
+            .projects
+            .iter()
+            .map(|(project, _)| project.clone())
+            .collect()
+    }
+
+    /// Gets the project with a path in the solution.
+    ///
+    /// The separators and a leading `./` in the path don't matter.
+    ///
+    /// # Arguments
+    ///
+    /// - `project_path` - The path to the project file, relative to the
+    ///   solution's directory.
+    pub fn project(
+        &self,
+        project_path: &str
+    ) -> Option<SolutionProject> {
+        self.project_index(project_path)
+            .map(|index| self.contents.projects[index].0.clone())
+    }
+
+    /// Gets the solution folders, in the order they're listed.
+    pub fn folders(&self) -> Vec<SolutionFolder> {
+        self.contents.folders.clone()
+    }
+
+    /// Gets the configurations the solution can be built in.
+    pub fn configurations(&self) -> Vec<SolutionConfiguration> {
+        self.contents.configurations.clone()
+    }
+
+    /// Adds a project to the solution, outside of any solution folder.
+    ///
+    /// In a `.sln` file, the project gets a new GUID and is mapped to each of
+    /// the solution's configurations. A `.csproj`, `.fsproj` or `.vbproj`
+    /// project is mapped to the `Any CPU` platform, like `dotnet sln add`
+    /// does. Returns `false` if the project is already in the solution.
+    ///
+    /// # Arguments
+    ///
+    /// - `project_path` - The path to the project file, relative to the
+    ///   solution's directory.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::solution::{SolutionFile, SolutionFormat};
+    ///
+    /// let mut solution = SolutionFile::parse(
+    ///     "<Solution>\n  <Project Path=\"ConsoleApp/ConsoleApp.csproj\" />\n</Solution>\n",
+    ///     SolutionFormat::Slnx
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(solution.add_project("ClassLib/ClassLib.csproj").unwrap());
+    /// assert!(!solution.add_project("./ClassLib/ClassLib.csproj").unwrap());
+    ///
+    /// assert_eq!(
+    ///     solution.text(),
+    ///     "<Solution>\n  <Project Path=\"ConsoleApp/ConsoleApp.csproj\" />\n  <Project Path=\"ClassLib/ClassLib.csproj\" />\n</Solution>\n"
+    /// );
+    /// ```
+    ///
+    /// ## Example 02
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::solution::{SolutionFile, SolutionFormat};
+    ///
+    /// let mut solution = SolutionFile::parse(
+    ///     "Microsoft Visual Studio Solution File, Format Version 12.00\r\n\
+    ///     Global\r\n\
+    ///     \tGlobalSection(SolutionConfigurationPlatforms) = preSolution\r\n\
+    ///     \t\tDebug|x64 = Debug|x64\r\n\
+    ///     \tEndGlobalSection\r\n\
+    ///     EndGlobal\r\n",
+    ///     SolutionFormat::Sln
+    /// )
+    /// .unwrap();
+    ///
+    /// solution.add_project("ConsoleApp/ConsoleApp.csproj").unwrap();
+    ///
+    /// assert!(solution.text().contains(".Debug|x64.ActiveCfg = Debug|Any CPU\r\n"));
+    /// assert!(solution.text().contains(".Debug|x64.Build.0 = Debug|Any CPU\r\n"));
+    /// ```
+    pub fn add_project(
+        &mut self,
+        project_path: &str
+    ) -> Result<bool, CliError> {
+        if self.project_index(project_path).is_some() {
+            return Ok(false);
+        }
+
+        let project_path = normalize_path(project_path);
+
+        let text = match self.format {
+            SolutionFormat::Sln => self.sln_with_project(&project_path),
+            SolutionFormat::Slnx => self.slnx_with_project(&project_path)?
+        };
+
+        self.set_text(text)?;
+
+        Ok(true)
+    }
+
+    /// Removes a project from the solution.
+    ///
+    /// In a `.sln` file, the project's configuration mappings and its entry in
+    /// a solution folder are removed too. Returns `false` if the project isn't
+    /// in the solution.
+    ///
+    /// # Arguments
+    ///
+    /// - `project_path` - The path to the project file, relative to the
+    ///   solution's directory.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Adding a project and removing it again leaves the file as it was.
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::solution::{SolutionFile, SolutionFormat};
+    ///
+    /// let text = "\u{feff}\r\nMicrosoft Visual Studio Solution File, Format Version 12.00\r\n\
+    ///     Global\r\n\
+    ///     \tGlobalSection(SolutionConfigurationPlatforms) = preSolution\r\n\
+    ///     \t\tDebug|Any CPU = Debug|Any CPU\r\n\
+    ///     \tEndGlobalSection\r\n\
+    ///     EndGlobal\r\n";
+    ///
+    /// let mut solution = SolutionFile::parse(text, SolutionFormat::Sln).unwrap();
+    ///
+    /// solution.add_project("ConsoleApp\\ConsoleApp.csproj").unwrap();
+    ///
+    /// assert_eq!(solution.projects()[0].path, "ConsoleApp/ConsoleApp.csproj");
+    /// assert!(solution.text().contains(".Debug|Any CPU.Build.0 = Debug|Any CPU\r\n"));
+    ///
+    /// assert!(solution.remove_project("ConsoleApp/ConsoleApp.csproj").unwrap());
+    /// assert!(!solution.remove_project("ConsoleApp/ConsoleApp.csproj").unwrap());
+    ///
+    /// assert_eq!(solution.text(), text);
+    /// ```
+    ///
+    /// ## Example 02
+    ///
+    /// Sections that were already empty are kept.
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::dotnet::solution::{SolutionFile, SolutionFormat};
+    ///
+    /// let mut solution = SolutionFile::parse(
+    ///     "Microsoft Visual Studio Solution File, Format Version 12.00\r\n\
+    ///     Project(\"{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}\") = \"App\", \"App\\App.csproj\", \"{11111111-1111-1111-1111-111111111111}\"\r\n\
+    ///     EndProject\r\n\
+    ///     Global\r\n\
+    ///     \tGlobalSection(SolutionProperties) = preSolution\r\n\
+    ///     \tEndGlobalSection\r\n\
+    ///     EndGlobal\r\n",
+    ///     SolutionFormat::Sln
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(solution.remove_project("App/App.csproj").unwrap());
+    ///
+    /// assert!(solution.text().contains("\tGlobalSection(SolutionProperties) = preSolution\r\n"));
+    /// ```
+    pub fn remove_project(
+        &mut self,
+        project_path: &str
+    ) -> Result<bool, CliError> {
+        let Some(index) = self.project_index(project_path) else {
+            return Ok(false);
+        };
+
+        let (project, span) = self.contents.projects[index].clone();
+
+        let text = match self.format {
+            SolutionFormat::Sln => self.sln_without_project(&project, span),
+            SolutionFormat::Slnx => xml::remove_element(&self.text, span)
+        };
+
+        self.set_text(text)?;
+
+        Ok(true)
+    }
+
+    /// Gets the index of the project with a path.
+    fn project_index(
+        &self,
+        project_path: &str
+    ) -> Option<usize> {
+        let project_path = normalize_path(project_path);
+
+        self.contents
+            .projects
+            .iter()
+            .position(|(project, _)| project.path == project_path)
+    }
+
+    /// Replaces the text of the solution file, parsing it again.
+    fn set_text(
+        &mut self,
+        text: String
+    ) -> Result<(), CliError> {
+        self.contents = match self.format {
+            SolutionFormat::Sln => parse_sln(&text)?,
+            SolutionFormat::Slnx => parse_slnx(&text)?
+        };
+        self.text = text;
+
+        Ok(())
+    }
+
+    /// Gets the text of a `.sln` file with a project added after the last
+    /// project, and mapped to each of the solution's configurations.
+    fn sln_with_project(
+        &self,
+        project_path: &str
+    ) -> String {
+        let line_ending = xml::line_ending(&self.text);
+
+        let extension = project_path.rsplit_once('.').map(|(_, extension)| extension);
+        let project_type = PROJECT_TYPE_GUIDS
+            .iter()
+            .find(|(project_extension, _)| Some(*project_extension) == extension);
+        let type_guid = project_type
+            .map(|(_, type_guid)| *type_guid)
+            .unwrap_or(PROJECT_TYPE_GUIDS[0].1);
+
+        let guid = new_guid();
+
+        let project_block = format!(
+            "Project(\"{}\") = \"{}\", \"{}\", \"{}\"{}EndProject{}",
+            type_guid,
+            project_name(project_path),
+            project_path.replace('/', "\\"),
+            guid,
+            line_ending,
+            line_ending
+        );
+
+        let lines = split_lines(&self.text);
+
+        let project_insert_position = self
+            .contents
+            .projects
+            .iter()
+            .map(|(_, span)| span.end)
+            .chain(self.contents.folder_spans.iter().map(|span| span.end))
+            .max()
+            .or_else(|| {
+                lines
+                    .iter()
+                    .find(|(_, line)| line.trim() == "Global")
+                    .map(|(start, _)| *start)
+            })
+            .unwrap_or(self.text.len());
+
+        let mut insertions = vec![(project_insert_position, project_block)];
+
+        if !self.contents.configurations.is_empty() {
+            let configuration_lines = self
+                .contents
+                .configurations
+                .iter()
+                .flat_map(|configuration| {
+                    // .NET projects are built for `Any CPU` whatever the
+                    // solution's platform is, like `dotnet sln add` maps them.
+                    let project_configuration = match project_type {
+                        Some(_) => SolutionConfiguration {
+                            build_type: configuration.build_type.clone(),
+                            platform: DEFAULT_PLATFORM.to_string()
+                        },
+                        None => configuration.clone()
+                    };
+
+                    [
+                        format!(
+                            "\t\t{}.{}.ActiveCfg = {}{}",
+                            guid, configuration, project_configuration, line_ending
+                        ),
+                        format!(
+                            "\t\t{}.{}.Build.0 = {}{}",
+                            guid, configuration, project_configuration, line_ending
+                        )
+                    ]
+                })
+                .collect::<String>();
+
+            match find_sln_section(&lines, "ProjectConfigurationPlatforms") {
+                Some(section) => insertions.push((section.end_line_start, configuration_lines)),
+                None => {
+                    let section_text = format!(
+                        "\tGlobalSection(ProjectConfigurationPlatforms) = postSolution{}{}\tEndGlobalSection{}",
+                        line_ending, configuration_lines, line_ending
+                    );
+
+                    let section_position =
+                        find_sln_section(&lines, "SolutionConfigurationPlatforms")
+                            .map(|section| section.end)
+                            .unwrap_or(self.text.len());
+
+                    insertions.push((section_position, section_text));
+                }
+            }
+        }
+
+        insertions.sort_by_key(|(position, _)| Reverse(*position));
+
+        let mut text = self.text.clone();
+
+        for (position, inserted_text) in insertions {
+            text.insert_str(position, &inserted_text);
+        }
+
+        text
+    }
+
+    /// Gets the text of a `.slnx` file with a project added after the last
+    /// project that isn't in a folder, or at the end of the solution.
+    fn slnx_with_project(
+        &self,
+        project_path: &str
+    ) -> Result<String, CliError> {
+        let tags = xml::scan_tags(&self.text)?;
+        let elements = xml::match_elements(&self.text, &tags)?;
+
+        let last_root_project = elements.iter().rposition(|element| {
+            element.parent == Some(0) && tags[element.tag_index].name == "Project"
+        });
+
+        let (text, _) = xml::insert_element(
+            &self.text,
+            &tags,
+            &elements,
+            0,
+            last_root_project,
+            &format!("<Project Path=\"{}\" />", xml::escape(project_path))
+        );
+
+        Ok(text)
+    }
+
+    /// Gets the text of a `.sln` file without a project, its configuration
+    /// mappings and its entry in a solution folder.
+    fn sln_without_project(
+        &self,
+        project: &SolutionProject,
+        span: Range<usize>
+    ) -> String {
+        let guid = project.guid.clone().unwrap_or_default().to_uppercase();
+
+        let is_project_line = |line: &str| {
+            let line = line.trim_start().to_uppercase();
+
+            !guid.is_empty()
+                && (line.starts_with(&format!("{}.", guid))
+                    || line.starts_with(&format!("{} =", guid)))
+        };
+
+        let lines = split_lines(&self.text)
+            .into_iter()
+            .map(|(start, line)| (line, span.contains(&start) || is_project_line(line)))
+            .collect::<Vec<(&str, bool)>>();
+
+        let mut text = String::with_capacity(self.text.len());
+        let mut index = 0;
+
+        while index < lines.len() {
+            let (line, is_removed) = lines[index];
+
+            // Sections that only had the project's lines are removed too, like
+            // `dotnet sln remove` does. Sections that were already empty are
+            // kept.
+            if line.trim_start().starts_with("GlobalSection(") {
+                let section_end = lines[index..]
+                    .iter()
+                    .position(|(line, _)| line.trim() == "EndGlobalSection")
+                    .map(|position| index + position);
+
+                if let Some(section_end) = section_end {
+                    let section_lines = &lines[index + 1..section_end];
+
+                    if !section_lines.is_empty()
+                        && section_lines.iter().all(|(_, is_removed)| *is_removed)
+                    {
+                        index = section_end + 1;
+                        continue;
+                    }
+                }
+            }
+
+            if !is_removed {
+                text.push_str(line);
+            }
+
+            index += 1;
+        }
+
+        text
+    }
+}
+
+/// Finds the solution file in a directory.
+///
+/// Returns an error if there isn't one, or if there's more than one.
+///
+/// # Arguments
+///
+/// - `directory` - The directory to look in.
+pub fn find_solution_file(directory: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut solution_file_paths = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && SolutionFormat::from_path(path).is_some())
+        .collect::<Vec<PathBuf>>();
+
+    match solution_file_paths.len() {
+        0 => Err(CliError::new(
+            "No solution file found in the directory.",
+            CliErrorKind::FilePathDoesNotExist
+        )
+        .in_file(&directory.to_string_lossy())
+        .into()),
+        1 => Ok(solution_file_paths.remove(0)),
+        _ => Err(CliError::new(
+            "More than one solution file found in the directory. Use '--solution-file-path' to choose one.",
+            CliErrorKind::MultipleSolutionFiles
+        )
+        .in_file(&directory.to_string_lossy())
+        .into())
+    }
+}
+
+/// The contents of a solution file, with where they are in the text.
+#[derive(Debug, Clone, Default)]
+struct SolutionContents {
+    /// The projects, with where they are in the text. In a `.sln` file, the
+    /// span covers the whole lines from `Project(` to `EndProject`.
+    projects: Vec<(SolutionProject, Range<usize>)>,
+
+    /// The solution folders.
+    folders: Vec<SolutionFolder>,
+
+    /// Where the solution folders are in the text.
+    folder_spans: Vec<Range<usize>>,
+
+    /// The configurations.
+    configurations: Vec<SolutionConfiguration>
+}
+
+/// A `GlobalSection` of a `.sln` file.
+struct SlnSection {
+    /// Where the line with `EndGlobalSection` starts.
+    end_line_start: usize,
+
+    /// Where the line after `EndGlobalSection` starts.
+    end: usize
+}
+
+/// Parses the text of a `.sln` file.
+fn parse_sln(text: &str) -> Result<SolutionContents, CliError> {
+    let lines = split_lines(text);
+
+    let mut blocks = Vec::new();
+    let mut line_index = 0;
+
+    while line_index < lines.len() {
+        let (block_start, line) = lines[line_index];
+        let line_number = line_index + 1;
+        line_index += 1;
+
+        let Some(declaration) = line.trim().strip_prefix("Project(") else {
+            continue;
+        };
+
+        let parts = declaration.split('"').collect::<Vec<&str>>();
+
+        if parts.len() < 8 {
+            return Err(CliError::new(
+                "A 'Project' line doesn't have a type, name, path and GUID.",
+                CliErrorKind::InvalidSolutionFile
+            )
+            .at_line(line_number));
+        }
+
+        let end_index = lines[line_index..]
+            .iter()
+            .position(|(_, line)| line.trim() == "EndProject")
+            .map(|index| line_index + index)
+            .ok_or_else(|| {
+                CliError::new(
+                    "A 'Project' doesn't have an 'EndProject'.",
+                    CliErrorKind::InvalidSolutionFile
+                )
+                .at_line(line_number)
+            })?;
+
+        let (end_line_start, end_line) = lines[end_index];
+        line_index = end_index + 1;
+
+        blocks.push((
+            parts[1].to_string(),
+            parts[3].to_string(),
+            parts[5].to_string(),
+            parts[7].to_string(),
+            block_start..end_line_start + end_line.len()
+        ));
+    }
+
+    let nested_projects = find_sln_section(&lines, "NestedProjects")
+        .map(|section| sln_section_pairs(text, &section))
+        .unwrap_or_default();
+
+    let parent_guid = |guid: &str| {
+        nested_projects
+            .iter()
+            .find(|(child_guid, _)| child_guid.eq_ignore_ascii_case(guid))
+            .map(|(_, parent_guid)| parent_guid.as_str())
+    };
+
+    let folder_path = |guid: &str| -> Option<String> {
+        let mut names = Vec::new();
+        let mut current_guid = guid;
+
+        // A limit on the depth keeps a cycle in the nesting from looping.
+        while names.len() < blocks.len() {
+            let (_, name, ..) = blocks.iter().find(|(type_guid, _, _, block_guid, _)| {
+                type_guid.eq_ignore_ascii_case(SOLUTION_FOLDER_TYPE_GUID)
+                    && block_guid.eq_ignore_ascii_case(current_guid)
+            })?;
+
+            names.push(name.as_str());
+
+            match parent_guid(current_guid) {
+                Some(parent_guid) => current_guid = parent_guid,
+                None => break
+            }
+        }
+
+        names.reverse();
+
+        Some(format!("/{}/", names.join("/")))
+    };
+
+    let mut contents = SolutionContents::default();
+
+    for (type_guid, name, path, guid, span) in &blocks {
+        let folder = parent_guid(guid).and_then(folder_path);
+
+        match type_guid.eq_ignore_ascii_case(SOLUTION_FOLDER_TYPE_GUID) {
+            true => {
+                contents.folders.push(SolutionFolder {
+                    name: name.clone(),
+                    path: folder_path(guid).unwrap_or_default(),
+                    guid: Some(guid.clone())
+                });
+                contents.folder_spans.push(span.clone());
+            }
+            false => contents.projects.push((
+                SolutionProject {
+                    name: name.clone(),
+                    path: normalize_path(path),
+                    type_guid: Some(type_guid.clone()),
+                    guid: Some(guid.clone()),
+                    folder
+                },
+                span.clone()
+            ))
+        }
+    }
+
+    contents.configurations = find_sln_section(&lines, "SolutionConfigurationPlatforms")
+        .map(|section| sln_section_pairs(text, &section))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(configuration, _)| {
+            let (build_type, platform) = configuration
+                .split_once('|')
+                .unwrap_or((configuration.as_str(), ""));
+
+            SolutionConfiguration {
+                build_type: build_type.to_string(),
+                platform: platform.to_string()
+            }
+        })
+        .collect();
+
+    Ok(contents)
+}
+
+/// Parses the text of a `.slnx` file.
+fn parse_slnx(text: &str) -> Result<SolutionContents, CliError> {
+    let tags = xml::scan_tags(text)?;
+    let elements = xml::match_elements(text, &tags)?;
+
+    if elements
+        .first()
+        .is_none_or(|root| tags[root.tag_index].name != "Solution")
+    {
+        return Err(CliError::new(
+            "The root element isn't '<Solution>'.",
+            CliErrorKind::InvalidSolutionFile
+        ));
+    }
+
+    let mut contents = SolutionContents::default();
+
+    let mut build_types = Vec::new();
+    let mut platforms = Vec::new();
+
+    for element in &elements {
+        let tag = &tags[element.tag_index];
+
+        let parent_name = element
+            .parent
+            .map(|parent| tags[elements[parent].tag_index].name.as_str());
+
+        let folder = std::iter::successors(element.parent, |parent| elements[*parent].parent)
+            .map(|ancestor| &tags[elements[ancestor].tag_index])
+            .find(|ancestor_tag| ancestor_tag.name == "Folder")
+            .and_then(|folder_tag| folder_tag.attribute("Name"))
+            .map(|name| name.to_string());
+
+        match (tag.name.as_str(), parent_name) {
+            ("Project", _) => {
+                let Some(path) = tag.attribute("Path") else {
+                    continue;
+                };
+
+                let path = normalize_path(path);
+
+                contents.projects.push((
+                    SolutionProject {
+                        name: project_name(&path),
+                        path,
+                        type_guid: tag.attribute("Type").map(|type_guid| type_guid.to_string()),
+                        guid: tag.attribute("Id").map(|guid| guid.to_string()),
+                        folder
+                    },
+                    element.span.clone()
+                ));
+            }
+            ("Folder", _) => {
+                let Some(path) = tag.attribute("Name") else {
+                    continue;
+                };
+
+                contents.folders.push(SolutionFolder {
+                    name: path
+                        .split('/')
+                        .rfind(|name| !name.is_empty())
+                        .unwrap_or_default()
+                        .to_string(),
+                    path: path.to_string(),
+                    guid: tag.attribute("Id").map(|guid| guid.to_string())
+                });
+                contents.folder_spans.push(element.span.clone());
+            }
+            ("BuildType", Some("Configurations")) => build_types.extend(tag.attribute("Name")),
+            ("Platform", Some("Configurations")) => platforms.extend(tag.attribute("Name")),
+            _ => {}
+        }
+    }
+
+    if build_types.is_empty() {
+        build_types.extend(DEFAULT_BUILD_TYPES);
+    }
+
+    if platforms.is_empty() {
+        platforms.push(DEFAULT_PLATFORM);
+    }
+
+    contents.configurations = build_types
+        .iter()
+        .flat_map(|build_type| {
+            platforms.iter().map(|platform| SolutionConfiguration {
+                build_type: build_type.to_string(),
+                platform: platform.to_string()
+            })
+        })
+        .collect();
+
+    Ok(contents)
+}
+
+/// Splits a text into lines with their line endings, along with where each
+/// line starts.
+fn split_lines(text: &str) -> Vec<(usize, &str)> {
+    let mut line_start = 0;
+
+    text.split_inclusive('\n')
+        .map(|line| {
+            let start = line_start;
+            line_start += line.len();
+
+            (start, line)
+        })
+        .collect()
+}
+
+/// Finds a `GlobalSection` of a `.sln` file by its name.
+fn find_sln_section(
+    lines: &[(usize, &str)],
+    name: &str
+) -> Option<SlnSection> {
+    let header = format!("GlobalSection({})", name);
+
+    let header_index = lines
+        .iter()
+        .position(|(_, line)| line.trim_start().starts_with(&header))?;
+
+    let end_index = lines[header_index..]
+        .iter()
+        .position(|(_, line)| line.trim() == "EndGlobalSection")?
+        + header_index;
+
+    let (end_line_start, end_line) = lines[end_index];
+
+    Some(SlnSection {
+        end_line_start,
+        end: end_line_start + end_line.len()
+    })
+}
+
+/// Reads the `key = value` lines of a `GlobalSection` of a `.sln` file.
+fn sln_section_pairs(
+    text: &str,
+    section: &SlnSection
+) -> Vec<(String, String)> {
+    let section_start = text[..section.end_line_start]
+        .rfind("GlobalSection(")
+        .unwrap_or(section.end_line_start);
+
+    text[section_start..section.end_line_start]
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+/// Normalizes the path to a project in a solution, so that paths like
+/// `.\ConsoleApp\ConsoleApp.csproj` and `ConsoleApp/ConsoleApp.csproj` match.
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/")
+        .split('/')
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+/// Gets the name of a project from the path to its project file, which is
+/// the name of the file without its extension.
+fn project_name(project_path: &str) -> String {
+    let file_name = project_path.rsplit('/').next().unwrap_or(project_path);
+
+    file_name
+        .rsplit_once('.')
+        .map(|(name, _)| name)
+        .unwrap_or(file_name)
+        .to_string()
+}
+
+/// Creates a random GUID (e.g. `{2150E333-8FDC-42A3-9474-1A3956D46DE8}`) for
+/// a project in a `.sln` file.
+fn new_guid() -> String {
+    let random_state = RandomState::new();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+
+    let random_half = |half: u8| {
+        let mut hasher = random_state.build_hasher();
+        hasher.write_u128(nanos);
+        hasher.write_u8(half);
+        hasher.finish()
+    };
+
+    // The version (4) and variant bits mark the GUID as a random one.
+    let high = (random_half(0) & 0xFFFF_FFFF_FFFF_0FFF) | 0x0000_0000_0000_4000;
+    let low = (random_half(1) & 0x3FFF_FFFF_FFFF_FFFF) | 0x8000_0000_0000_0000;
+
+    format!(
+        "{{{:08X}-{:04X}-{:04X}-{:04X}-{:012X}}}",
+        high >> 32,
+        (high >> 16) & 0xFFFF,
+        high & 0xFFFF,
+        low >> 48,
+        low & 0xFFFF_FFFF_FFFF
+    )
+}
@@ -0,0 +1,621 @@
+use std::ops::Range;
+
+use crate::error::{CliError, CliErrorKind};
+
+/// The kind of an XML tag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum XmlTagKind {
+    /// A start tag (e.g. `<Project>`).
+    Start,
+
+    /// An end tag (e.g. `</Project>`).
+    End,
+
+    /// An empty-element tag (e.g. `<Project />`).
+    Empty
+}
+
+/// An attribute of an XML tag.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct XmlAttribute {
+    /// The name of the attribute.
+    pub name: String,
+
+    /// The value of the attribute, with its entities decoded.
+    pub value: String,
+
+    /// Where the value is in the text, without its quotes.
+    pub value_span: Range<usize>
+}
+
+/// A tag in an XML document, with where it is in the text.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct XmlTag {
+    /// The kind of the tag.
+    pub kind: XmlTagKind,
+
+    /// The name of the element.
+    pub name: String,
+
+    /// Where the tag is in the text, from its `<` to its `>`.
+    pub span: Range<usize>,
+
+    /// The attributes of the tag. End tags have none.
+    pub attributes: Vec<XmlAttribute>
+}
+
+impl XmlTag {
+    /// Gets the value of an attribute of the tag.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the attribute.
+    pub fn attribute(
+        &self,
+        name: &str
+    ) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .map(|attribute| attribute.value.as_str())
+    }
+}
+
+/// An element in an XML document, from its start tag to its end tag.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct XmlElement {
+    /// The index of the element's start tag (or empty-element tag) in the
+    /// tags of the document.
+    pub tag_index: usize,
+
+    /// The index of the element's end tag in the tags of the document. It's
+    /// the same as `tag_index` for an empty element.
+    pub end_tag_index: usize,
+
+    /// The index of the element's parent in the elements of the document.
+    pub parent: Option<usize>,
+
+    /// Where the element is in the text, from the `<` of its start tag to the
+    /// `>` of its end tag.
+    pub span: Range<usize>,
+
+    /// Where the element's content is in the text, between its tags.
+    pub content_span: Range<usize>
+}
+
+/// Scans the tags of an XML document.
+///
+/// Comments, processing instructions, CDATA sections and the document type
+/// are skipped. The text is only scanned as far as finding the tags needs, so
+/// it isn't checked to be well-formed beyond that.
+///
+/// # Arguments
+///
+/// - `text` - The text of the document.
+pub(crate) fn scan_tags(text: &str) -> Result<Vec<XmlTag>, CliError> {
+    let mut tags = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = text[position..].find('<') {
+        let tag_start = position + offset;
+        let rest = &text[tag_start..];
+
+        let skipped_end = [
+            ("<!--", "-->"),
+            ("<?", "?>"),
+            ("<![CDATA[", "]]>"),
+            ("<!", ">")
+        ]
+        .iter()
+        .find(|(opening, _)| rest.starts_with(opening))
+        .map(|(opening, closing)| {
+            rest[opening.len()..]
+                .find(closing)
+                .map(|index| tag_start + opening.len() + index + closing.len())
+                .ok_or_else(|| xml_error(text, tag_start, "Unclosed comment or declaration."))
+        });
+
+        if let Some(skipped_end) = skipped_end {
+            position = skipped_end?;
+            continue;
+        }
+
+        let tag = scan_tag(text, tag_start)?;
+        position = tag.span.end;
+        tags.push(tag);
+    }
+
+    Ok(tags)
+}
+
+/// Matches the start and end tags of an XML document into elements, in the
+/// order of their start tags.
+///
+/// # Arguments
+///
+/// - `text` - The text of the document, for the errors.
+/// - `tags` - The tags of the document, from [`scan_tags`].
+pub(crate) fn match_elements(
+    text: &str,
+    tags: &[XmlTag]
+) -> Result<Vec<XmlElement>, CliError> {
+    let mut elements: Vec<XmlElement> = Vec::new();
+    let mut open_elements: Vec<usize> = Vec::new();
+
+    for (tag_index, tag) in tags.iter().enumerate() {
+        match tag.kind {
+            XmlTagKind::Start | XmlTagKind::Empty => {
+                elements.push(XmlElement {
+                    tag_index,
+                    end_tag_index: tag_index,
+                    parent: open_elements.last().copied(),
+                    span: tag.span.clone(),
+                    content_span: tag.span.end..tag.span.end
+                });
+
+                if tag.kind == XmlTagKind::Start {
+                    open_elements.push(elements.len() - 1);
+                }
+            }
+            XmlTagKind::End => {
+                let element_index = open_elements
+                    .pop()
+                    .filter(|element_index| {
+                        tags[elements[*element_index].tag_index].name == tag.name
+                    })
+                    .ok_or_else(|| {
+                        xml_error(
+                            text,
+                            tag.span.start,
+                            format!("Unexpected end tag '</{}>'.", tag.name).as_str()
+                        )
+                    })?;
+
+                let element = &mut elements[element_index];
+                element.end_tag_index = tag_index;
+                element.content_span = element.span.end..tag.span.start;
+                element.span = element.span.start..tag.span.end;
+            }
+        }
+    }
+
+    if let Some(element_index) = open_elements.pop() {
+        let tag = &tags[elements[element_index].tag_index];
+
+        return Err(xml_error(
+            text,
+            tag.span.start,
+            format!("The element '<{}>' isn't closed.", tag.name).as_str()
+        ));
+    }
+
+    Ok(elements)
+}
+
+/// Escapes the characters that can't be in an XML attribute value or text.
+///
+/// # Arguments
+///
+/// - `value` - The value to escape.
+pub(crate) fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Decodes the predefined entities and character references in XML text.
+///
+/// # Arguments
+///
+/// - `value` - The text to decode.
+pub(crate) fn unescape(value: &str) -> String {
+    if !value.contains('&') {
+        return value.to_string();
+    }
+
+    let mut unescaped = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(index) = rest.find('&') {
+        unescaped.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let entity = rest
+            .find(';')
+            .map(|end| (&rest[1..end], end + 1))
+            .and_then(|(name, length)| {
+                let character = match name {
+                    "amp" => Some('&'),
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    _ => name
+                        .strip_prefix("#x")
+                        .and_then(|code| u32::from_str_radix(code, 16).ok())
+                        .or_else(|| name.strip_prefix('#').and_then(|code| code.parse().ok()))
+                        .and_then(char::from_u32)
+                };
+
+                character.map(|character| (character, length))
+            });
+
+        match entity {
+            Some((character, length)) => {
+                unescaped.push(character);
+                rest = &rest[length..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+
+    unescaped
+}
+
+/// Gets the line ending used in a text: `\r\n` if its first line ends with
+/// one, or `\n` otherwise.
+pub(crate) fn line_ending(text: &str) -> &'static str {
+    match text.find('\n') {
+        Some(index) if index > 0 && text.as_bytes()[index - 1] == b'\r' => "\r\n",
+        _ => "\n"
+    }
+}
+
+/// Gets the whitespace at the start of the line a position is on.
+pub(crate) fn line_indent(
+    text: &str,
+    position: usize
+) -> &str {
+    let line_start = text[..position]
+        .rfind('\n')
+        .map(|index| index + 1)
+        .unwrap_or(0);
+
+    let indent_length = text[line_start..]
+        .find(|character: char| character != ' ' && character != '\t')
+        .unwrap_or(text.len() - line_start);
+
+    &text[line_start..line_start + indent_length]
+}
+
+/// Widens a span of text to the whole lines it's on, with their line
+/// endings, if there's only whitespace around it on those lines. Otherwise,
+/// the span is kept as it is.
+///
+/// Used to remove an element along with its line.
+pub(crate) fn whole_line_span(
+    text: &str,
+    span: Range<usize>
+) -> Range<usize> {
+    let line_start = text[..span.start]
+        .rfind('\n')
+        .map(|index| index + 1)
+        .unwrap_or(0);
+
+    let line_end = text[span.end..]
+        .find('\n')
+        .map(|index| span.end + index + 1)
+        .unwrap_or(text.len());
+
+    let is_alone = text[line_start..span.start].trim().is_empty()
+        && text[span.end..line_end].trim().is_empty();
+
+    match is_alone {
+        true => line_start..line_end,
+        false => span
+    }
+}
+
+/// Inserts an element into a parent element, on its own line after one of the
+/// parent's children, or at the end of the parent.
+///
+/// The element gets the indentation of the parent's children and the line
+/// endings of the text. An empty parent (e.g. `<ItemGroup />`) is expanded to
+/// hold it. If the element is inserted next to a blank line, it's kept apart
+/// from its neighbors by a blank line on both sides, so groups stay separated.
+///
+/// Returns the new text, and where the element starts in it.
+///
+/// # Arguments
+///
+/// - `text` - The text of the document.
+/// - `tags` - The tags of the document, from [`scan_tags`].
+/// - `elements` - The elements of the document, from [`match_elements`].
+/// - `parent` - The index of the parent element.
+/// - `after` - The index of the child to insert the element after, or `None`
+///   to insert it at the end of the parent.
+/// - `element_text` - The text of the element to insert.
+pub(crate) fn insert_element(
+    text: &str,
+    tags: &[XmlTag],
+    elements: &[XmlElement],
+    parent: usize,
+    after: Option<usize>,
+    element_text: &str
+) -> (String, usize) {
+    let line_ending = line_ending(text);
+    let parent_element = &elements[parent];
+    let parent_indent = line_indent(text, parent_element.span.start);
+
+    let child_indent = elements
+        .iter()
+        .find(|element| element.parent == Some(parent))
+        .map(|child| line_indent(text, child.span.start))
+        .filter(|child_indent| child_indent.len() > parent_indent.len())
+        .map(|child_indent| child_indent.to_string())
+        .unwrap_or_else(|| {
+            let indent_unit = match text.contains("\n\t") {
+                true => "\t",
+                false => "  "
+            };
+
+            format!("{}{}", parent_indent, indent_unit)
+        });
+
+    let mut new_text = text.to_string();
+
+    let (position, inserted_text, element_offset) = match after {
+        Some(sibling) => {
+            let sibling_span = elements[sibling].span.clone();
+            let line_span = whole_line_span(text, sibling_span.clone());
+
+            match line_span == sibling_span {
+                true => (sibling_span.end, format!(" {}", element_text), 1),
+                false => {
+                    let sibling_indent = line_indent(text, sibling_span.start);
+                    let separator = match is_blank_line(text, line_span.end) {
+                        true => line_ending,
+                        false => ""
+                    };
+
+                    (
+                        line_span.end,
+                        format!(
+                            "{}{}{}{}",
+                            separator, sibling_indent, element_text, line_ending
+                        ),
+                        separator.len() + sibling_indent.len()
+                    )
+                }
+            }
+        }
+        None if parent_element.end_tag_index == parent_element.tag_index => {
+            // An empty element has no end tag to insert the element before.
+            let parent_tag = &tags[parent_element.tag_index];
+            let start_tag = text[parent_tag.span.clone()]
+                .trim_end_matches('>')
+                .trim_end_matches('/')
+                .trim_end();
+
+            new_text.replace_range(parent_tag.span.clone(), "");
+
+            let inserted_text = format!(
+                "{}>{}{}{}{}{}</{}>",
+                start_tag,
+                line_ending,
+                child_indent,
+                element_text,
+                line_ending,
+                parent_indent,
+                parent_tag.name
+            );
+            let element_offset = start_tag.len() + 1 + line_ending.len() + child_indent.len();
+
+            (parent_tag.span.start, inserted_text, element_offset)
+        }
+        None => {
+            let end_tag_start = tags[parent_element.end_tag_index].span.start;
+            let line_start = text[..end_tag_start]
+                .rfind('\n')
+                .map(|index| index + 1)
+                .unwrap_or(0);
+
+            match text[line_start..end_tag_start].trim().is_empty() {
+                true => {
+                    let follows_blank_line = line_start > 0
+                        && is_blank_line(text, previous_line_start(text, line_start));
+
+                    let separator = match follows_blank_line {
+                        true => line_ending,
+                        false => ""
+                    };
+
+                    (
+                        line_start,
+                        format!(
+                            "{}{}{}{}",
+                            child_indent, element_text, line_ending, separator
+                        ),
+                        child_indent.len()
+                    )
+                }
+                false => (
+                    end_tag_start,
+                    format!(
+                        "{}{}{}{}{}",
+                        line_ending, child_indent, element_text, line_ending, parent_indent
+                    ),
+                    line_ending.len() + child_indent.len()
+                )
+            }
+        }
+    };
+
+    new_text.insert_str(position, &inserted_text);
+
+    (new_text, position + element_offset)
+}
+
+/// Removes an element from a text, along with its lines if there's only
+/// whitespace around it on them.
+///
+/// If the element's lines are between two blank lines, one of them is removed
+/// too, which undoes the separation added by [`insert_element`].
+///
+/// # Arguments
+///
+/// - `text` - The text of the document.
+/// - `span` - Where the element is in the text.
+pub(crate) fn remove_element(
+    text: &str,
+    span: Range<usize>
+) -> String {
+    let line_span = whole_line_span(text, span.clone());
+    let mut removed_span = line_span.clone();
+
+    if line_span != span
+        && line_span.start > 0
+        && is_blank_line(text, previous_line_start(text, line_span.start))
+        && is_blank_line(text, line_span.end)
+    {
+        removed_span.end = text[line_span.end..]
+            .find('\n')
+            .map(|index| line_span.end + index + 1)
+            .unwrap_or(text.len());
+    }
+
+    format!("{}{}", &text[..removed_span.start], &text[removed_span.end..])
+}
+
+/// Checks whether the line that starts at a position only has whitespace.
+/// There's no line at the end of the text.
+fn is_blank_line(
+    text: &str,
+    line_start: usize
+) -> bool {
+    line_start < text.len()
+        && text[line_start..]
+            .split('\n')
+            .next()
+            .is_some_and(|line| line.trim().is_empty())
+}
+
+/// Gets where the line before the line that starts at a position starts.
+fn previous_line_start(
+    text: &str,
+    line_start: usize
+) -> usize {
+    text[..line_start.saturating_sub(1)]
+        .rfind('\n')
+        .map(|index| index + 1)
+        .unwrap_or(0)
+}
+
+/// Scans the tag that starts at a position.
+fn scan_tag(
+    text: &str,
+    tag_start: usize
+) -> Result<XmlTag, CliError> {
+    let bytes = text.as_bytes();
+    let mut position = tag_start + 1;
+
+    let is_end_tag = bytes.get(position) == Some(&b'/');
+
+    if is_end_tag {
+        position += 1;
+    }
+
+    let name_start = position;
+
+    while position < bytes.len()
+        && !bytes[position].is_ascii_whitespace()
+        && bytes[position] != b'>'
+        && bytes[position] != b'/'
+    {
+        position += 1;
+    }
+
+    let name = text[name_start..position].to_string();
+
+    if name.is_empty() {
+        return Err(xml_error(text, tag_start, "A tag doesn't have a name."));
+    }
+
+    let mut attributes = Vec::new();
+
+    loop {
+        while position < bytes.len() && bytes[position].is_ascii_whitespace() {
+            position += 1;
+        }
+
+        match bytes.get(position) {
+            None => return Err(xml_error(text, tag_start, "A tag isn't closed.")),
+            Some(b'>') => {
+                let kind = match is_end_tag {
+                    true => XmlTagKind::End,
+                    false => XmlTagKind::Start
+                };
+
+                return Ok(XmlTag {
+                    kind,
+                    name,
+                    span: tag_start..position + 1,
+                    attributes
+                });
+            }
+            Some(b'/') if !is_end_tag && bytes.get(position + 1) == Some(&b'>') => {
+                return Ok(XmlTag {
+                    kind: XmlTagKind::Empty,
+                    name,
+                    span: tag_start..position + 2,
+                    attributes
+                });
+            }
+            Some(_) if is_end_tag => {
+                return Err(xml_error(text, tag_start, "An end tag has attributes."));
+            }
+            Some(_) => {
+                let attribute = scan_attribute(text, position)
+                    .ok_or_else(|| xml_error(text, position, "An attribute isn't valid."))?;
+
+                position = attribute.value_span.end + 1;
+                attributes.push(attribute);
+            }
+        }
+    }
+}
+
+/// Scans the attribute that starts at a position, like `Name="value"`.
+fn scan_attribute(
+    text: &str,
+    attribute_start: usize
+) -> Option<XmlAttribute> {
+    let rest = &text[attribute_start..];
+
+    let name_length = rest.find(|character: char| character == '=' || character.is_whitespace())?;
+    let name = rest[..name_length].to_string();
+
+    let after_name = rest[name_length..].trim_start();
+    let after_equals = after_name.strip_prefix('=')?.trim_start();
+
+    let quote = after_equals.chars().next().filter(|quote| *quote == '"' || *quote == '\'')?;
+    let value_start = attribute_start + (rest.len() - after_equals.len()) + 1;
+    let value_length = text[value_start..].find(quote)?;
+
+    let value_span = value_start..value_start + value_length;
+
+    Some(XmlAttribute {
+        name,
+        value: unescape(&text[value_span.clone()]),
+        value_span
+    })
+}
+
+/// Creates the error for XML that can't be scanned, at the line of a
+/// position.
+fn xml_error(
+    text: &str,
+    position: usize,
+    message: &str
+) -> CliError {
+    let line = text[..position].matches('\n').count() + 1;
+
+    CliError::new(message, CliErrorKind::InvalidXml).at_line(line)
+}
@@ -7,7 +7,13 @@ pub struct CliError {
     pub message: String,
 
     /// The kind of error.
-    pub kind: CliErrorKind
+    pub kind: CliErrorKind,
+
+    /// The file the error occurred in, if it is about a file.
+    pub file_path: Option<String>,
+
+    /// The line the error occurred on, if it is about a file.
+    pub line: Option<usize>
 }
 
 impl CliError {
@@ -18,9 +24,39 @@ impl CliError {
     ) -> Self {
         Self {
             message: message.to_string(),
-            kind
+            kind,
+            file_path: None,
+            line: None
         }
     }
+
+    /// Sets the file the error occurred in.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_path` - The path to the file.
+    pub fn in_file(
+        mut self,
+        file_path: &str
+    ) -> Self {
+        self.file_path = Some(file_path.to_string());
+
+        self
+    }
+
+    /// Sets the line the error occurred on.
+    ///
+    /// # Arguments
+    ///
+    /// - `line` - The line number, starting at 1.
+    pub fn at_line(
+        mut self,
+        line: usize
+    ) -> Self {
+        self.line = Some(line);
+
+        self
+    }
 }
 
 impl fmt::Display for CliError {
@@ -28,7 +64,12 @@ impl fmt::Display for CliError {
         &self,
         f: &mut fmt::Formatter<'_>
     ) -> fmt::Result {
-        write!(f, "{}", self.message)
+        match (&self.file_path, self.line) {
+            (Some(file_path), Some(line)) => write!(f, "{}:{}: {}", file_path, line, self.message),
+            (Some(file_path), None) => write!(f, "{}: {}", file_path, self.message),
+            (None, Some(line)) => write!(f, "line {}: {}", line, self.message),
+            (None, None) => write!(f, "{}", self.message)
+        }
     }
 }
 
@@ -44,7 +85,6 @@ pub enum CliErrorKind {
     /// The solution name could not be parsed.
     UnableToParseSolutionName,
 
-    #[allow(dead_code)]
     /// The file path does not exist.
     FilePathDoesNotExist,
 
@@ -54,6 +94,89 @@ pub enum CliErrorKind {
     /// The output directory does not exist.
     OutputDirectoryDoesNotExist,
 
+    /// The template file could not be found.
+    TemplateNotFound,
+
+    /// The template could not be rendered.
+    TemplateRenderError,
+
+    /// The template uses a variable that has no value.
+    UnknownTemplateVariable,
+
+    /// A template variable supplied by the user is invalid.
+    InvalidTemplateVariable,
+
+    /// A template pack needs a variable that has no value.
+    MissingTemplateVariable,
+
+    /// A template pack's manifest is invalid.
+    InvalidTemplatePack,
+
+    /// A step of a template pack failed.
+    TemplateStepFailed,
+
+    /// A templates directory has problems.
+    TemplateValidationFailed,
+
+    /// A Git command failed.
+    GitCommandFailed,
+
+    /// A Cargo command failed.
+    CargoCommandFailed,
+
+    /// A template set could not be installed, updated or removed.
+    TemplateSetError,
+
+    /// A project could not be captured as a template pack.
+    TemplateCaptureFailed,
+
+    /// The command does not support dry runs.
+    DryRunNotSupported,
+
+    /// A JSON file with comments could not be parsed.
+    InvalidJsonc,
+
+    /// A VSCode file doesn't have the structure it should.
+    InvalidVSCodeFile,
+
+    /// A task, input or launch configuration couldn't be found in a VSCode
+    /// file.
+    VSCodeItemNotFound,
+
+    /// A VSCode file doesn't match the projects it lists.
+    VSCodeFileOutOfSync,
+
+    /// More than one `.code-workspace` file was found where only one was
+    /// expected.
+    MultipleWorkspaceFiles,
+
+    /// The language of a project couldn't be detected.
+    UnsupportedProjectLanguage,
+
+    /// A .NET project file is missing or doesn't have a value that's needed.
+    InvalidProjectFile,
+
+    /// A .NET solution file could not be parsed, or doesn't have a value
+    /// that's needed.
+    InvalidSolutionFile,
+
+    /// More than one solution file was found where only one was expected.
+    MultipleSolutionFiles,
+
+    /// An XML file could not be parsed.
+    InvalidXml,
+
+    /// The user cancelled the run at a prompt.
+    OperationCancelled,
+
+    /// An existing file or directory would be replaced, and the conflict
+    /// policy doesn't allow it.
+    FileConflict,
+
+    /// A package name doesn't point to a package directory inside the output
+    /// directory.
+    InvalidPackageName,
+
     #[allow(dead_code)]
     /// Unknown error.
     UnknownError
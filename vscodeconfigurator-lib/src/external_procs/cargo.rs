@@ -1,6 +1,11 @@
-use std::{fs, path::PathBuf, process};
+use std::{fs, path::Path, process};
 
+use serde::Deserialize;
+
+use super::describe_planned_command;
 use crate::{
+    error::{CliError, CliErrorKind},
+    io::describe_planned_write,
     lang_options::CargoPackageTemplateOption,
     logging::{ConsoleLogger, OutputEmoji}
 };
@@ -12,7 +17,6 @@ use crate::{
 /// - `output_directory` - The output directory for the new solution.
 /// - `package_name` - The name of the package.
 /// - `package_template` - The type of package to create.
-/// - `force` - Whether to forcefully overwrite.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
 ///   for logging.
@@ -24,28 +28,30 @@ use crate::{
 /// Initializes a new package, named `my_package`, with the binary template in
 /// `my-project` directory in the temp directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::cargo::initalize_package,
+///     lang_options::CargoPackageTemplateOption,
+///     logging::ConsoleLogger
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("my-project");
 /// let package_name = "my_package";
 /// let package_template = CargoPackageTemplateOption::Binary;
-/// let force = false;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
 /// initalize_package(
 ///     &output_directory,
 ///     &package_name,
 ///     package_template,
-///     force,
-///     logger
-/// );
+///     &mut logger
+/// )
+/// .unwrap();
 /// ```
 pub fn initalize_package(
-    output_directory: &PathBuf,
+    output_directory: &Path,
     package_name: &str,
     package_template: CargoPackageTemplateOption,
-    force: bool,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log(
@@ -56,19 +62,6 @@ pub fn initalize_package(
 
     let package_output_directory = output_directory.join(package_name);
 
-    if package_output_directory.exists() {
-        if !force {
-            let overwrite_response = logger.ask_for_overwrite()?;
-
-            if !overwrite_response {
-                logger.write_warning(format!("Already exists 🟠\n"))?;
-                return Ok(());
-            }
-        }
-
-        fs::remove_dir_all(&package_output_directory)?;
-    }
-
     let package_template_arg_str = match package_template {
         CargoPackageTemplateOption::Binary => "--bin",
         CargoPackageTemplateOption::Library => "--lib"
@@ -84,6 +77,24 @@ pub fn initalize_package(
         &package_output_directory_string.as_str(),
     ];
 
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&package_output_directory, logger.on_conflict),
+            describe_planned_command("cargo", &cargo_proc_args, Some(output_directory))
+        ])?;
+
+        return Ok(());
+    }
+
+    if package_output_directory.exists() {
+        if !logger.resolve_conflict(&package_output_directory)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(());
+        }
+
+        fs::remove_dir_all(&package_output_directory)?;
+    }
+
     process::Command::new("cargo")
         .args(cargo_proc_args)
         .current_dir(output_directory)
@@ -93,3 +104,84 @@ pub fn initalize_package(
 
     Ok(())
 }
+
+/// The parts of the output of `cargo metadata` that are read.
+#[derive(Deserialize)]
+struct CargoMetadata {
+    /// The packages in the workspace.
+    packages: Vec<CargoMetadataPackage>,
+
+    /// The IDs of the workspace's member packages.
+    workspace_members: Vec<String>
+}
+
+/// A package in the output of `cargo metadata`.
+#[derive(Deserialize)]
+struct CargoMetadataPackage {
+    /// The ID of the package.
+    id: String,
+
+    /// The name of the package.
+    name: String
+}
+
+/// Reads the names of the member packages of a Cargo workspace with `cargo
+/// metadata`, in the order Cargo lists them.
+///
+/// The command only reads the workspace, so it runs on a dry run too.
+///
+/// # Arguments
+///
+/// - `output_directory` - The root directory of the workspace.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Read the packages of the workspace in the `my-project` directory in the
+/// temp directory.
+///
+/// ```rust,ignore
+/// let output_directory = std::env::temp_dir().join("my-project");
+///
+/// let package_names = read_workspace_package_names(&output_directory)?;
+/// ```
+pub fn read_workspace_package_names(
+    output_directory: &Path
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let cargo_proc_args = vec!["metadata", "--no-deps", "--format-version", "1"];
+
+    let cargo_proc_output = process::Command::new("cargo")
+        .args(&cargo_proc_args)
+        .current_dir(output_directory)
+        .output()?;
+
+    if !cargo_proc_output.status.success() {
+        return Err(CliError::new(
+            format!(
+                "'cargo {}' failed: {}",
+                cargo_proc_args.join(" "),
+                String::from_utf8_lossy(&cargo_proc_output.stderr).trim()
+            )
+            .as_str(),
+            CliErrorKind::CargoCommandFailed
+        )
+        .into());
+    }
+
+    let metadata: CargoMetadata = serde_json::from_slice(&cargo_proc_output.stdout)?;
+
+    let package_names = metadata
+        .workspace_members
+        .iter()
+        .filter_map(|member_id| {
+            metadata
+                .packages
+                .iter()
+                .find(|package| &package.id == member_id)
+        })
+        .map(|package| package.name.clone())
+        .collect();
+
+    Ok(package_names)
+}
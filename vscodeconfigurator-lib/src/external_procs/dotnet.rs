@@ -1,6 +1,14 @@
-use std::{fs, path::PathBuf, process};
-
-use crate::logging::{ConsoleLogger, OutputEmoji};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process
+};
+
+use super::describe_planned_command;
+use crate::{
+    io::describe_planned_write,
+    logging::{ConsoleLogger, OutputEmoji}
+};
 
 /// Initializes a new .NET solution.
 ///
@@ -8,7 +16,6 @@ use crate::logging::{ConsoleLogger, OutputEmoji};
 ///
 /// - `output_directory` - The output directory for the new solution.
 /// - `solution_name` - The name of the solution file.
-/// - `force` - Whether to forcefully overwrite.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
 ///   for logging.
@@ -20,20 +27,21 @@ use crate::logging::{ConsoleLogger, OutputEmoji};
 /// Initializes a new solution, named `MySolution`, in the `MySolution`
 /// directory in the temp directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::dotnet::initalize_dotnet_solution,
+///     logging::ConsoleLogger
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
-/// let solution_name = "MySolution";
-/// let force = false;
-/// let mut logger = ConsoleLogger::new();
+/// let solution_name = "MySolution".to_string();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// initalize_dotnet_solution(&output_directory, &solution_name, force, logger);
+/// initalize_dotnet_solution(&output_directory, &solution_name, &mut logger).unwrap();
 /// ```
 pub fn initalize_dotnet_solution(
-    output_directory: &PathBuf,
+    output_directory: &Path,
     solution_name: &String,
-    force: bool,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log(
@@ -45,21 +53,25 @@ pub fn initalize_dotnet_solution(
     let output_file_name = format!("{:}.sln", &solution_name);
     let output_file_path = PathBuf::from(output_directory).join(&output_file_name);
 
-    if output_file_path.exists() {
-        if !force {
-            let overwrite_response = logger.ask_for_overwrite()?;
+    let dotnet_proc_args = vec!["new", "sln", "--name", solution_name];
 
-            if !overwrite_response {
-                logger.write_warning(format!("Already exists 🟠\n"))?;
-                return Ok(());
-            }
-        }
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&output_file_path, logger.on_conflict),
+            describe_planned_command("dotnet", &dotnet_proc_args, Some(output_directory))
+        ])?;
 
-        fs::remove_file(output_file_path)
-            .expect(format!("Failed to remove existing '{:}' file.", &output_file_name).as_str());
+        return Ok(());
     }
 
-    let dotnet_proc_args = vec!["new", "sln", "--name", solution_name];
+    if output_file_path.exists() {
+        if !logger.resolve_conflict(&output_file_path)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(());
+        }
+
+        fs::remove_file(output_file_path)?;
+    }
 
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
@@ -76,7 +88,6 @@ pub fn initalize_dotnet_solution(
 /// # Arguments
 ///
 /// - `output_directory` - The output directory for the project.
-/// - `force` - Whether to forcefully overwrite.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
 ///   for logging.
@@ -88,18 +99,19 @@ pub fn initalize_dotnet_solution(
 /// Add a `global.json` file to the `MySolution` directory in the temp
 /// directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::dotnet::add_dotnet_globaljson,
+///     logging::ConsoleLogger
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
-/// let force = false;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// add_dotnet_globaljson(&output_directory, force, logger);
+/// add_dotnet_globaljson(&output_directory, &mut logger).unwrap();
 /// ```
 pub fn add_dotnet_globaljson(
-    output_directory: &PathBuf,
-    force: bool,
+    output_directory: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log(
@@ -111,21 +123,25 @@ pub fn add_dotnet_globaljson(
     let output_file_name = "global.json";
     let output_file_path = PathBuf::from(output_directory).join(output_file_name);
 
-    if output_file_path.exists() {
-        if !force {
-            let overwrite_response = logger.ask_for_overwrite()?;
+    let dotnet_proc_args = vec!["new", "globaljson", "--roll-forward", "latestMinor"];
 
-            if !overwrite_response {
-                logger.write_warning(format!("Already exists 🟠\n"))?;
-                return Ok(());
-            }
-        }
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&output_file_path, logger.on_conflict),
+            describe_planned_command("dotnet", &dotnet_proc_args, Some(output_directory))
+        ])?;
 
-        fs::remove_file(output_file_path)
-            .expect(format!("Failed to remove existing '{:}' file.", output_file_name).as_str());
+        return Ok(());
     }
 
-    let dotnet_proc_args = vec!["new", "globaljson", "--roll-forward", "latestMinor"];
+    if output_file_path.exists() {
+        if !logger.resolve_conflict(&output_file_path)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(());
+        }
+
+        fs::remove_file(output_file_path)?;
+    }
 
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
@@ -142,7 +158,6 @@ pub fn add_dotnet_globaljson(
 /// # Arguments
 ///
 /// - `output_directory` - The output directory for the project.
-/// - `force` - Whether to forcefully overwrite.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
 ///   for logging.
@@ -153,18 +168,19 @@ pub fn add_dotnet_globaljson(
 ///
 /// Add a `.gitignore` file to the `MySolution` directory in the temp directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::dotnet::add_dotnet_gitignore,
+///     logging::ConsoleLogger
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
-/// let force = false;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// add_dotnet_gitignore(&output_directory, force, logger);
+/// add_dotnet_gitignore(&output_directory, &mut logger).unwrap();
 /// ```
 pub fn add_dotnet_gitignore(
-    output_directory: &PathBuf,
-    force: bool,
+    output_directory: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log(
@@ -176,21 +192,25 @@ pub fn add_dotnet_gitignore(
     let output_file_name = ".gitignore";
     let output_file_path = PathBuf::from(output_directory).join(output_file_name);
 
-    if output_file_path.exists() {
-        if !force {
-            let overwrite_response = logger.ask_for_overwrite()?;
+    let dotnet_proc_args = vec!["new", "gitignore"];
 
-            if !overwrite_response {
-                logger.write_warning(format!("Already exists 🟠\n"))?;
-                return Ok(());
-            }
-        }
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&output_file_path, logger.on_conflict),
+            describe_planned_command("dotnet", &dotnet_proc_args, Some(output_directory))
+        ])?;
 
-        fs::remove_file(output_file_path)
-            .expect(format!("Failed to remove existing '{:}' file.", output_file_name).as_str());
+        return Ok(());
     }
 
-    let dotnet_proc_args = vec!["new", "gitignore"];
+    if output_file_path.exists() {
+        if !logger.resolve_conflict(&output_file_path)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(());
+        }
+
+        fs::remove_file(output_file_path)?;
+    }
 
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
@@ -207,7 +227,6 @@ pub fn add_dotnet_gitignore(
 /// # Arguments
 ///
 /// - `output_directory` - The output directory for the project.
-/// - `force` - Whether to forcefully overwrite.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
 ///   for logging.
@@ -219,18 +238,19 @@ pub fn add_dotnet_gitignore(
 /// Add a `Directory.Build.props` file to the `MySolution` directory in the temp
 /// directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::dotnet::add_dotnet_buildprops,
+///     logging::ConsoleLogger
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
-/// let force = false;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// add_dotnet_buildprops(&output_directory, force, logger);
+/// add_dotnet_buildprops(&output_directory, &mut logger).unwrap();
 /// ```
 pub fn add_dotnet_buildprops(
-    output_directory: &PathBuf,
-    force: bool,
+    output_directory: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log(
@@ -242,21 +262,25 @@ pub fn add_dotnet_buildprops(
     let output_file_name = "Directory.Build.props";
     let output_file_path = PathBuf::from(output_directory).join(output_file_name);
 
-    if output_file_path.exists() {
-        if !force {
-            let overwrite_response = logger.ask_for_overwrite()?;
+    let dotnet_proc_args = vec!["new", "buildprops", "--use-artifacts"];
 
-            if !overwrite_response {
-                logger.write_warning(format!("Already exists 🟠\n"))?;
-                return Ok(());
-            }
-        }
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&output_file_path, logger.on_conflict),
+            describe_planned_command("dotnet", &dotnet_proc_args, Some(output_directory))
+        ])?;
 
-        fs::remove_file(output_file_path)
-            .expect(format!("Failed to remove existing '{:}' file.", output_file_name).as_str());
+        return Ok(());
     }
 
-    let dotnet_proc_args = vec!["new", "buildprops", "--use-artifacts"];
+    if output_file_path.exists() {
+        if !logger.resolve_conflict(&output_file_path)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(());
+        }
+
+        fs::remove_file(output_file_path)?;
+    }
 
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
@@ -273,7 +297,6 @@ pub fn add_dotnet_buildprops(
 /// # Arguments
 ///
 /// - `output_directory` - The output directory for the project.
-/// - `force` - Whether to forcefully overwrite.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
 ///   for logging.
@@ -285,18 +308,19 @@ pub fn add_dotnet_buildprops(
 /// Add a `NuGet.Config` file to the `MySolution` directory in the temp
 /// directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::dotnet::add_dotnet_nugetconfig,
+///     logging::ConsoleLogger
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
-/// let force = false;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// add_dotnet_nugetconfig(&output_directory, force, logger);
+/// add_dotnet_nugetconfig(&output_directory, &mut logger).unwrap();
 /// ```
 pub fn add_dotnet_nugetconfig(
-    output_directory: &PathBuf,
-    force: bool,
+    output_directory: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log(
@@ -308,21 +332,25 @@ pub fn add_dotnet_nugetconfig(
     let output_file_name = "NuGet.Config";
     let output_file_path = PathBuf::from(output_directory).join(output_file_name);
 
-    if output_file_path.exists() {
-        if !force {
-            let overwrite_response = logger.ask_for_overwrite()?;
+    let dotnet_proc_args = vec!["new", "nugetconfig"];
 
-            if !overwrite_response {
-                logger.write_warning(format!("Already exists 🟠\n"))?;
-                return Ok(());
-            }
-        }
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&output_file_path, logger.on_conflict),
+            describe_planned_command("dotnet", &dotnet_proc_args, Some(output_directory))
+        ])?;
 
-        fs::remove_file(output_file_path)
-            .expect(format!("Failed to remove existing '{:}' file.", output_file_name).as_str());
+        return Ok(());
     }
 
-    let dotnet_proc_args = vec!["new", "nugetconfig"];
+    if output_file_path.exists() {
+        if !logger.resolve_conflict(&output_file_path)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(());
+        }
+
+        fs::remove_file(output_file_path)?;
+    }
 
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
@@ -339,7 +367,6 @@ pub fn add_dotnet_nugetconfig(
 /// # Arguments
 ///
 /// - `output_directory` - The output directory for the project.
-/// - `force` - Whether to forcefully overwrite.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
 ///   for logging.
@@ -351,18 +378,19 @@ pub fn add_dotnet_nugetconfig(
 /// Add a `Directory.Packages.props` file to the `MySolution` directory in the
 /// temp directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::dotnet::add_dotnet_packagesprops,
+///     logging::ConsoleLogger
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
-/// let force = false;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// add_dotnet_packagesprops(&output_directory, force, logger);
+/// add_dotnet_packagesprops(&output_directory, &mut logger).unwrap();
 /// ```
 pub fn add_dotnet_packagesprops(
-    output_directory: &PathBuf,
-    force: bool,
+    output_directory: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log(
@@ -374,21 +402,25 @@ pub fn add_dotnet_packagesprops(
     let output_file_name = "Directory.Packages.props";
     let output_file_path = PathBuf::from(output_directory).join(output_file_name);
 
-    if output_file_path.exists() {
-        if !force {
-            let overwrite_response = logger.ask_for_overwrite()?;
+    let dotnet_proc_args = vec!["new", "packagesprops"];
 
-            if !overwrite_response {
-                logger.write_warning(format!("Already exists 🟠\n"))?;
-                return Ok(());
-            }
-        }
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&output_file_path, logger.on_conflict),
+            describe_planned_command("dotnet", &dotnet_proc_args, Some(output_directory))
+        ])?;
 
-        fs::remove_file(output_file_path)
-            .expect(format!("Failed to remove existing '{:}' file.", output_file_name).as_str());
+        return Ok(());
     }
 
-    let dotnet_proc_args = vec!["new", "packagesprops"];
+    if output_file_path.exists() {
+        if !logger.resolve_conflict(&output_file_path)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(());
+        }
+
+        fs::remove_file(output_file_path)?;
+    }
 
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
@@ -417,17 +449,17 @@ pub fn add_dotnet_packagesprops(
 /// Add a .NET tool named `gitversion` to the `MySolution` directory in the temp
 /// directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{external_procs::dotnet::add_dotnet_tool, logging::ConsoleLogger};
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
 /// let tool_name = "gitversion";
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// add_dotnet_tool(&output_directory, tool_name, logger);
+/// add_dotnet_tool(&output_directory, tool_name, &mut logger).unwrap();
 /// ```
 pub fn add_dotnet_tool(
-    output_directory: &PathBuf,
+    output_directory: &Path,
     tool_name: &str,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -438,6 +470,16 @@ pub fn add_dotnet_tool(
 
     let dotnet_proc_args = vec!["tool", "install", tool_name, "--tool-manifest"];
 
+    if logger.dry_run {
+        logger.write_dry_run_log(&[describe_planned_command(
+            "dotnet",
+            &dotnet_proc_args,
+            Some(output_directory)
+        )])?;
+
+        return Ok(());
+    }
+
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
         .current_dir(output_directory)
@@ -464,7 +506,7 @@ pub fn add_dotnet_tool(
 /// Add a tool manifest file to the `MySolution` directory in the temp
 /// directory.
 ///
-/// ```rust
+/// ```rust,ignore
 /// use vscodeconfigurator::logger::ConsoleLogger;
 ///
 /// let output_directory = std::env::temp_dir().join("MySolution");
@@ -473,16 +515,12 @@ pub fn add_dotnet_tool(
 /// initialize_dotnet_tool_manifest(&output_directory, logger);
 /// ```
 fn initialize_dotnet_tool_manifest(
-    output_directory: &PathBuf,
+    output_directory: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     let config_directory = PathBuf::from(output_directory).join(".config");
     let tool_manifest_file_path = PathBuf::from(&config_directory).join("dotnet-tools.json");
 
-    if !config_directory.exists() {
-        fs::create_dir(&config_directory)?;
-    }
-
     if tool_manifest_file_path.exists() {
         return Ok(());
     }
@@ -492,6 +530,19 @@ fn initialize_dotnet_tool_manifest(
 
     let dotnet_proc_args = vec!["new", "tool-manifest"];
 
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            describe_planned_write(&tool_manifest_file_path, logger.on_conflict),
+            describe_planned_command("dotnet", &dotnet_proc_args, Some(output_directory))
+        ])?;
+
+        return Ok(());
+    }
+
+    if !config_directory.exists() {
+        fs::create_dir(&config_directory)?;
+    }
+
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
         .current_dir(output_directory)
@@ -519,23 +570,24 @@ fn initialize_dotnet_tool_manifest(
 /// Add a project in the `ConsoleApp` directory to the `MySolution.sln` solution
 /// file in the `MySolution` directory in the temp directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     external_procs::dotnet::add_project_to_solution,
+///     logging::ConsoleLogger
+/// };
 ///
-/// let solution_file_path = std::env::temp_dir()
-///    .join("MySolution")
-/// let project_file_path = std::env::temp_dir()
-///   .join("MySolution/ConsoleApp");
-/// let mut logger = ConsoleLogger::new();
+/// let solution_file_path = std::env::temp_dir().join("MySolution/MySolution.sln");
+/// let project_file_path = std::env::temp_dir().join("MySolution/ConsoleApp");
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// add_project_to_solution(&solution_file_path, &project_file_path, logger);
+/// add_project_to_solution(&solution_file_path, &project_file_path, &mut logger).unwrap();
 /// ```
 pub fn add_project_to_solution(
-    solution_file_path: &PathBuf,
-    project_file_path: &PathBuf,
+    solution_file_path: &Path,
+    project_file_path: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let project_file_path = project_file_path.canonicalize().unwrap();
+    let project_file_path = project_file_path.canonicalize()?;
     let project_path_relative =
         project_file_path.strip_prefix(solution_file_path.parent().unwrap())?;
 
@@ -552,6 +604,15 @@ pub fn add_project_to_solution(
         project_file_path.to_str().unwrap(),
     ];
 
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            format!("Would modify '{}'", solution_file_path.display()),
+            describe_planned_command("dotnet", &dotnet_proc_args, None)
+        ])?;
+
+        return Ok(());
+    }
+
     process::Command::new("dotnet")
         .args(dotnet_proc_args)
         .output()?;
@@ -560,3 +621,99 @@ pub fn add_project_to_solution(
 
     Ok(())
 }
+
+/// Remove a project from a solution.
+///
+/// If the project isn't in the solution, or `dotnet` can't remove it, the log
+/// says so instead of returning an error.
+///
+/// # Arguments
+///
+/// - `solution_file_path` - The path to the solution file.
+/// - `project_file_path` - The path to the project file, or to the directory
+///   it's in.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Remove the project in the `ConsoleApp` directory from the `MySolution.sln`
+/// solution file in the `MySolution` directory in the temp directory.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let solution_file_path = std::env::temp_dir()
+///    .join("MySolution/MySolution.sln");
+/// let project_file_path = std::env::temp_dir()
+///   .join("MySolution/ConsoleApp");
+/// let mut logger = ConsoleLogger::new();
+///
+/// remove_project_from_solution(&solution_file_path, &project_file_path, logger);
+/// ```
+pub fn remove_project_from_solution(
+    solution_file_path: &Path,
+    project_file_path: &Path,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    let solution_directory = solution_file_path.parent().unwrap_or(Path::new(""));
+    let project_path_relative = project_file_path
+        .strip_prefix(solution_directory)
+        .unwrap_or(project_file_path);
+
+    logger.write_info(format!(
+        "- 📄 Removing '{:}' from solution... ",
+        project_path_relative.to_string_lossy()
+    ))?;
+    logger.save_cursor_position()?;
+
+    let solution_file_path_string = solution_file_path.to_string_lossy().to_string();
+    let project_file_path_string = project_file_path.to_string_lossy().to_string();
+
+    let dotnet_proc_args = vec![
+        "sln",
+        solution_file_path_string.as_str(),
+        "remove",
+        project_file_path_string.as_str(),
+    ];
+
+    if logger.dry_run {
+        logger.write_dry_run_log(&[
+            format!("Would modify '{}'", solution_file_path.display()),
+            describe_planned_command("dotnet", &dotnet_proc_args, None)
+        ])?;
+
+        return Ok(());
+    }
+
+    let dotnet_output = process::Command::new("dotnet")
+        .args(dotnet_proc_args)
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&dotnet_output.stdout);
+
+    if !dotnet_output.status.success() {
+        let stderr = String::from_utf8_lossy(&dotnet_output.stderr);
+        let reason = stderr
+            .lines()
+            .chain(stdout.lines())
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty())
+            .unwrap_or("'dotnet sln remove' failed.");
+
+        logger.write_warning(format!("Not removed 🟠 ({})\n", reason))?;
+        return Ok(());
+    }
+
+    if stdout.contains("could not be found") {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
+
+    logger.write_operation_success_log()?;
+
+    Ok(())
+}
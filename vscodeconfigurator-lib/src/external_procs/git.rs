@@ -1,6 +1,10 @@
-use std::path::PathBuf;
+use std::path::Path;
 
-use crate::logging::{ConsoleLogger, OutputEmoji};
+use super::describe_planned_command;
+use crate::{
+    error::{CliError, CliErrorKind},
+    logging::{ConsoleLogger, OutputEmoji}
+};
 
 /// Initializes a Git repository in the output directory.
 ///
@@ -18,22 +22,32 @@ use crate::logging::{ConsoleLogger, OutputEmoji};
 /// Initializes a Git repository in the `my-project` directory in the temp
 /// directory.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{external_procs::git::initialize_git_repo, logging::ConsoleLogger};
 ///
 /// let output_directory = std::env::temp_dir().join("my-project");
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// initialize_git_repo(&output_directory, logger);
+/// initialize_git_repo(&output_directory, &mut logger).unwrap();
 /// ```
 pub fn initialize_git_repo(
-    output_directory: &PathBuf,
+    output_directory: &Path,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
     logger.write_operation_log("Initializing Git repository...", OutputEmoji::Package)?;
 
     let git_proc_args = vec!["init"];
 
+    if logger.dry_run {
+        logger.write_dry_run_log(&[describe_planned_command(
+            "git",
+            &git_proc_args,
+            Some(output_directory)
+        )])?;
+
+        return Ok(());
+    }
+
     std::process::Command::new("git")
         .args(git_proc_args)
         .current_dir(output_directory)
@@ -42,3 +56,180 @@ pub fn initialize_git_repo(
     logger.write_operation_success_log()?;
     Ok(())
 }
+
+/// Clones a Git repository.
+///
+/// # Arguments
+///
+/// - `source` - The path or URL of the repository to clone.
+/// - `destination` - The directory to clone the repository into.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Clones a local repository into the `my-templates` directory in the temp
+/// directory.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator_lib::external_procs::git;
+///
+/// let destination = std::env::temp_dir().join("my-templates");
+///
+/// git::clone_repo("/path/to/my-templates", &destination)?;
+/// ```
+pub fn clone_repo(
+    source: &str,
+    destination: &Path
+) -> Result<(), Box<dyn std::error::Error>> {
+    let destination_string = destination.to_string_lossy().to_string();
+
+    let git_proc_args = vec!["clone", "--quiet", source, destination_string.as_str()];
+
+    run_git_command(None, git_proc_args)
+}
+
+/// Pulls the latest changes into a Git repository.
+///
+/// Only fast-forward pulls are made, so local changes are never merged.
+///
+/// # Arguments
+///
+/// - `repo_directory` - The directory of the repository.
+pub fn pull_repo(repo_directory: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let git_proc_args = vec!["pull", "--quiet", "--ff-only"];
+
+    run_git_command(Some(repo_directory), git_proc_args)
+}
+
+/// Gets the commit that `HEAD` points to in a Git repository.
+///
+/// Returns `None` if Git is not installed or the directory is not in a Git
+/// repository.
+///
+/// # Arguments
+///
+/// - `repo_directory` - The directory of the repository.
+pub fn get_head_commit(repo_directory: &Path) -> Option<String> {
+    let git_proc_args = vec!["rev-parse", "HEAD"];
+
+    run_git_query(repo_directory, git_proc_args)
+}
+
+/// Gets a value from the Git configuration.
+///
+/// Returns `None` if Git is not installed or the value is not set.
+///
+/// # Arguments
+///
+/// - `output_directory` - The directory to run Git in.
+/// - `key` - The configuration key (e.g. `user.name`).
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Get the user's name from the Git configuration.
+///
+/// ```rust
+/// use vscodeconfigurator_lib::external_procs::git;
+///
+/// let output_directory = std::env::temp_dir();
+///
+/// let user_name = git::get_config_value(&output_directory, "user.name");
+/// ```
+pub fn get_config_value(
+    output_directory: &Path,
+    key: &str
+) -> Option<String> {
+    let git_proc_args = vec!["config", "--get", key];
+
+    run_git_query(output_directory, git_proc_args)
+}
+
+/// Gets the URL of the `origin` remote of the Git repository.
+///
+/// Returns `None` if Git is not installed, the directory is not in a Git
+/// repository or the repository has no `origin` remote.
+///
+/// # Arguments
+///
+/// - `output_directory` - The directory of the Git repository.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Get the remote URL of the repository in the current directory.
+///
+/// ```rust
+/// use vscodeconfigurator_lib::external_procs::git;
+///
+/// let output_directory = std::env::current_dir().unwrap();
+///
+/// let remote_url = git::get_remote_url(&output_directory);
+/// ```
+pub fn get_remote_url(output_directory: &Path) -> Option<String> {
+    let git_proc_args = vec!["remote", "get-url", "origin"];
+
+    run_git_query(output_directory, git_proc_args)
+}
+
+/// Runs a Git command that reads a value and returns its trimmed output.
+fn run_git_query(
+    output_directory: &Path,
+    git_proc_args: Vec<&str>
+) -> Option<String> {
+    if !output_directory.is_dir() {
+        return None;
+    }
+
+    let git_proc_output = std::process::Command::new("git")
+        .args(git_proc_args)
+        .current_dir(output_directory)
+        .output()
+        .ok()?;
+
+    if !git_proc_output.status.success() {
+        return None;
+    }
+
+    let value = String::from_utf8_lossy(&git_proc_output.stdout)
+        .trim()
+        .to_string();
+
+    match value.is_empty() {
+        true => None,
+        false => Some(value)
+    }
+}
+
+/// Runs a Git command and returns an error with Git's output if it fails.
+fn run_git_command(
+    working_directory: Option<&Path>,
+    git_proc_args: Vec<&str>
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut git_proc = std::process::Command::new("git");
+    git_proc.args(&git_proc_args);
+
+    if let Some(working_directory) = working_directory {
+        git_proc.current_dir(working_directory);
+    }
+
+    let git_proc_output = git_proc.output()?;
+
+    if !git_proc_output.status.success() {
+        return Err(CliError::new(
+            format!(
+                "'git {}' failed: {}",
+                git_proc_args.join(" "),
+                String::from_utf8_lossy(&git_proc_output.stderr).trim()
+            )
+            .as_str(),
+            CliErrorKind::GitCommandFailed
+        )
+        .into());
+    }
+
+    Ok(())
+}
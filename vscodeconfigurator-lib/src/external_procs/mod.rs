@@ -1,3 +1,64 @@
 pub mod cargo;
 pub mod dotnet;
 pub mod git;
+
+use std::path::Path;
+
+/// Describes an external command for a dry run, with its full argument list
+/// and working directory.
+///
+/// Arguments with whitespace in them are quoted.
+///
+/// # Arguments
+///
+/// - `program` - The program to run.
+/// - `args` - The arguments to the program.
+/// - `working_directory` - The directory the command runs in, if it isn't the
+///   current directory.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// use vscodeconfigurator_lib::external_procs::describe_planned_command;
+///
+/// let description = describe_planned_command(
+///     "cargo",
+///     &["init", "--lib", "my package"],
+///     Some(Path::new("/tmp/my-project"))
+/// );
+///
+/// assert_eq!(
+///     description,
+///     "Would run 'cargo init --lib \"my package\"' in '/tmp/my-project'"
+/// );
+/// ```
+pub fn describe_planned_command<S>(
+    program: &str,
+    args: &[S],
+    working_directory: Option<&Path>
+) -> String
+where
+    S: AsRef<str>
+{
+    let command_line = std::iter::once(program)
+        .chain(args.iter().map(|arg| arg.as_ref()))
+        .map(|arg| match arg.contains(char::is_whitespace) {
+            true => format!("\"{}\"", arg),
+            false => arg.to_string()
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    match working_directory {
+        Some(working_directory) => format!(
+            "Would run '{}' in '{}'",
+            command_line,
+            working_directory.display()
+        ),
+        None => format!("Would run '{}'", command_line)
+    }
+}
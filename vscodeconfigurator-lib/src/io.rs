@@ -6,7 +6,10 @@ use std::{
     path::{absolute, Path, PathBuf}
 };
 
-use crate::error::{CliError, CliErrorKind};
+use crate::{
+    error::{CliError, CliErrorKind},
+    logging::ConflictPolicy
+};
 
 /// Represents an output directory.
 #[derive(Clone, Debug, PartialEq)]
@@ -55,19 +58,15 @@ impl OutputDirectory {
     /// Resolves the home directory in the path if it starts with `~`.
     pub fn resolve_home_dir(&mut self) -> Result<OutputDirectory, CliError> {
         if self.path.starts_with("~") {
-            let home_dir_env_var_key = match env::consts::OS {
-                "windows" => "USERPROFILE",
-                "unix" | "macos" => "HOME",
-                _ => {
+            let home_dir = match get_home_dir() {
+                Some(home_dir) => home_dir,
+                None => {
                     return Err(CliError::new(
                         "The operating system is not supported.",
                         CliErrorKind::UnsupportedOperatingSystem
-                    )
-                    .into())
+                    ))
                 }
             };
-            let home_dir_env_var = env::var(home_dir_env_var_key).unwrap();
-            let home_dir = Path::new(&home_dir_env_var);
 
             self.path = PathBuf::from(&home_dir)
                 .join(self.path.strip_prefix("~").unwrap())
@@ -100,7 +99,7 @@ impl OutputDirectory {
 
     /// Converts the path to an absolute path.
     pub fn to_absolute(&self) -> PathBuf {
-        return absolute(self.as_pathbuf()).unwrap();
+        absolute(self.as_pathbuf()).unwrap()
     }
 }
 
@@ -113,14 +112,128 @@ impl fmt::Display for OutputDirectory {
     }
 }
 
-impl Into<clap::builder::OsStr> for OutputDirectory {
-    fn into(self) -> clap::builder::OsStr {
-        self.path.into()
+impl From<OutputDirectory> for clap::builder::OsStr {
+    fn from(val: OutputDirectory) -> Self {
+        val.path.into()
+    }
+}
+
+impl From<OutputDirectory> for PathBuf {
+    fn from(val: OutputDirectory) -> Self {
+        PathBuf::from(val.path)
     }
 }
 
-impl Into<PathBuf> for OutputDirectory {
-    fn into(self) -> PathBuf {
-        PathBuf::from(self.path)
+/// Gets the current user's home directory.
+///
+/// Returns `None` if the operating system is not supported or the home
+/// directory environment variable is not set.
+pub fn get_home_dir() -> Option<PathBuf> {
+    let home_dir_env_var_key = match env::consts::OS {
+        "windows" => "USERPROFILE",
+        "linux" | "macos" | "freebsd" | "netbsd" | "openbsd" => "HOME",
+        _ => return None
+    };
+
+    env::var_os(home_dir_env_var_key)
+        .filter(|home_dir| !home_dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Gets the current user's configuration directory.
+///
+/// This is `$XDG_CONFIG_HOME` if it is set. Otherwise it falls back to
+/// `%APPDATA%` on Windows and `~/.config` on every other operating system.
+pub fn get_config_dir() -> Option<PathBuf> {
+    if let Some(xdg_config_home) = env::var_os("XDG_CONFIG_HOME").filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(xdg_config_home));
+    }
+
+    match env::consts::OS {
+        "windows" => env::var_os("APPDATA")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from),
+
+        _ => get_home_dir().map(|home_dir| home_dir.join(".config"))
+    }
+}
+
+/// Gets the VSCode Configurator's directory in the user's configuration
+/// directory (e.g. `~/.config/vscode-configurator`).
+pub fn get_app_config_dir() -> Option<PathBuf> {
+    get_config_dir().map(|config_dir| config_dir.join("vscode-configurator"))
+}
+
+/// Copies a directory and everything in it.
+///
+/// `.git` directories are skipped.
+///
+/// # Arguments
+///
+/// - `source` - The directory to copy.
+/// - `destination` - The directory to copy to. It is created if it does not
+///   exist.
+pub fn copy_dir_recursive(
+    source: &Path,
+    destination: &Path
+) -> Result<(), io::Error> {
+    std::fs::create_dir_all(destination)?;
+
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let entry_destination = destination.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() == ".git" {
+                continue;
+            }
+
+            copy_dir_recursive(&entry.path(), &entry_destination)?;
+        } else {
+            std::fs::copy(entry.path(), entry_destination)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Describes writing a file or directory for a dry run.
+///
+/// # Arguments
+///
+/// - `path` - The path that would be written.
+/// - `on_conflict` - What would happen if the path already exists.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust
+/// use vscodeconfigurator_lib::{io::describe_planned_write, logging::ConflictPolicy};
+///
+/// let path = std::env::temp_dir().join("does-not-exist.json");
+///
+/// assert_eq!(
+///     describe_planned_write(&path, ConflictPolicy::Prompt),
+///     format!("Would create '{}'", path.display())
+/// );
+/// ```
+pub fn describe_planned_write(
+    path: &Path,
+    on_conflict: ConflictPolicy
+) -> String {
+    if !path.exists() {
+        return format!("Would create '{}'", path.display());
+    }
+
+    match on_conflict {
+        ConflictPolicy::Prompt => format!("Would ask to overwrite '{}'", path.display()),
+        ConflictPolicy::Overwrite => format!("Would overwrite '{}'", path.display()),
+        ConflictPolicy::Skip => format!("Would keep '{}'", path.display()),
+        ConflictPolicy::Merge => format!(
+            "Would merge into '{}', or keep it if it can't be merged",
+            path.display()
+        ),
+        ConflictPolicy::Fail => format!("Would fail because '{}' exists", path.display())
     }
 }
@@ -0,0 +1,13 @@
+use clap::ValueEnum;
+
+/// The language of a project.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum LanguageOption {
+    /// A Rust workspace.
+    #[value(name = "Rust")]
+    Rust,
+
+    /// A C# solution.
+    #[value(name = "Csharp")]
+    Csharp
+}
@@ -1,5 +1,7 @@
 mod csharp;
+mod language;
 mod rust;
 
 pub use csharp::CsharpLspOption;
+pub use language::LanguageOption;
 pub use rust::CargoPackageTemplateOption;
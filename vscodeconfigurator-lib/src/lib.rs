@@ -1,8 +1,8 @@
+pub mod dotnet;
 pub mod error;
 pub mod external_procs;
 pub mod io;
 pub mod lang_options;
 pub mod logging;
 pub mod template_ops;
-pub mod utils;
 pub mod vscode_ops;
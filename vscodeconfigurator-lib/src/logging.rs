@@ -1,13 +1,21 @@
 use std::{
     io::{Result, Stderr, Stdout, Write},
-    process
+    path::Path
 };
 
+use clap::ValueEnum;
 use crossterm::{
     cursor::{RestorePosition, SavePosition},
-    event::{read, Event, KeyCode, KeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    event::{
+        read,
+        Event,
+        KeyCode,
+        KeyEventKind,
+        KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags
+    },
     execute,
-    queue,
     style::{
         Attribute,
         Color,
@@ -20,6 +28,7 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType},
     tty::IsTty
 };
+use similar::TextDiff;
 
 /// Utility for writing to the console.
 pub struct ConsoleLogger {
@@ -27,7 +36,17 @@ pub struct ConsoleLogger {
     pub stdout: Stdout,
 
     /// The standard error stream.
-    pub stderr: Stderr
+    pub stderr: Stderr,
+
+    /// Whether the run is a dry run, where operations log what they would do
+    /// instead of writing files or running external commands.
+    pub dry_run: bool,
+
+    /// Whether to write a diff of each existing file that is changed.
+    pub show_diff: bool,
+
+    /// What to do with existing files and directories that would be replaced.
+    pub on_conflict: ConflictPolicy
 }
 
 #[allow(dead_code)]
@@ -64,7 +83,10 @@ impl ConsoleLogger {
 
         Self {
             stdout: stdout_item,
-            stderr: stderr_item
+            stderr: stderr_item,
+            dry_run: false,
+            show_diff: false,
+            on_conflict: ConflictPolicy::Prompt
         }
     }
 
@@ -121,7 +143,7 @@ impl ConsoleLogger {
         message: String
     ) -> Result<()> {
         if !self.stdout.is_tty() {
-            execute!(self.stdout, Print(format!("{}", &message)))?;
+            execute!(self.stdout, Print(message.to_string()))?;
 
             return Ok(());
         }
@@ -283,11 +305,11 @@ impl ConsoleLogger {
 
         execute!(
             self.stdout,
-            Print(format!("\n\n")),
+            Print("\n\n".to_string()),
             SetAttribute(Attribute::Bold),
             SetBackgroundColor(Color::Red),
             SetForegroundColor(Color::White),
-            Print(format!("🚨 Error ")),
+            Print("🚨 Error ".to_string()),
             ResetColor,
             SetAttribute(Attribute::Bold),
             SetForegroundColor(Color::Red),
@@ -296,13 +318,13 @@ impl ConsoleLogger {
             ResetColor
         )?;
 
-        if source_error_kind.is_some() {
+        if let Some(source_error_kind) = source_error_kind {
             execute!(
                 self.stdout,
                 SetAttribute(Attribute::Bold),
                 SetForegroundColor(Color::Red),
                 SetBackgroundColor(Color::Black),
-                Print(format!(" Kind: {} ", source_error_kind.unwrap())),
+                Print(format!(" Kind: {} ", source_error_kind)),
                 ResetColor
             )?;
         }
@@ -337,90 +359,289 @@ impl ConsoleLogger {
         )
     }
 
-    /// Ask the user if they want to overwrite a file.
-    pub fn ask_for_overwrite(&mut self) -> Result<bool> {
+    /// Decides what to do with an existing file or directory that would be
+    /// replaced, following the run's [`ConflictPolicy`].
+    ///
+    /// Returns `true` if it should be replaced. With the
+    /// [`Merge`](ConflictPolicy::Merge) policy it's kept, since only files can
+    /// be merged.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the existing file or directory.
+    pub fn resolve_conflict(
+        &mut self,
+        path: &Path
+    ) -> std::result::Result<bool, Box<dyn std::error::Error>> {
+        let overwrite_response =
+            self.resolve_conflict_with_preview(&path.display().to_string(), None)?;
+
+        Ok(overwrite_response == OverwriteResponse::Overwrite)
+    }
+
+    /// Decides what to do with an existing file that would be replaced,
+    /// following the run's [`ConflictPolicy`].
+    ///
+    /// When the user is prompted, they can see a diff of the change first.
+    /// [`Merge`](OverwriteResponse::Merge) is only returned if `can_merge` is
+    /// `true`, and the [`Merge`](ConflictPolicy::Merge) policy keeps the file
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_path` - The path of the file shown in the diff.
+    /// - `old_contents` - The current contents of the file.
+    /// - `new_contents` - The contents that would replace them.
+    /// - `can_merge` - Whether the new contents can be merged into the file.
+    pub fn resolve_file_conflict(
+        &mut self,
+        file_path: &str,
+        old_contents: &str,
+        new_contents: &str,
+        can_merge: bool
+    ) -> std::result::Result<OverwriteResponse, Box<dyn std::error::Error>> {
+        self.resolve_conflict_with_preview(
+            file_path,
+            Some(OverwritePreview {
+                file_path,
+                old_contents,
+                new_contents,
+                can_merge
+            })
+        )
+    }
+
+    /// Applies the run's [`ConflictPolicy`] to an existing file or directory.
+    fn resolve_conflict_with_preview(
+        &mut self,
+        path: &str,
+        preview: Option<OverwritePreview>
+    ) -> std::result::Result<OverwriteResponse, Box<dyn std::error::Error>> {
+        let can_merge = preview.as_ref().is_some_and(|preview| preview.can_merge);
+
+        match self.on_conflict {
+            ConflictPolicy::Prompt => self.prompt_for_overwrite(path, preview),
+            ConflictPolicy::Overwrite => Ok(OverwriteResponse::Overwrite),
+            ConflictPolicy::Skip => Ok(OverwriteResponse::Skip),
+            ConflictPolicy::Merge => Ok(match can_merge {
+                true => OverwriteResponse::Merge,
+                false => OverwriteResponse::Skip
+            }),
+            ConflictPolicy::Fail => Err(crate::error::CliError::new(
+                format!(
+                    "'{}' already exists. Use '--on-conflict' or '--force' to replace it.",
+                    path
+                )
+                .as_str(),
+                crate::error::CliErrorKind::FileConflict
+            )
+            .into())
+        }
+    }
+
+    /// Prompts the user to overwrite an existing file or directory.
+    ///
+    /// Answering `[d]iff` writes a diff of the change and asks again, and
+    /// `[m]erge` is only offered if the preview can be merged. Answering
+    /// `[a]ll` or `[s]kip-all` changes the run's [`ConflictPolicy`], so the
+    /// answer applies to the rest of the run. Answering `[q]uit` returns an
+    /// [`OperationCancelled`](crate::error::CliErrorKind::OperationCancelled)
+    /// error.
+    fn prompt_for_overwrite(
+        &mut self,
+        path: &str,
+        preview: Option<OverwritePreview>
+    ) -> std::result::Result<OverwriteResponse, Box<dyn std::error::Error>> {
         if !self.stdout.is_tty() {
-            panic!("Cannot ask for overwrite. Terminating...");
+            return Err(crate::error::CliError::new(
+                format!(
+                    "Cannot ask whether to overwrite '{}' without a terminal. Use '--on-conflict' to choose what happens to existing files.",
+                    path
+                )
+                .as_str(),
+                crate::error::CliErrorKind::FileConflict
+            )
+            .into());
         }
 
-        let supports_keyboard_enhancement = matches!(
-            crossterm::terminal::supports_keyboard_enhancement(),
-            Ok(true)
+        let can_merge = preview.as_ref().is_some_and(|preview| preview.can_merge);
+
+        let prompt = format!(
+            "✋ Overwrite? ([y]es/[n]o{}{}/[a]ll/[s]kip-all/[q]uit) ",
+            match preview.is_some() {
+                true => "/[d]iff",
+                false => ""
+            },
+            match can_merge {
+                true => "/[m]erge",
+                false => ""
+            }
         );
 
-        if supports_keyboard_enhancement {
-            queue!(
-                self.stdout,
-                PushKeyboardEnhancementFlags(
-                    KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
-                        | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES
-                        | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
-                        | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+        self.save_cursor_position()?;
+
+        execute!(
+            self.stdout,
+            SetForegroundColor(Color::Yellow),
+            Print(&prompt),
+            ResetColor
+        )?;
+
+        let result = loop {
+            match read_prompt_key()? {
+                'y' => break OverwriteResponse::Overwrite,
+                'n' => break OverwriteResponse::Skip,
+                'm' if can_merge => break OverwriteResponse::Merge,
+                'a' => {
+                    self.on_conflict = ConflictPolicy::Overwrite;
+                    break OverwriteResponse::Overwrite;
+                }
+                's' => {
+                    self.on_conflict = ConflictPolicy::Skip;
+                    break OverwriteResponse::Skip;
+                }
+                'd' if preview.is_some() => {
+                    let preview = preview.as_ref().unwrap();
+
+                    self.restore_cursor_position_and_clear_below()?;
+                    execute!(self.stdout, Print("\n"))?;
+                    self.write_diff(
+                        preview.file_path,
+                        preview.old_contents,
+                        preview.new_contents
+                    )?;
+                    self.save_cursor_position()?;
+
+                    execute!(
+                        self.stdout,
+                        SetForegroundColor(Color::Yellow),
+                        Print(&prompt),
+                        ResetColor
+                    )?;
+                }
+                'q' => {
+                    execute!(
+                        self.stdout,
+                        SetForegroundColor(Color::Red),
+                        Print("\n\n🛑 Quitting...\n"),
+                        ResetColor
+                    )?;
+
+                    return Err(crate::error::CliError::new(
+                        "The run was cancelled at an overwrite prompt.",
+                        crate::error::CliErrorKind::OperationCancelled
+                    )
+                    .into());
+                }
+                _ => {
+                    self.restore_cursor_position_and_clear_below()?;
+                    self.save_cursor_position()?;
+                    execute!(
+                        self.stdout,
+                        SetForegroundColor(Color::Red),
+                        Print("🛑 Invalid input. "),
+                        SetForegroundColor(Color::Yellow),
+                        Print(&prompt),
+                        ResetColor
+                    )?;
+                }
+            }
+        };
+
+        self.restore_cursor_position_and_clear_below()?;
+
+        Ok(result)
+    }
+
+    /// Asks the user to confirm an action that can't be undone, like deleting a
+    /// directory.
+    ///
+    /// Returns `true` if the user answers `[y]es`. Returns an error if there's
+    /// no terminal to ask in.
+    ///
+    /// # Arguments
+    ///
+    /// - `prompt` - The question to ask (e.g. `Delete 'my_package'?`).
+    pub fn confirm(
+        &mut self,
+        prompt: &str
+    ) -> std::result::Result<bool, Box<dyn std::error::Error>> {
+        if !self.stdout.is_tty() {
+            return Err(crate::error::CliError::new(
+                format!(
+                    "Cannot ask '{}' without a terminal. Use '--force' to skip the question.",
+                    prompt
                 )
-            )?;
+                .as_str(),
+                crate::error::CliErrorKind::OperationCancelled
+            )
+            .into());
         }
 
+        let prompt = format!("✋ {} ([y]es/[n]o) ", prompt);
+
         self.save_cursor_position()?;
 
         execute!(
             self.stdout,
             SetForegroundColor(Color::Yellow),
-            Print("✋ Overwrite? ([y]es/[n]o/[q]uit) "),
+            Print(&prompt),
             ResetColor
         )?;
 
-        // TODO: Figure out how to handle mofifier keys for closing the program.
-        enable_raw_mode()?;
-
-        let result;
-        loop {
-            match read()? {
-                Event::Key(event) => match event.code {
-                    KeyCode::Char('y') => {
-                        result = true;
-                        break;
-                    }
-                    KeyCode::Char('n') => {
-                        result = false;
-                        break;
-                    }
-                    KeyCode::Char('q') => {
-                        disable_raw_mode()?;
-                        execute!(
-                            self.stdout,
-                            SetForegroundColor(Color::Red),
-                            Print("\n\n🛑 Quitting...\n"),
-                            ResetColor
-                        )?;
-                        process::exit(1);
-                    }
-                    _ => {
-                        disable_raw_mode()?;
-                        self.restore_cursor_position_and_clear_below()?;
-                        self.save_cursor_position()?;
-                        execute!(
-                            self.stdout,
-                            SetForegroundColor(Color::Red),
-                            Print("🛑 Invalid input. "),
-                            SetForegroundColor(Color::Yellow),
-                            Print("✋ Overwrite? ([y]es/[n]o/[q]uit) "),
-                            ResetColor
-                        )?;
-                        enable_raw_mode()?;
-                        continue;
-                    }
-                },
-                _ => continue
+        let result = loop {
+            match read_prompt_key()? {
+                'y' => break true,
+                'n' => break false,
+                _ => {
+                    self.restore_cursor_position_and_clear_below()?;
+                    self.save_cursor_position()?;
+                    execute!(
+                        self.stdout,
+                        SetForegroundColor(Color::Red),
+                        Print("🛑 Invalid input. "),
+                        SetForegroundColor(Color::Yellow),
+                        Print(&prompt),
+                        ResetColor
+                    )?;
+                }
             }
-        }
-
-        disable_raw_mode()?;
+        };
 
         self.restore_cursor_position_and_clear_below()?;
 
         Ok(result)
     }
 
+    /// Ask the user for a value.
+    ///
+    /// # Arguments
+    ///
+    /// - `prompt` - The prompt to show the user.
+    pub fn ask_for_value(
+        &mut self,
+        prompt: &str
+    ) -> Result<String> {
+        if !self.stdout.is_tty() {
+            return Err(std::io::Error::other(format!(
+                "Cannot ask for '{}' without a terminal.",
+                prompt
+            )));
+        }
+
+        execute!(
+            self.stdout,
+            SetForegroundColor(Color::Yellow),
+            Print(format!("✋ {}: ", prompt)),
+            ResetColor
+        )?;
+
+        let mut value = String::new();
+        std::io::stdin().read_line(&mut value)?;
+
+        Ok(value.trim().to_string())
+    }
+
     /// Write a newline to the console.
     pub fn write_newline(&mut self) -> Result<()> {
         execute!(self.stdout, Print("\n"))
@@ -496,13 +717,117 @@ impl ConsoleLogger {
     /// ```
     pub fn write_operation_success_log(&mut self) -> Result<()> {
         let message = match self.stdout.is_tty() {
-            false => format!("Done!\n"),
+            false => "Done!\n".to_string(),
             true => format!("Done! {}\n", OutputEmoji::CheckMark)
         };
 
         self.write_success(message)
     }
 
+    /// Writes the log for an operation that was skipped because the run is a
+    /// dry run, with what the operation would have done.
+    ///
+    /// # Arguments
+    ///
+    /// - `planned_actions` - What the operation would have done (e.g. `Would
+    ///   create '/path/to/file'`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::logging::ConsoleLogger;
+    ///
+    /// let mut logger = ConsoleLogger::new(None, None);
+    ///
+    /// logger.write_dry_run_log(&["Would create '.vscode/settings.json'".to_string()]);
+    /// // "Skipped (dry run) 🟠"
+    /// // "    Would create '.vscode/settings.json'"
+    /// ```
+    pub fn write_dry_run_log(
+        &mut self,
+        planned_actions: &[String]
+    ) -> Result<()> {
+        match self.stdout.is_tty() {
+            false => self.write_success("Skipped (dry run)\n".to_string())?,
+            true => {
+                self.write_warning(format!("Skipped (dry run) {}\n", OutputEmoji::OrangeCircle))?
+            }
+        };
+
+        for planned_action in planned_actions {
+            self.write_info(format!("    {}\n", planned_action))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a unified diff of the changes to a file to the console.
+    ///
+    /// Added lines are written in green and removed lines in red.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_path` - The path to the file, for the diff header.
+    /// - `old_contents` - The current contents of the file.
+    /// - `new_contents` - The contents the file is changed to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::logging::ConsoleLogger;
+    ///
+    /// let mut logger = ConsoleLogger::new(None, None);
+    ///
+    /// logger.write_diff(
+    ///     ".vscode/settings.json",
+    ///     "{\n    \"a\": 1\n}\n",
+    ///     "{\n    \"a\": 2\n}\n"
+    /// );
+    /// ```
+    pub fn write_diff(
+        &mut self,
+        file_path: &str,
+        old_contents: &str,
+        new_contents: &str
+    ) -> Result<()> {
+        if old_contents == new_contents {
+            return self.write_info(format!("    No changes to '{}'.\n", file_path));
+        }
+
+        let text_diff = TextDiff::from_lines(old_contents, new_contents);
+
+        let unified_diff = text_diff
+            .unified_diff()
+            .context_radius(3)
+            .header(&format!("a/{}", file_path), &format!("b/{}", file_path))
+            .to_string();
+
+        let is_tty = self.stdout.is_tty();
+
+        for line in unified_diff.lines() {
+            let color = match line.chars().next() {
+                _ if line.starts_with("---") || line.starts_with("+++") => Color::White,
+                Some('+') => Color::Green,
+                Some('-') => Color::Red,
+                Some('@') => Color::Cyan,
+                _ => Color::Reset
+            };
+
+            match is_tty {
+                false => execute!(self.stdout, Print(format!("{}\n", line)))?,
+                true => execute!(
+                    self.stdout,
+                    SetForegroundColor(color),
+                    Print(line),
+                    ResetColor,
+                    Print("\n")
+                )?
+            }
+        }
+
+        Ok(())
+    }
+
     /// Writes a project initialized log to the console.
     ///
     /// # Example
@@ -515,8 +840,12 @@ impl ConsoleLogger {
     /// logger.write_project_initialized_log(); // "🥳 VSCode project initialized!"
     /// ```
     pub fn write_project_initialized_log(&mut self) -> Result<()> {
+        if self.dry_run {
+            return self.write_info("Dry run finished. Nothing was changed.\n".to_string());
+        }
+
         let message = match self.stdout.is_tty() {
-            false => format!("VSCode project initialized!\n"),
+            false => "VSCode project initialized!\n".to_string(),
             true => format!("{} VSCode project initialized!\n", OutputEmoji::Party)
         };
 
@@ -531,6 +860,112 @@ impl ConsoleLogger {
     }
 }
 
+/// What to do with an existing file or directory that a command would replace.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum ConflictPolicy {
+    /// Ask the user for each one.
+    Prompt,
+
+    /// Replace it.
+    Overwrite,
+
+    /// Keep it.
+    Skip,
+
+    /// Merge JSON files into the existing ones, and keep anything else.
+    Merge,
+
+    /// Stop the run with an error.
+    Fail
+}
+
+/// The user's answer to an overwrite prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverwriteResponse {
+    /// Replace the existing file.
+    Overwrite,
+
+    /// Keep the existing file.
+    Skip,
+
+    /// Merge the new contents into the existing file.
+    Merge
+}
+
+/// The change to a file shown when the user asks for a diff at an overwrite
+/// prompt.
+struct OverwritePreview<'a> {
+    /// The path of the file shown in the diff.
+    file_path: &'a str,
+
+    /// The current contents of the file.
+    old_contents: &'a str,
+
+    /// The contents that would replace them.
+    new_contents: &'a str,
+
+    /// Whether the new contents can be merged into the file.
+    can_merge: bool
+}
+
+/// Puts the terminal back into its normal input mode when dropped, so an error
+/// while reading a key doesn't leave raw mode on.
+struct RawModeGuard {
+    /// Whether keyboard enhancement flags were pushed and need to be popped.
+    keyboard_enhancement: bool
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+
+        if self.keyboard_enhancement {
+            let _ = execute!(std::io::stdout(), PopKeyboardEnhancementFlags);
+        }
+    }
+}
+
+/// Reads a single key press for a prompt, with the terminal in raw mode only
+/// while waiting for it.
+fn read_prompt_key() -> Result<char> {
+    let keyboard_enhancement = matches!(
+        crossterm::terminal::supports_keyboard_enhancement(),
+        Ok(true)
+    );
+
+    if keyboard_enhancement {
+        execute!(
+            std::io::stdout(),
+            PushKeyboardEnhancementFlags(
+                KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                    | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES
+                    | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
+                    | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+            )
+        )?;
+    }
+
+    let _raw_mode_guard = RawModeGuard {
+        keyboard_enhancement
+    };
+
+    // TODO: Figure out how to handle mofifier keys for closing the program.
+    enable_raw_mode()?;
+
+    loop {
+        if let Event::Key(event) = read()? {
+            if event.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            return Ok(match event.code {
+                KeyCode::Char(key) => key.to_ascii_lowercase(),
+                _ => '\0'
+            });
+        }
+    }
+}
+
 /// An emoji to use in the console.
 #[allow(dead_code)]
 pub enum OutputEmoji {
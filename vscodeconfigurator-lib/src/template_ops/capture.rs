@@ -0,0 +1,777 @@
+use std::{
+    fmt,
+    fs,
+    path::{Path, PathBuf}
+};
+
+use serde_json::Value;
+
+use super::{
+    pack::{TemplatePack, TemplatePackStep, TemplatePackStepAction, TEMPLATE_PACK_MANIFEST_FILE},
+    variables::builtin_variables,
+    TemplateSource
+};
+use crate::{
+    dotnet::solution::SolutionFormat,
+    error::{CliError, CliErrorKind},
+    logging::{ConsoleLogger, OutputEmoji},
+    vscode_ops::{jsonc::JsoncDocument, tasks::TasksConfig, VSCodeSettingsFile, VSCodeTasksFile}
+};
+
+/// The kind of project a template pack is captured from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CapturedProjectKind {
+    /// A Rust workspace, laid out like the built-in `rust` pack.
+    Rust,
+
+    /// A .NET solution, laid out like the built-in `csharp` pack.
+    CSharp
+}
+
+impl CapturedProjectKind {
+    /// Detects the kind of project in a directory.
+    ///
+    /// A directory with a `Cargo.toml` that has a `[workspace]` table is a Rust
+    /// project. A directory with a `.sln` or `.slnx` file is a .NET project.
+    ///
+    /// # Arguments
+    ///
+    /// - `project_directory` - The root directory of the project.
+    pub fn detect(project_directory: &Path) -> Option<Self> {
+        if read_cargo_workspace(project_directory).is_some() {
+            return Some(Self::Rust);
+        }
+
+        if find_solution_name(project_directory).is_some() {
+            return Some(Self::CSharp);
+        }
+
+        None
+    }
+
+    /// Gets the name of the built-in template pack for the kind of project.
+    pub fn builtin_pack_name(&self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::CSharp => "csharp"
+        }
+    }
+
+    /// Gets the ids of the `tasks.json` inputs that list the project's
+    /// packages, which the `add` commands append to.
+    fn project_input_ids(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &["packageName"],
+            Self::CSharp => &["projectItem", "watchProject", "runProject"]
+        }
+    }
+}
+
+impl fmt::Display for CapturedProjectKind {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>
+    ) -> fmt::Result {
+        match self {
+            Self::Rust => write!(f, "Rust"),
+            Self::CSharp => write!(f, "C#")
+        }
+    }
+}
+
+/// A concrete value in the project that is turned back into a placeholder.
+struct Placeholder {
+    /// The value found in the project.
+    value: String,
+
+    /// The name of the variable that replaces it.
+    variable_name: &'static str
+}
+
+/// A file captured from the project.
+struct CapturedFile {
+    /// The path to the template file, relative to the pack.
+    template: String,
+
+    /// The path the pack writes the file to, relative to the output directory.
+    destination: String,
+
+    /// The contents of the template file.
+    contents: String
+}
+
+/// Captures a project's `.vscode` files and related root files as a template
+/// pack.
+///
+/// The pack is laid out like the built-in pack for the kind of project
+/// (`rust` or `csharp`), with that pack's steps, so it can be used as a
+/// replacement for it. The following files are captured:
+///
+/// - Every file in `.vscode/`.
+/// - `.gitignore`.
+/// - The `[workspace]` table of `Cargo.toml`, with its `members` emptied.
+/// - `global.json`.
+///
+/// Values that the built-in templates parameterize are turned back into
+/// placeholders: the solution name (`{{solutionName}}`), the base package name
+/// (`{{basePackageName}}`) and, in `Cargo.toml`, the Git user and repository
+/// URL. Options in `tasks.json` for packages or projects added after the
+/// project was created are dropped.
+///
+/// # Arguments
+///
+/// - `project_directory` - The root directory of the project to capture.
+/// - `pack_name` - The name of the pack to write.
+/// - `templates_directory` - The templates directory to write the pack to.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Capture a project as the user's `rust` pack.
+///
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     logging::ConsoleLogger,
+///     template_ops::{capture::capture_template_pack, search_path::get_user_templates_dir}
+/// };
+///
+/// let project_directory = std::env::current_dir().unwrap();
+/// let templates_directory = get_user_templates_dir().unwrap();
+/// let mut logger = ConsoleLogger::new(None, None);
+///
+/// capture_template_pack(&project_directory, "rust", &templates_directory, &mut logger).unwrap();
+/// ```
+pub fn capture_template_pack(
+    project_directory: &Path,
+    pack_name: &str,
+    templates_directory: &Path,
+    logger: &mut ConsoleLogger
+) -> Result<Option<PathBuf>, Box<dyn std::error::Error>> {
+    validate_pack_name(pack_name)?;
+
+    let project_kind = match CapturedProjectKind::detect(project_directory) {
+        Some(project_kind) => project_kind,
+        None => {
+            return Err(CliError::new(
+                format!(
+                    "'{}' is not a Rust workspace or a .NET solution. Capturing needs a \
+                     'Cargo.toml' with a '[workspace]' table or a '.sln' or '.slnx' file.",
+                    project_directory.display()
+                )
+                .as_str(),
+                CliErrorKind::TemplateCaptureFailed
+            )
+            .into())
+        }
+    };
+
+    let placeholders = find_placeholders(project_directory, project_kind);
+
+    logger.write_operation_category(format!("Capturing {} project", project_kind).as_str())?;
+
+    for placeholder in &placeholders {
+        logger.write_operation_log(
+            format!(
+                "Replacing '{}' with '{{{{{}}}}}'...",
+                placeholder.value, placeholder.variable_name
+            )
+            .as_str(),
+            OutputEmoji::Document
+        )?;
+        logger.write_operation_success_log()?;
+    }
+
+    let builtin_pack =
+        TemplatePack::from_source(project_kind.builtin_pack_name(), TemplateSource::Embedded)?;
+
+    let mut captured_files = capture_vscode_files(project_directory, project_kind, &placeholders)?;
+
+    if let Some(captured_file) = capture_gitignore(project_directory, &placeholders)? {
+        captured_files.push(captured_file);
+    }
+
+    if let Some(captured_file) = capture_cargo_workspace(project_directory, &placeholders)? {
+        captured_files.push(captured_file);
+    }
+
+    if let Some(captured_file) = capture_global_json(project_directory, &placeholders)? {
+        captured_files.push(captured_file);
+    }
+
+    let mut manifest = builtin_pack.manifest.clone();
+
+    for captured_file in &captured_files {
+        add_captured_file_step(&mut manifest.steps, captured_file);
+    }
+
+    let pack_directory = templates_directory.join(pack_name);
+
+    logger.write_operation_log(
+        format!(
+            "Creating '{}' pack in '{}'...",
+            pack_name,
+            templates_directory.display()
+        )
+        .as_str(),
+        OutputEmoji::Folder
+    )?;
+
+    if pack_directory.exists() {
+        if !logger.resolve_conflict(&pack_directory)? {
+            logger.write_warning("Already exists 🟠\n".to_string())?;
+            return Ok(None);
+        }
+
+        fs::remove_dir_all(&pack_directory)?;
+    }
+
+    fs::create_dir_all(&pack_directory)?;
+
+    logger.write_operation_success_log()?;
+
+    for captured_file in &captured_files {
+        logger.write_operation_log(
+            format!(
+                "Capturing '{}' as '{}'...",
+                captured_file.destination, captured_file.template
+            )
+            .as_str(),
+            OutputEmoji::Document
+        )?;
+
+        write_pack_file(
+            &pack_directory,
+            &captured_file.template,
+            captured_file.contents.as_bytes()
+        )?;
+
+        logger.write_operation_success_log()?;
+    }
+
+    // Template files of the built-in pack that weren't captured are copied as
+    // they are, so the pack works under any name.
+    for step in &manifest.steps {
+        let TemplatePackStepAction::File { template, .. } = &step.action else {
+            continue;
+        };
+
+        if captured_files
+            .iter()
+            .any(|captured_file| &captured_file.template == template)
+        {
+            continue;
+        }
+
+        if let Some(contents) =
+            TemplateSource::Embedded.read(&builtin_pack.template_path(template))?
+        {
+            write_pack_file(&pack_directory, template, &contents)?;
+        }
+    }
+
+    logger.write_operation_log(
+        format!("Writing '{}'...", TEMPLATE_PACK_MANIFEST_FILE).as_str(),
+        OutputEmoji::Document
+    )?;
+
+    write_pack_file(
+        &pack_directory,
+        TEMPLATE_PACK_MANIFEST_FILE,
+        toml::to_string(&manifest)?.as_bytes()
+    )?;
+
+    logger.write_operation_success_log()?;
+    logger.write_newline()?;
+
+    Ok(Some(pack_directory))
+}
+
+/// Checks that a pack name can be used as a directory name.
+fn validate_pack_name(pack_name: &str) -> Result<(), CliError> {
+    let is_valid = !pack_name.is_empty()
+        && !pack_name.starts_with('.')
+        && pack_name
+            .chars()
+            .all(|character| character.is_alphanumeric() || "-_.".contains(character));
+
+    match is_valid {
+        true => Ok(()),
+        false => Err(CliError::new(
+            format!(
+                "'{}' is not a valid template pack name. Use letters, numbers, '-', '_' and '.'.",
+                pack_name
+            )
+            .as_str(),
+            CliErrorKind::TemplateCaptureFailed
+        ))
+    }
+}
+
+/// Finds the concrete values in the project that the built-in templates
+/// parameterize.
+fn find_placeholders(
+    project_directory: &Path,
+    project_kind: CapturedProjectKind
+) -> Vec<Placeholder> {
+    let mut placeholders = Vec::new();
+
+    match project_kind {
+        CapturedProjectKind::Rust => {
+            let base_package_name = read_cargo_workspace(project_directory)
+                .and_then(|workspace| {
+                    workspace
+                        .get("members")
+                        .and_then(|members| members.as_array())
+                        .and_then(|members| members.first())
+                        .and_then(|member| member.as_str())
+                        .map(|member| member.to_string())
+                })
+                .and_then(|member| {
+                    Path::new(&member)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                });
+
+            if let Some(base_package_name) = base_package_name {
+                placeholders.push(Placeholder {
+                    value: base_package_name,
+                    variable_name: "basePackageName"
+                });
+            }
+        }
+
+        CapturedProjectKind::CSharp => {
+            if let Some(solution_name) = find_solution_name(project_directory) {
+                placeholders.push(Placeholder {
+                    value: solution_name,
+                    variable_name: "solutionName"
+                });
+            }
+        }
+    }
+
+    placeholders
+}
+
+/// Finds the values of the built-in variables that `Cargo.toml` templates use.
+fn find_cargo_placeholders(project_directory: &Path) -> Vec<Placeholder> {
+    let builtin_context = builtin_variables(project_directory);
+
+    let mut placeholders = [
+        "repositoryUrl",
+        "gitRemoteUrl",
+        "gitUserEmail",
+        "gitUserName"
+    ]
+    .into_iter()
+    .filter_map(|variable_name| {
+        builtin_context
+            .get(variable_name)
+            .and_then(|value| value.as_str())
+            .filter(|value| !value.is_empty())
+            .map(|value| Placeholder {
+                value: value.to_string(),
+                variable_name
+            })
+    })
+    .collect::<Vec<Placeholder>>();
+
+    // A longer value may contain a shorter one (e.g. the repository URL and
+    // the user name), so it is replaced first.
+    placeholders.sort_by_key(|placeholder| std::cmp::Reverse(placeholder.value.len()));
+
+    placeholders
+}
+
+/// Captures the files in the project's `.vscode` directory.
+fn capture_vscode_files(
+    project_directory: &Path,
+    project_kind: CapturedProjectKind,
+    placeholders: &[Placeholder]
+) -> Result<Vec<CapturedFile>, Box<dyn std::error::Error>> {
+    let vscode_directory = project_directory.join(".vscode");
+
+    if !vscode_directory.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut file_names = fs::read_dir(&vscode_directory)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+
+    file_names.sort();
+
+    let mut captured_files = Vec::new();
+
+    for file_name in file_names {
+        let file_path = vscode_directory.join(&file_name);
+
+        let contents = match file_name.as_str() {
+            "settings.json" => {
+                let mut vscode_settings = VSCodeSettingsFile::new(file_path)?;
+                vscode_settings
+                    .values
+                    .values_mut()
+                    .for_each(|value| replace_in_json(value, placeholders));
+
+                vscode_settings.to_json()?
+            }
+
+            "tasks.json" => {
+                let mut vscode_tasks = VSCodeTasksFile::new(file_path)?;
+                let mut tasks = serde_json::to_value(&vscode_tasks.values)?;
+                replace_in_json(&mut tasks, placeholders);
+
+                vscode_tasks.values = serde_json::from_value(tasks)?;
+                remove_added_project_inputs(&mut vscode_tasks.values, project_kind);
+
+                vscode_tasks.to_json()?
+            }
+
+            _ => {
+                let file_contents = fs::read_to_string(&file_path)?;
+
+                match JsoncDocument::parse(&file_contents) {
+                    Ok(mut document) if file_name.ends_with(".json") => {
+                        let mut values = document.value();
+                        replace_in_json(&mut values, placeholders);
+                        document.update(&values)?;
+
+                        document.text().to_string()
+                    }
+
+                    _ => replace_in_text(&file_contents, placeholders)
+                }
+            }
+        };
+
+        captured_files.push(CapturedFile {
+            template: format!("VSCode/{}", file_name),
+            destination: format!(".vscode/{}", file_name),
+            contents: escape_template_syntax(&contents, placeholders)
+        });
+    }
+
+    Ok(captured_files)
+}
+
+/// Captures the project's `.gitignore` file.
+fn capture_gitignore(
+    project_directory: &Path,
+    placeholders: &[Placeholder]
+) -> Result<Option<CapturedFile>, Box<dyn std::error::Error>> {
+    let gitignore_path = project_directory.join(".gitignore");
+
+    if !gitignore_path.is_file() {
+        return Ok(None);
+    }
+
+    let contents = replace_in_text(&fs::read_to_string(gitignore_path)?, placeholders);
+
+    Ok(Some(CapturedFile {
+        template: "Git/gitignore".to_string(),
+        destination: ".gitignore".to_string(),
+        contents: escape_template_syntax(&contents, placeholders)
+    }))
+}
+
+/// Captures the `[workspace]` table of the project's `Cargo.toml` file, with
+/// its `members` emptied.
+fn capture_cargo_workspace(
+    project_directory: &Path,
+    placeholders: &[Placeholder]
+) -> Result<Option<CapturedFile>, Box<dyn std::error::Error>> {
+    let Some(mut workspace) = read_cargo_workspace(project_directory) else {
+        return Ok(None);
+    };
+
+    workspace.insert("members".to_string(), toml::Value::Array(Vec::new()));
+
+    let mut cargo_placeholders = find_cargo_placeholders(project_directory);
+    cargo_placeholders.extend(placeholders.iter().map(|placeholder| Placeholder {
+        value: placeholder.value.clone(),
+        variable_name: placeholder.variable_name
+    }));
+
+    let mut workspace = toml::Value::Table(workspace);
+    replace_in_toml(&mut workspace, &cargo_placeholders);
+
+    let mut cargo_manifest = toml::Table::new();
+    cargo_manifest.insert("workspace".to_string(), workspace);
+
+    let mut contents =
+        escape_template_syntax(&toml::to_string(&cargo_manifest)?, &cargo_placeholders);
+
+    // The current user as the only author is written from the `authors`
+    // variable, like the built-in template does, so a new project without a
+    // Git user doesn't get an empty author.
+    for captured_authors in [
+        "authors = [\"{{gitUserName}} <{{gitUserEmail}}>\"]",
+        "authors = [\"{{gitUserName}}\"]"
+    ] {
+        contents = contents.replace(
+            captured_authors,
+            "authors = [{{#each authors}}\"{{this}}\"{{#unless @last}}, {{/unless}}{{/each}}]"
+        );
+    }
+
+    Ok(Some(CapturedFile {
+        template: "Cargo/Cargo.workspace.toml".to_string(),
+        destination: "Cargo.toml".to_string(),
+        contents
+    }))
+}
+
+/// Captures the project's `global.json` file.
+fn capture_global_json(
+    project_directory: &Path,
+    placeholders: &[Placeholder]
+) -> Result<Option<CapturedFile>, Box<dyn std::error::Error>> {
+    let global_json_path = project_directory.join("global.json");
+
+    if !global_json_path.is_file() {
+        return Ok(None);
+    }
+
+    // `dotnet` allows comments in `global.json`, so they're kept like in the
+    // VSCode files.
+    let mut global_json = JsoncDocument::parse(&fs::read_to_string(&global_json_path)?)
+        .map_err(|e| e.in_file(&global_json_path.to_string_lossy()))?;
+
+    let mut values = global_json.value();
+    replace_in_json(&mut values, placeholders);
+    global_json.update(&values)?;
+
+    Ok(Some(CapturedFile {
+        template: "DotNet/global.json".to_string(),
+        destination: "global.json".to_string(),
+        contents: escape_template_syntax(global_json.text(), placeholders)
+    }))
+}
+
+/// Points the step that writes a captured file at the captured template.
+///
+/// A step that writes the file from a template uses the captured template
+/// instead. A step that runs a command to create the file (e.g. `dotnet new
+/// gitignore`) is replaced with one that writes the captured template. Files
+/// that no step writes get a new step at the end.
+fn add_captured_file_step(
+    steps: &mut Vec<TemplatePackStep>,
+    captured_file: &CapturedFile
+) {
+    let captured_action = TemplatePackStepAction::File {
+        template: captured_file.template.clone(),
+        destination: captured_file.destination.clone()
+    };
+
+    let existing_step = steps.iter_mut().find(|step| match &step.action {
+        TemplatePackStepAction::File { destination, .. } => {
+            destination == &captured_file.destination
+        }
+        TemplatePackStepAction::Command { creates, .. } => {
+            creates.as_deref() == Some(captured_file.destination.as_str())
+        }
+    });
+
+    match existing_step {
+        Some(step) => step.action = captured_action,
+        None => {
+            let category = match captured_file.destination.starts_with(".vscode/") {
+                true => "VSCode",
+                false => "Basic"
+            };
+
+            steps.push(TemplatePackStep {
+                category: Some(category.to_string()),
+                when: None,
+                skip_if_exists: false,
+                action: captured_action
+            });
+        }
+    }
+}
+
+/// Writes a file to the pack directory, creating missing directories.
+fn write_pack_file(
+    pack_directory: &Path,
+    relative_path: &str,
+    contents: &[u8]
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file_path = pack_directory.join(relative_path);
+
+    if let Some(parent_directory) = file_path.parent() {
+        fs::create_dir_all(parent_directory)?;
+    }
+
+    fs::write(file_path, contents)?;
+
+    Ok(())
+}
+
+/// Reads the `[workspace]` table of the project's `Cargo.toml` file.
+fn read_cargo_workspace(project_directory: &Path) -> Option<toml::Table> {
+    let cargo_manifest = fs::read_to_string(project_directory.join("Cargo.toml")).ok()?;
+    let mut cargo_manifest = toml::from_str::<toml::Table>(&cargo_manifest).ok()?;
+
+    match cargo_manifest.remove("workspace") {
+        Some(toml::Value::Table(workspace)) => Some(workspace),
+        _ => None
+    }
+}
+
+/// Finds the name of the solution file in the project's root directory,
+/// without its extension.
+fn find_solution_name(project_directory: &Path) -> Option<String> {
+    let mut solution_names = fs::read_dir(project_directory)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && SolutionFormat::from_path(path).is_some())
+        .filter_map(|path| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+        })
+        .collect::<Vec<String>>();
+
+    solution_names.sort();
+
+    solution_names.into_iter().next()
+}
+
+/// Drops the options of the `tasks.json` inputs that list the project's
+/// packages, except the ones that were turned into placeholders.
+///
+/// The options left are the ones the built-in template writes (e.g. the base
+/// package or the solution), so packages added to the captured project aren't
+/// added to new projects.
+fn remove_added_project_inputs(
+    tasks: &mut TasksConfig,
+    project_kind: CapturedProjectKind
+) {
+    for input in tasks.inputs.iter_mut().flatten() {
+        if !project_kind
+            .project_input_ids()
+            .contains(&input.id.as_str())
+        {
+            continue;
+        }
+
+        if let Some(options) = &mut input.options {
+            options.retain(|option| option.value().contains("{{"));
+        }
+    }
+}
+
+/// Replaces the placeholder values in every string in a JSON value.
+fn replace_in_json(
+    value: &mut Value,
+    placeholders: &[Placeholder]
+) {
+    match value {
+        Value::String(string) => *string = replace_in_text(string, placeholders),
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| replace_in_json(item, placeholders)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|item| replace_in_json(item, placeholders)),
+        _ => {}
+    }
+}
+
+/// Replaces the placeholder values in every string in a TOML value.
+fn replace_in_toml(
+    value: &mut toml::Value,
+    placeholders: &[Placeholder]
+) {
+    match value {
+        toml::Value::String(string) => *string = replace_in_text(string, placeholders),
+        toml::Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| replace_in_toml(item, placeholders)),
+        toml::Value::Table(table) => table
+            .iter_mut()
+            .for_each(|(_, item)| replace_in_toml(item, placeholders)),
+        _ => {}
+    }
+}
+
+/// Replaces the placeholder values in text with `{{variable}}` tags.
+///
+/// A value is only replaced where it isn't part of a longer name, so the base
+/// package `app` doesn't replace the `app` in `app_core`.
+fn replace_in_text(
+    text: &str,
+    placeholders: &[Placeholder]
+) -> String {
+    let mut replaced_text = text.to_string();
+
+    for placeholder in placeholders {
+        let tag = format!("{{{{{}}}}}", placeholder.variable_name);
+        let mut result = String::with_capacity(replaced_text.len());
+        let mut remaining = replaced_text.as_str();
+
+        while let Some(index) = remaining.find(&placeholder.value) {
+            let before = remaining[..index].chars().next_back();
+            let after = remaining[index + placeholder.value.len()..].chars().next();
+
+            result.push_str(&remaining[..index]);
+
+            match !before.is_some_and(is_name_character) && !after.is_some_and(is_name_character) {
+                true => result.push_str(&tag),
+                false => result.push_str(&placeholder.value)
+            }
+
+            remaining = &remaining[index + placeholder.value.len()..];
+        }
+
+        result.push_str(remaining);
+        replaced_text = result;
+    }
+
+    replaced_text
+}
+
+/// Whether a character can be part of a package or solution name.
+fn is_name_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_' || character == '-'
+}
+
+/// Escapes `{{` in captured text, except in the `{{variable}}` tags that were
+/// added for placeholders, so the text renders as it was.
+fn escape_template_syntax(
+    text: &str,
+    placeholders: &[Placeholder]
+) -> String {
+    let tags = placeholders
+        .iter()
+        .map(|placeholder| format!("{{{{{}}}}}", placeholder.variable_name))
+        .collect::<Vec<String>>();
+
+    let mut escaped_text = String::with_capacity(text.len());
+    let mut remaining = text;
+
+    while let Some(index) = remaining.find("{{") {
+        escaped_text.push_str(&remaining[..index]);
+
+        if !tags
+            .iter()
+            .any(|tag| remaining[index..].starts_with(tag.as_str()))
+        {
+            escaped_text.push('\\');
+        }
+
+        escaped_text.push_str("{{");
+        remaining = &remaining[index + 2..];
+    }
+
+    escaped_text.push_str(remaining);
+
+    escaped_text
+}
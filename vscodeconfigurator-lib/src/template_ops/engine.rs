@@ -0,0 +1,956 @@
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::error::{CliError, CliErrorKind};
+
+/// The format of a rendered template, which decides how variable values are
+/// escaped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// JSON. Values are escaped for use inside a JSON string.
+    Json,
+
+    /// TOML. Values are escaped for use inside a TOML basic string.
+    Toml,
+
+    /// Any other text file. Values are not escaped.
+    Plain
+}
+
+impl OutputFormat {
+    /// Gets the output format for a file from its extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_name` - The name of the file.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::template_ops::engine::OutputFormat;
+    ///
+    /// assert_eq!(
+    ///     OutputFormat::from_file_name("tasks.json"),
+    ///     OutputFormat::Json
+    /// );
+    /// assert_eq!(
+    ///     OutputFormat::from_file_name("Cargo.toml"),
+    ///     OutputFormat::Toml
+    /// );
+    /// assert_eq!(
+    ///     OutputFormat::from_file_name(".gitignore"),
+    ///     OutputFormat::Plain
+    /// );
+    /// ```
+    pub fn from_file_name(file_name: &str) -> Self {
+        let extension = Path::new(file_name)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+
+        match extension.as_deref() {
+            Some("json") | Some("jsonc") | Some("code-workspace") => OutputFormat::Json,
+            Some("toml") => OutputFormat::Toml,
+            _ => OutputFormat::Plain
+        }
+    }
+
+    /// Escapes a string for the output format.
+    ///
+    /// # Arguments
+    ///
+    /// - `value` - The string to escape.
+    pub fn escape(
+        &self,
+        value: &str
+    ) -> String {
+        match self {
+            OutputFormat::Plain => value.to_string(),
+
+            OutputFormat::Json | OutputFormat::Toml => {
+                let mut escaped = String::with_capacity(value.len());
+
+                for character in value.chars() {
+                    match character {
+                        '"' => escaped.push_str("\\\""),
+                        '\\' => escaped.push_str("\\\\"),
+                        '\n' => escaped.push_str("\\n"),
+                        '\r' => escaped.push_str("\\r"),
+                        '\t' => escaped.push_str("\\t"),
+                        '\u{08}' => escaped.push_str("\\b"),
+                        '\u{0C}' => escaped.push_str("\\f"),
+                        character if character.is_control() => {
+                            escaped.push_str(&format!("\\u{:04X}", character as u32))
+                        }
+                        character => escaped.push(character)
+                    }
+                }
+
+                escaped
+            }
+        }
+    }
+}
+
+/// The variables available to a template while it is rendered.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TemplateContext {
+    /// The variables, by name.
+    pub variables: Map<String, Value>
+}
+
+impl TemplateContext {
+    /// Creates a new, empty `TemplateContext`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a variable, replacing any existing value.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the variable.
+    /// - `value` - The value of the variable.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::template_ops::engine::TemplateContext;
+    ///
+    /// let context = TemplateContext::new()
+    ///     .with("basePackageName", "my_package")
+    ///     .with("authors", vec!["Jane Doe"]);
+    /// ```
+    pub fn with<V>(
+        mut self,
+        name: &str,
+        value: V
+    ) -> Self
+    where
+        V: Into<Value>
+    {
+        self.set(name, value);
+
+        self
+    }
+
+    /// Sets a variable, replacing any existing value.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the variable.
+    /// - `value` - The value of the variable.
+    pub fn set<V>(
+        &mut self,
+        name: &str,
+        value: V
+    ) where
+        V: Into<Value>
+    {
+        self.variables.insert(name.to_string(), value.into());
+    }
+
+    /// Sets every variable from another context, replacing existing values.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` - The context to take the variables from.
+    pub fn extend(
+        &mut self,
+        other: TemplateContext
+    ) {
+        self.variables.extend(other.variables);
+    }
+
+    /// Gets a variable.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the variable.
+    pub fn get(
+        &self,
+        name: &str
+    ) -> Option<&Value> {
+        self.variables.get(name)
+    }
+
+    /// Whether the variable is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the variable.
+    pub fn contains(
+        &self,
+        name: &str
+    ) -> bool {
+        self.variables.contains_key(name)
+    }
+
+    /// Whether the variable is set to a value that counts as true in an
+    /// `{{#if}}` block.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the variable.
+    pub fn is_truthy(
+        &self,
+        name: &str
+    ) -> bool {
+        self.variables.get(name).is_some_and(is_truthy)
+    }
+}
+
+/// A parsed node of a template.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplateNode {
+    /// Literal text.
+    Text(String),
+
+    /// A variable that is escaped for the output format (`{{ name }}`).
+    Variable {
+        /// The path to the variable (e.g. `package.name`).
+        path: String,
+
+        /// The line the variable is on.
+        line: usize
+    },
+
+    /// A variable that is written as-is (`{{{ name }}}`).
+    RawVariable {
+        /// The path to the variable (e.g. `package.name`).
+        path: String,
+
+        /// The line the variable is on.
+        line: usize
+    },
+
+    /// A conditional block (`{{#if name}} ... {{else}} ... {{/if}}`).
+    If {
+        /// The path to the variable that is checked.
+        path: String,
+
+        /// Whether the condition is negated (`{{#unless name}}`).
+        negated: bool,
+
+        /// The nodes rendered when the condition is true.
+        then_nodes: Vec<TemplateNode>,
+
+        /// The nodes rendered when the condition is false.
+        else_nodes: Vec<TemplateNode>,
+
+        /// The line the block starts on.
+        line: usize
+    },
+
+    /// A loop over a list (`{{#each name}} ... {{/each}}`).
+    Each {
+        /// The path to the list.
+        path: String,
+
+        /// The nodes rendered for each item.
+        body_nodes: Vec<TemplateNode>,
+
+        /// The line the block starts on.
+        line: usize
+    }
+}
+
+/// A parsed template.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    /// The parsed nodes of the template.
+    pub nodes: Vec<TemplateNode>
+}
+
+impl Template {
+    /// Parses a template.
+    ///
+    /// # Arguments
+    ///
+    /// - `source` - The template's source text.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::template_ops::engine::Template;
+    ///
+    /// let template = Template::parse("Hello, {{name}}!").unwrap();
+    ///
+    /// assert_eq!(template.variable_paths(), vec!["name".to_string()]);
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, CliError> {
+        let tokens = tokenize(source)?;
+
+        let mut position = 0;
+        let nodes = parse_nodes(&tokens, &mut position, None)?;
+
+        Ok(Self { nodes })
+    }
+
+    /// Renders the template.
+    ///
+    /// # Arguments
+    ///
+    /// - `context` - The variables available to the template.
+    /// - `format` - The output format, which decides how values are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::template_ops::engine::{OutputFormat, Template, TemplateContext};
+    ///
+    /// let template = Template::parse(
+    ///     "authors = [{{#each authors}}\"{{this}}\"{{#unless @last}}, {{/unless}}{{/each}}]"
+    /// )
+    /// .unwrap();
+    ///
+    /// let context = TemplateContext::new().with("authors", vec!["Jane \"JD\" Doe", "John Doe"]);
+    ///
+    /// assert_eq!(
+    ///     template.render(&context, OutputFormat::Toml).unwrap(),
+    ///     "authors = [\"Jane \\\"JD\\\" Doe\", \"John Doe\"]"
+    /// );
+    /// ```
+    pub fn render(
+        &self,
+        context: &TemplateContext,
+        format: OutputFormat
+    ) -> Result<String, CliError> {
+        let mut output = String::new();
+        let mut scopes = vec![Scope {
+            value: Value::Object(context.variables.clone()),
+            loop_state: None
+        }];
+
+        render_nodes(&self.nodes, &mut scopes, format, &mut output)?;
+
+        Ok(output)
+    }
+
+    /// Gets the paths of all variables used by the template, in the order they
+    /// first appear.
+    ///
+    /// Paths used inside an `each` block that refer to the loop item (`this`,
+    /// `@index`, ...) are left out.
+    pub fn variable_paths(&self) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        collect_variable_paths(&self.nodes, 0, &mut paths);
+
+        paths
+    }
+
+    /// Gets the paths of all variables used by the template, with the line each
+    /// one first appears on.
+    pub fn variable_paths_with_lines(&self) -> Vec<(String, usize)> {
+        let mut paths = Vec::new();
+
+        collect_variable_paths_with_lines(&self.nodes, 0, &mut paths);
+
+        paths
+    }
+}
+
+/// Renders a template.
+///
+/// # Arguments
+///
+/// - `source` - The template's source text.
+/// - `context` - The variables available to the template.
+/// - `format` - The output format, which decides how values are escaped.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust
+/// use vscodeconfigurator_lib::template_ops::engine::{render, OutputFormat, TemplateContext};
+///
+/// let context = TemplateContext::new().with("solutionName", "MySolution");
+///
+/// let rendered = render(
+///     "{ \"dotnet.defaultSolution\": \"{{solutionName}}.sln\" }",
+///     &context,
+///     OutputFormat::Json
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     rendered,
+///     "{ \"dotnet.defaultSolution\": \"MySolution.sln\" }"
+/// );
+/// ```
+///
+/// ## Example 02
+///
+/// Unknown variables are an error.
+///
+/// ```rust
+/// use vscodeconfigurator_lib::template_ops::engine::{render, OutputFormat, TemplateContext};
+///
+/// let result = render(
+///     "{{solutionName}}",
+///     &TemplateContext::new(),
+///     OutputFormat::Plain
+/// );
+///
+/// assert!(result.is_err());
+/// ```
+///
+/// ## Example 03
+///
+/// Non-ASCII text, including a leading byte order mark, is kept as is.
+///
+/// ```rust
+/// use vscodeconfigurator_lib::template_ops::engine::{render, OutputFormat, TemplateContext};
+///
+/// let context = TemplateContext::new().with("name", "Zoë");
+///
+/// assert_eq!(
+///     render("{{name}}é", &context, OutputFormat::Plain).unwrap(),
+///     "Zoëé"
+/// );
+/// assert_eq!(
+///     render("\u{FEFF}Hi, {{name}} 👋", &context, OutputFormat::Plain).unwrap(),
+///     "\u{FEFF}Hi, Zoë 👋"
+/// );
+/// ```
+pub fn render(
+    source: &str,
+    context: &TemplateContext,
+    format: OutputFormat
+) -> Result<String, CliError> {
+    Template::parse(source)?.render(context, format)
+}
+
+/// A token of a template's source text.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// Literal text.
+    Text(String),
+
+    /// A tag, with its trimmed contents and whether it used triple braces.
+    Tag {
+        contents: String,
+        raw: bool,
+        line: usize
+    }
+}
+
+/// Splits a template's source text into text and tags.
+///
+/// Block tags (`#if`, `else`, `/if`, ...) that are the only thing on their line
+/// take the whole line with them, so they don't leave blank lines in the
+/// output.
+fn tokenize(source: &str) -> Result<Vec<Token>, CliError> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut line = 1;
+    let mut rest = source;
+
+    // Whether the text buffer started at the beginning of a line.
+    let mut text_starts_line = true;
+
+    while !rest.is_empty() {
+        if rest.starts_with("\\{{") {
+            text.push_str("{{");
+            rest = &rest[3..];
+            continue;
+        }
+
+        if rest.starts_with("{{") {
+            let raw = rest.starts_with("{{{");
+            let (open_len, close) = match raw {
+                true => (3, "}}}"),
+                false => (2, "}}")
+            };
+
+            let close_index = match rest[open_len..].find(close) {
+                Some(index) => index + open_len,
+                None => {
+                    return Err(render_error(line, "unclosed '{{' tag"));
+                }
+            };
+
+            let contents = rest[open_len..close_index].trim().to_string();
+
+            if contents.is_empty() {
+                return Err(render_error(line, "empty '{{}}' tag"));
+            }
+
+            let tag_line = line;
+            line += rest[..close_index].matches('\n').count();
+            rest = &rest[close_index + close.len()..];
+
+            let mut is_standalone = false;
+
+            let is_block_tag = !raw
+                && (contents.starts_with('#') || contents.starts_with('/') || contents == "else");
+
+            if is_block_tag {
+                let line_start = text.rfind('\n').map_or(0, |index| index + 1);
+                let preceded_by_whitespace = text[line_start..]
+                    .chars()
+                    .all(|character| character == ' ' || character == '\t');
+                let at_line_start = line_start > 0 || text_starts_line;
+
+                let rest_of_line_end = rest.find('\n');
+                let followed_by_whitespace = rest[..rest_of_line_end.unwrap_or(rest.len())]
+                    .chars()
+                    .all(|character| character == ' ' || character == '\t' || character == '\r');
+
+                is_standalone = preceded_by_whitespace && at_line_start && followed_by_whitespace;
+
+                if is_standalone {
+                    text.truncate(line_start);
+
+                    match rest_of_line_end {
+                        Some(index) => {
+                            rest = &rest[index + 1..];
+                            line += 1;
+                        }
+                        None => rest = ""
+                    }
+                }
+            }
+
+            // The text after a standalone tag starts on a new line.
+            text_starts_line = is_standalone;
+
+            if !text.is_empty() {
+                tokens.push(Token::Text(std::mem::take(&mut text)));
+            }
+
+            tokens.push(Token::Tag {
+                contents,
+                raw,
+                line: tag_line
+            });
+
+            continue;
+        }
+
+        // Always consume the whole first character, which can be more than one
+        // byte long.
+        let first_len = rest.chars().next().map_or(1, char::len_utf8);
+        let next_index = rest[first_len..]
+            .find(['{', '\\'])
+            .map_or(rest.len(), |index| index + first_len);
+
+        let chunk = &rest[..next_index];
+        line += chunk.matches('\n').count();
+        text.push_str(chunk);
+        rest = &rest[next_index..];
+    }
+
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+
+    Ok(tokens)
+}
+
+/// Parses tokens into nodes until the closing tag of the current block.
+fn parse_nodes(
+    tokens: &[Token],
+    position: &mut usize,
+    closing_block: Option<&str>
+) -> Result<Vec<TemplateNode>, CliError> {
+    let mut nodes = Vec::new();
+
+    while *position < tokens.len() {
+        let token = &tokens[*position];
+        *position += 1;
+
+        let (contents, raw, line) = match token {
+            Token::Text(text) => {
+                nodes.push(TemplateNode::Text(text.clone()));
+                continue;
+            }
+
+            Token::Tag {
+                contents,
+                raw,
+                line
+            } => (contents.as_str(), *raw, *line)
+        };
+
+        if raw {
+            nodes.push(TemplateNode::RawVariable {
+                path: parse_path(contents, line)?,
+                line
+            });
+            continue;
+        }
+
+        if let Some(block_tag) = contents.strip_prefix('#') {
+            let (block_name, block_path) = match block_tag.split_once(char::is_whitespace) {
+                Some((name, path)) => (name, parse_path(path.trim(), line)?),
+                None => {
+                    return Err(render_error(
+                        line,
+                        &format!("the '#{}' block is missing a variable", block_tag)
+                    ))
+                }
+            };
+
+            match block_name {
+                "if" | "unless" => {
+                    let then_nodes = parse_nodes(tokens, position, Some(block_name))?;
+
+                    let else_nodes = match matches!(
+                        tokens.get(*position - 1),
+                        Some(Token::Tag { contents, .. }) if contents == "else"
+                    ) {
+                        true => parse_nodes(tokens, position, Some(block_name))?,
+                        false => Vec::new()
+                    };
+
+                    nodes.push(TemplateNode::If {
+                        path: block_path,
+                        negated: block_name == "unless",
+                        then_nodes,
+                        else_nodes,
+                        line
+                    });
+                }
+
+                "each" => {
+                    let body_nodes = parse_nodes(tokens, position, Some("each"))?;
+
+                    nodes.push(TemplateNode::Each {
+                        path: block_path,
+                        body_nodes,
+                        line
+                    });
+                }
+
+                _ => {
+                    return Err(render_error(
+                        line,
+                        &format!("unknown block '#{}'", block_name)
+                    ))
+                }
+            }
+
+            continue;
+        }
+
+        if contents == "else" {
+            return match closing_block {
+                Some("if") | Some("unless") => Ok(nodes),
+                _ => Err(render_error(line, "'else' outside of an 'if' block"))
+            };
+        }
+
+        if let Some(closed_block) = contents.strip_prefix('/') {
+            return match closing_block {
+                Some(expected) if expected == closed_block.trim() => Ok(nodes),
+
+                Some(expected) => Err(render_error(
+                    line,
+                    &format!(
+                        "expected '{{{{/{}}}}}' but found '{{{{/{}}}}}'",
+                        expected,
+                        closed_block.trim()
+                    )
+                )),
+
+                None => Err(render_error(
+                    line,
+                    &format!("unexpected '{{{{/{}}}}}'", closed_block.trim())
+                ))
+            };
+        }
+
+        nodes.push(TemplateNode::Variable {
+            path: parse_path(contents, line)?,
+            line
+        });
+    }
+
+    match closing_block {
+        Some(block_name) => Err(render_error(
+            count_lines(tokens),
+            &format!("the '#{}' block is never closed", block_name)
+        )),
+        None => Ok(nodes)
+    }
+}
+
+/// Checks that a variable path only has valid characters.
+fn parse_path(
+    path: &str,
+    line: usize
+) -> Result<String, CliError> {
+    let is_valid = !path.is_empty()
+        && path.split('.').all(|segment| {
+            let segment = segment.strip_prefix('@').unwrap_or(segment);
+
+            !segment.is_empty()
+                && segment.chars().all(|character| {
+                    character.is_alphanumeric() || character == '_' || character == '-'
+                })
+        });
+
+    match is_valid {
+        true => Ok(path.to_string()),
+        false => Err(render_error(
+            line,
+            &format!("'{}' is not a valid variable name", path)
+        ))
+    }
+}
+
+/// Counts the lines spanned by the tokens.
+fn count_lines(tokens: &[Token]) -> usize {
+    tokens.iter().fold(1, |line, token| match token {
+        Token::Text(text) => line + text.matches('\n').count(),
+        Token::Tag { line, .. } => *line
+    })
+}
+
+/// A scope that variables are looked up in while rendering.
+struct Scope {
+    /// The value of the scope (the context, or the current loop item).
+    value: Value,
+
+    /// The state of the loop, if the scope is a loop item.
+    loop_state: Option<LoopState>
+}
+
+/// The state of an `each` loop.
+struct LoopState {
+    index: usize,
+    length: usize
+}
+
+/// Renders nodes into the output.
+fn render_nodes(
+    nodes: &[TemplateNode],
+    scopes: &mut Vec<Scope>,
+    format: OutputFormat,
+    output: &mut String
+) -> Result<(), CliError> {
+    for node in nodes {
+        match node {
+            TemplateNode::Text(text) => output.push_str(text),
+
+            TemplateNode::Variable { path, line } => {
+                let value = lookup(scopes, path, *line)?;
+                output.push_str(&format.escape(&value_to_text(&value, path, *line)?));
+            }
+
+            TemplateNode::RawVariable { path, line } => {
+                let value = lookup(scopes, path, *line)?;
+                output.push_str(&value_to_text(&value, path, *line)?);
+            }
+
+            TemplateNode::If {
+                path,
+                negated,
+                then_nodes,
+                else_nodes,
+                line
+            } => {
+                let condition = is_truthy(&lookup(scopes, path, *line)?) != *negated;
+
+                match condition {
+                    true => render_nodes(then_nodes, scopes, format, output)?,
+                    false => render_nodes(else_nodes, scopes, format, output)?
+                };
+            }
+
+            TemplateNode::Each {
+                path,
+                body_nodes,
+                line
+            } => {
+                let items = match lookup(scopes, path, *line)? {
+                    Value::Array(items) => items,
+                    Value::Null => Vec::new(),
+                    _ => return Err(render_error(*line, &format!("'{}' is not a list", path)))
+                };
+
+                let length = items.len();
+
+                for (index, item) in items.into_iter().enumerate() {
+                    scopes.push(Scope {
+                        value: item,
+                        loop_state: Some(LoopState { index, length })
+                    });
+
+                    let result = render_nodes(body_nodes, scopes, format, output);
+
+                    scopes.pop();
+
+                    result?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Looks up a variable, starting in the innermost scope.
+fn lookup(
+    scopes: &[Scope],
+    path: &str,
+    line: usize
+) -> Result<Value, CliError> {
+    let mut segments = path.split('.');
+    let first_segment = segments.next().unwrap_or_default();
+
+    let mut value = match first_segment {
+        "this" => scopes.last().map(|scope| scope.value.clone()),
+
+        "@index" | "@first" | "@last" => scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.loop_state.as_ref())
+            .map(|loop_state| match first_segment {
+                "@index" => Value::from(loop_state.index),
+                "@first" => Value::Bool(loop_state.index == 0),
+                _ => Value::Bool(loop_state.index + 1 == loop_state.length)
+            }),
+
+        _ => scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.value.get(first_segment).cloned())
+    };
+
+    for segment in segments {
+        value = value.and_then(|value| value.get(segment).cloned());
+    }
+
+    match value {
+        Some(value) => Ok(value),
+        None => Err(CliError::new(
+            format!("unknown variable '{}'", path).as_str(),
+            CliErrorKind::UnknownTemplateVariable
+        )
+        .at_line(line))
+    }
+}
+
+/// Converts a value to the text written to the output.
+fn value_to_text(
+    value: &Value,
+    path: &str,
+    line: usize
+) -> Result<String, CliError> {
+    match value {
+        Value::String(value) => Ok(value.clone()),
+        Value::Number(value) => Ok(value.to_string()),
+        Value::Bool(value) => Ok(value.to_string()),
+        Value::Null => Ok(String::new()),
+        Value::Array(_) | Value::Object(_) => Err(render_error(
+            line,
+            &format!(
+                "'{}' is a list or a table and can't be written as text; use an '#each' block",
+                path
+            )
+        ))
+    }
+}
+
+/// Whether a value counts as true in an `if` block.
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(value) => value.as_f64() != Some(0.0),
+        Value::String(value) => !value.is_empty(),
+        Value::Array(value) => !value.is_empty(),
+        Value::Object(value) => !value.is_empty()
+    }
+}
+
+/// Collects the variable paths used by nodes, skipping paths that refer to a
+/// loop item.
+fn collect_variable_paths(
+    nodes: &[TemplateNode],
+    loop_depth: usize,
+    paths: &mut Vec<String>
+) {
+    let mut paths_with_lines = Vec::new();
+
+    collect_variable_paths_with_lines(nodes, loop_depth, &mut paths_with_lines);
+
+    for (path, _) in paths_with_lines {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+}
+
+/// Collects the variable paths used by nodes, with the line each one first
+/// appears on.
+fn collect_variable_paths_with_lines(
+    nodes: &[TemplateNode],
+    loop_depth: usize,
+    paths: &mut Vec<(String, usize)>
+) {
+    let push_path = |path: &str, line: usize, paths: &mut Vec<(String, usize)>| {
+        let is_loop_path = path == "this" || path.starts_with("this.") || path.starts_with('@');
+
+        // Inside a loop, a bare name may refer to a field of the loop item, so
+        // it can't be checked without knowing the item.
+        if is_loop_path || loop_depth > 0 {
+            return;
+        }
+
+        if !paths.iter().any(|(existing_path, _)| existing_path == path) {
+            paths.push((path.to_string(), line));
+        }
+    };
+
+    for node in nodes {
+        match node {
+            TemplateNode::Text(_) => {}
+
+            TemplateNode::Variable { path, line } | TemplateNode::RawVariable { path, line } => {
+                push_path(path, *line, paths)
+            }
+
+            TemplateNode::If {
+                path,
+                then_nodes,
+                else_nodes,
+                line,
+                ..
+            } => {
+                push_path(path, *line, paths);
+                collect_variable_paths_with_lines(then_nodes, loop_depth, paths);
+                collect_variable_paths_with_lines(else_nodes, loop_depth, paths);
+            }
+
+            TemplateNode::Each {
+                path,
+                body_nodes,
+                line
+            } => {
+                push_path(path, *line, paths);
+                collect_variable_paths_with_lines(body_nodes, loop_depth + 1, paths);
+            }
+        }
+    }
+}
+
+/// Creates a template rendering error for a line.
+fn render_error(
+    line: usize,
+    message: &str
+) -> CliError {
+    CliError::new(message, CliErrorKind::TemplateRenderError).at_line(line)
+}
@@ -0,0 +1,532 @@
+use std::{
+    fmt,
+    fs,
+    path::{Path, PathBuf}
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{CliError, CliErrorKind},
+    external_procs::git,
+    io::{copy_dir_recursive, get_app_config_dir},
+    logging::{ConsoleLogger, OutputEmoji}
+};
+
+/// The name of the directory, in the VSCode Configurator's configuration
+/// directory, that installed template sets are stored in.
+pub const INSTALLED_SETS_DIR: &str = "sets";
+
+/// The name of the file, in the VSCode Configurator's configuration directory,
+/// that records the installed template sets.
+pub const INSTALLED_SETS_FILE: &str = "installed.toml";
+
+/// How an installed template set was installed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateSetSourceKind {
+    /// The set was cloned from a Git repository.
+    Git,
+
+    /// The set was copied from a directory.
+    Directory
+}
+
+/// A template set installed with `template add`.
+///
+/// A template set is a templates directory, laid out like the built-in
+/// `templates/` directory, that holds one or more template packs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InstalledTemplateSet {
+    /// The name of the set.
+    pub name: String,
+
+    /// The absolute path the set was installed from.
+    pub source: String,
+
+    /// How the set was installed.
+    pub kind: TemplateSetSourceKind,
+
+    /// The commit the set is at, if it was cloned from a Git repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>
+}
+
+impl InstalledTemplateSet {
+    /// Gets the directory the set is installed in.
+    ///
+    /// Returns `None` if the configuration directory can't be found.
+    pub fn directory(&self) -> Option<PathBuf> {
+        get_installed_sets_dir().map(|sets_dir| sets_dir.join(&self.name))
+    }
+
+    /// Gets the version of the set, which is the short commit hash for sets
+    /// cloned from a Git repository.
+    pub fn version(&self) -> String {
+        match &self.commit {
+            Some(commit) => commit.chars().take(12).collect(),
+            None => "-".to_string()
+        }
+    }
+
+    /// Gets the names of the template packs in the set (e.g. `rust`,
+    /// `csharp`), which are the directories at its root.
+    pub fn pack_names(&self) -> Vec<String> {
+        let Some(set_directory) = self.directory() else {
+            return Vec::new();
+        };
+
+        let Ok(entries) = fs::read_dir(set_directory) else {
+            return Vec::new();
+        };
+
+        let mut pack_names = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| !name.starts_with('.'))
+            .collect::<Vec<String>>();
+
+        pack_names.sort();
+
+        pack_names
+    }
+}
+
+/// The template sets installed for the current user.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InstalledTemplateSets {
+    /// The installed sets, in the order they were installed.
+    #[serde(default)]
+    pub sets: Vec<InstalledTemplateSet>
+}
+
+impl InstalledTemplateSets {
+    /// Loads the installed template sets.
+    ///
+    /// Returns an empty list if no sets are installed.
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let Some(installed_sets_file_path) = get_installed_sets_file_path() else {
+            return Ok(Self::default());
+        };
+
+        if !installed_sets_file_path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let installed_sets_contents = fs::read_to_string(&installed_sets_file_path)?;
+
+        match toml::from_str::<Self>(&installed_sets_contents) {
+            Ok(installed_sets) => Ok(installed_sets),
+            Err(e) => Err(super::toml_parse_error(
+                &e,
+                &installed_sets_contents,
+                &installed_sets_file_path.to_string_lossy(),
+                CliErrorKind::TemplateSetError
+            )
+            .into())
+        }
+    }
+
+    /// Saves the installed template sets.
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let installed_sets_file_path = match get_installed_sets_file_path() {
+            Some(path) => path,
+            None => return Err(config_dir_not_found_error().into())
+        };
+
+        if let Some(parent_dir) = installed_sets_file_path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        fs::write(installed_sets_file_path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    /// Gets an installed template set by name.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the set.
+    pub fn get(
+        &self,
+        name: &str
+    ) -> Option<&InstalledTemplateSet> {
+        self.sets.iter().find(|set| set.name == name)
+    }
+
+    /// Installs a template set from a local directory or Git repository.
+    ///
+    /// Git repositories are cloned, so they can be updated with
+    /// [`update`](InstalledTemplateSets::update). Other directories are copied.
+    ///
+    /// # Arguments
+    ///
+    /// - `source` - The path or `file://` URL of the set.
+    /// - `name` - The name to install the set as. If `None`, the name of the
+    ///   source directory is used.
+    /// - `logger` - The [`ConsoleLogger`](crate::logging::ConsoleLogger)
+    ///   instance for logging.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Install the templates in a local Git repository.
+    ///
+    /// ```rust,ignore
+    /// use vscodeconfigurator_lib::{
+    ///     logging::ConsoleLogger,
+    ///     template_ops::installed::InstalledTemplateSets
+    /// };
+    ///
+    /// let mut logger = ConsoleLogger::new(None, None);
+    /// let mut installed_sets = InstalledTemplateSets::load()?;
+    ///
+    /// installed_sets.add("file:///srv/git/org-templates.git", None, &mut logger)?;
+    /// ```
+    pub fn add(
+        &mut self,
+        source: &str,
+        name: Option<&str>,
+        logger: &mut ConsoleLogger
+    ) -> Result<InstalledTemplateSet, Box<dyn std::error::Error>> {
+        let source_path = source_to_path(source)?;
+
+        if !source_path.is_dir() {
+            return Err(CliError::new(
+                format!("The template set '{}' does not exist.", source).as_str(),
+                CliErrorKind::FilePathDoesNotExist
+            )
+            .into());
+        }
+
+        // The set is updated from the source later, maybe from another
+        // directory, so a relative path can't be kept.
+        let source_path = source_path.canonicalize()?;
+        let source = source_path.to_string_lossy().to_string();
+
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => default_set_name(&source_path)
+        };
+
+        validate_set_name(&name)?;
+
+        if self.get(&name).is_some() {
+            return Err(CliError::new(
+                format!(
+                    "The template set '{}' is already installed. Update it with 'template update \
+                     {}' or remove it first.",
+                    name, name
+                )
+                .as_str(),
+                CliErrorKind::TemplateSetError
+            )
+            .into());
+        }
+
+        let set_directory = match get_installed_sets_dir() {
+            Some(sets_dir) => sets_dir.join(&name),
+            None => return Err(config_dir_not_found_error().into())
+        };
+
+        if set_directory.exists() {
+            fs::remove_dir_all(&set_directory)?;
+        }
+
+        if let Some(sets_dir) = set_directory.parent() {
+            fs::create_dir_all(sets_dir)?;
+        }
+
+        let kind = match is_git_repo(&source_path) {
+            true => TemplateSetSourceKind::Git,
+            false => TemplateSetSourceKind::Directory
+        };
+
+        let commit = match kind {
+            TemplateSetSourceKind::Git => {
+                logger.write_operation_log(
+                    format!("Cloning '{}'...", source).as_str(),
+                    OutputEmoji::Package
+                )?;
+                git::clone_repo(&source_path.to_string_lossy(), &set_directory)?;
+                git::get_head_commit(&set_directory)
+            }
+
+            TemplateSetSourceKind::Directory => {
+                logger.write_operation_log(
+                    format!("Copying '{}'...", source).as_str(),
+                    OutputEmoji::Folder
+                )?;
+                copy_dir_recursive(&source_path, &set_directory)?;
+                None
+            }
+        };
+
+        logger.write_operation_success_log()?;
+
+        let installed_set = InstalledTemplateSet {
+            name,
+            source,
+            kind,
+            commit
+        };
+
+        self.sets.push(installed_set.clone());
+        self.save()?;
+
+        Ok(installed_set)
+    }
+
+    /// Updates an installed template set from its source.
+    ///
+    /// Git repositories are pulled and other directories are copied again.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the set.
+    /// - `logger` - The [`ConsoleLogger`](crate::logging::ConsoleLogger)
+    ///   instance for logging.
+    pub fn update(
+        &mut self,
+        name: &str,
+        logger: &mut ConsoleLogger
+    ) -> Result<InstalledTemplateSet, Box<dyn std::error::Error>> {
+        let installed_set = match self.sets.iter_mut().find(|set| set.name == name) {
+            Some(installed_set) => installed_set,
+            None => return Err(set_not_installed_error(name).into())
+        };
+
+        let set_directory = match installed_set.directory() {
+            Some(set_directory) => set_directory,
+            None => return Err(config_dir_not_found_error().into())
+        };
+
+        match installed_set.kind {
+            TemplateSetSourceKind::Git => {
+                logger.write_operation_log(
+                    format!("Pulling '{}'...", installed_set.source).as_str(),
+                    OutputEmoji::Package
+                )?;
+
+                match set_directory.is_dir() {
+                    true => git::pull_repo(&set_directory)?,
+                    false => git::clone_repo(
+                        &source_to_path(&installed_set.source)?.to_string_lossy(),
+                        &set_directory
+                    )?
+                };
+
+                installed_set.commit = git::get_head_commit(&set_directory);
+            }
+
+            TemplateSetSourceKind::Directory => {
+                logger.write_operation_log(
+                    format!("Copying '{}'...", installed_set.source).as_str(),
+                    OutputEmoji::Folder
+                )?;
+
+                let source_path = source_to_path(&installed_set.source)?;
+
+                if !source_path.is_dir() {
+                    return Err(CliError::new(
+                        format!(
+                            "The source of the template set '{}' no longer exists: {}",
+                            name, installed_set.source
+                        )
+                        .as_str(),
+                        CliErrorKind::FilePathDoesNotExist
+                    )
+                    .into());
+                }
+
+                if set_directory.exists() {
+                    fs::remove_dir_all(&set_directory)?;
+                }
+
+                copy_dir_recursive(&source_path, &set_directory)?;
+            }
+        }
+
+        logger.write_operation_success_log()?;
+
+        let installed_set = installed_set.clone();
+        self.save()?;
+
+        Ok(installed_set)
+    }
+
+    /// Removes an installed template set and deletes its files.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the set.
+    /// - `logger` - The [`ConsoleLogger`](crate::logging::ConsoleLogger)
+    ///   instance for logging.
+    pub fn remove(
+        &mut self,
+        name: &str,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let installed_set = match self.get(name) {
+            Some(installed_set) => installed_set.clone(),
+            None => return Err(set_not_installed_error(name).into())
+        };
+
+        logger.write_operation_log(
+            format!("Removing template set '{}'...", name).as_str(),
+            OutputEmoji::Folder
+        )?;
+
+        if let Some(set_directory) = installed_set.directory().filter(|path| path.exists()) {
+            fs::remove_dir_all(set_directory)?;
+        }
+
+        self.sets.retain(|set| set.name != name);
+        self.save()?;
+
+        logger.write_operation_success_log()?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TemplateSetSourceKind {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>
+    ) -> fmt::Result {
+        match self {
+            TemplateSetSourceKind::Git => write!(f, "git"),
+            TemplateSetSourceKind::Directory => write!(f, "directory")
+        }
+    }
+}
+
+/// Gets the directory installed template sets are stored in.
+pub fn get_installed_sets_dir() -> Option<PathBuf> {
+    get_app_config_dir().map(|app_config_dir| app_config_dir.join(INSTALLED_SETS_DIR))
+}
+
+/// Gets the path to the file that records the installed template sets.
+fn get_installed_sets_file_path() -> Option<PathBuf> {
+    get_app_config_dir().map(|app_config_dir| app_config_dir.join(INSTALLED_SETS_FILE))
+}
+
+/// Converts a local path or `file://` URL to a path.
+///
+/// Only local sources are supported, so any other URL is an error.
+fn source_to_path(source: &str) -> Result<PathBuf, CliError> {
+    if let Some(file_url_path) = source.strip_prefix("file://") {
+        let file_url_path = percent_decode(file_url_path);
+
+        // `file:///C:/templates` on Windows.
+        let file_url_path = match file_url_path.as_bytes() {
+            [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => {
+                file_url_path[1..].to_string()
+            }
+            _ => file_url_path
+        };
+
+        return Ok(PathBuf::from(file_url_path));
+    }
+
+    if source.contains("://") || source.starts_with("git@") {
+        return Err(CliError::new(
+            format!(
+                "'{}' is not a local path or a 'file://' URL. Only local template sets are \
+                 supported.",
+                source
+            )
+            .as_str(),
+            CliErrorKind::TemplateSetError
+        ));
+    }
+
+    Ok(PathBuf::from(source))
+}
+
+/// Decodes `%XX` escapes in a URL path.
+fn percent_decode(url_path: &str) -> String {
+    let bytes = url_path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escaped_byte = match bytes[index] {
+            b'%' if index + 2 < bytes.len() => std::str::from_utf8(&bytes[index + 1..index + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None
+        };
+
+        match escaped_byte {
+            Some(escaped_byte) => {
+                decoded.push(escaped_byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// Whether a directory is a Git repository (a working tree or a bare
+/// repository).
+fn is_git_repo(directory: &Path) -> bool {
+    directory.join(".git").exists()
+        || (directory.join("HEAD").is_file() && directory.join("objects").is_dir())
+}
+
+/// Gets the default name for a set, which is the name of its directory without
+/// a `.git` suffix.
+fn default_set_name(source_path: &Path) -> String {
+    source_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .map(|name| name.trim_end_matches(".git").to_string())
+        .unwrap_or_default()
+}
+
+/// Checks that a set name can be used as a directory name.
+fn validate_set_name(name: &str) -> Result<(), CliError> {
+    let is_valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|character| character.is_alphanumeric() || "-_.".contains(character));
+
+    match is_valid {
+        true => Ok(()),
+        false => Err(CliError::new(
+            format!(
+                "'{}' is not a valid template set name. Use letters, numbers, '-', '_' and '.'.",
+                name
+            )
+            .as_str(),
+            CliErrorKind::TemplateSetError
+        ))
+    }
+}
+
+/// Creates the error for a template set that isn't installed.
+fn set_not_installed_error(name: &str) -> CliError {
+    CliError::new(
+        format!("The template set '{}' is not installed.", name).as_str(),
+        CliErrorKind::TemplateSetError
+    )
+}
+
+/// Creates the error for when the configuration directory can't be found.
+fn config_dir_not_found_error() -> CliError {
+    CliError::new(
+        "The configuration directory could not be found.",
+        CliErrorKind::UnsupportedOperatingSystem
+    )
+}
@@ -1,13 +1,55 @@
-use std::{env, fs, path::PathBuf};
+use std::{
+    fs,
+    io,
+    path::{Path, PathBuf}
+};
 
-pub mod csharp;
-pub mod rust;
-pub mod vscode;
+use include_dir::{include_dir, Dir};
+
+use self::{
+    engine::{OutputFormat, TemplateContext},
+    search_path::TemplateSearchPath
+};
+use crate::error::{CliError, CliErrorKind};
+
+pub mod capture;
+pub mod engine;
+pub mod installed;
+pub mod pack;
+pub mod search_path;
+pub mod validate;
+pub mod variables;
+
+/// The built-in templates, embedded into the binary at compile time.
+static EMBEDDED_TEMPLATES: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/templates");
+
+/// The environment variable that can be set to override the templates with a
+/// directory on disk.
+///
+/// The directory must be laid out like the built-in `templates/` directory
+/// (e.g. `rust/VSCode/settings.json`). It is the first layer of the
+/// [`TemplateSearchPath`](search_path::TemplateSearchPath), so any template
+/// that is not found in the directory falls back to the other layers.
+pub const TEMPLATES_DIR_ENV_VAR: &str = "VSCODECONFIGURATOR_TEMPLATES";
+
+/// Represents where a template file is read from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplateSource {
+    /// The template is embedded in the binary.
+    Embedded,
+
+    /// The template is read from a directory on disk.
+    Directory(PathBuf)
+}
 
 /// Represents a template file.
 pub struct TemplateFile {
-    /// The path to the template file.
-    pub template_file_path: PathBuf,
+    /// The relative path to the template file (e.g.
+    /// `rust/VSCode/settings.json`).
+    pub relative_template_file_path: String,
+
+    /// Where the template file is read from.
+    pub template_source: TemplateSource,
 
     /// The path to output the file to.
     pub output_file_path: PathBuf,
@@ -22,10 +64,14 @@ pub struct TemplateFile {
 impl TemplateFile {
     /// Creates a new instance of `TemplateFile`.
     ///
+    /// The template is read from the first layer of the
+    /// [`TemplateSearchPath`](search_path::TemplateSearchPath) that holds it.
+    /// If no layer holds it, the built-in templates are used.
+    ///
     /// # Arguments
     ///
     /// - `relative_template_file_path` - The relative path to the template file
-    ///   from the binary's directory.
+    ///   from the root of the templates directory.
     /// - `output_directory_path` - The path to the directory to output the
     ///   file.
     /// - `output_file_name` - The name of the output file.
@@ -40,49 +86,148 @@ impl TemplateFile {
     /// ```rust
     /// use std::{env, path::PathBuf};
     ///
+    /// use vscodeconfigurator_lib::template_ops::TemplateFile;
+    ///
     /// let relative_template_file_path = "csharp/GitVersion/GitVersion.yml";
     /// let output_directory_path = PathBuf::from(env::current_dir().unwrap());
     /// let output_file_name = "GitVersion.yml";
     ///
     /// let template_file = TemplateFile::new(
     ///     relative_template_file_path,
-    ///     output_directory_path,
+    ///     &output_directory_path,
     ///     output_file_name
     /// );
     /// ```
     pub fn new(
         relative_template_file_path: &str,
-        output_directory_path: &PathBuf,
+        output_directory_path: &Path,
         output_file_name: &str
     ) -> Self {
-        let binary_path = env::current_exe().unwrap();
-        let binary_dir = binary_path.parent().unwrap();
+        let template_source = TemplateSearchPath::new(output_directory_path)
+            .resolve(relative_template_file_path)
+            .map_or(TemplateSource::Embedded, |layer| layer.source.clone());
 
-        let template_file_path =
-            binary_dir.join(format!("templates/{}", relative_template_file_path));
+        Self::with_source(
+            relative_template_file_path,
+            template_source,
+            output_directory_path,
+            output_file_name
+        )
+    }
 
+    /// Creates a new instance of `TemplateFile` that reads from a specific
+    /// template source.
+    ///
+    /// # Arguments
+    ///
+    /// - `relative_template_file_path` - The relative path to the template file
+    ///   from the root of the templates directory.
+    /// - `template_source` - Where to read the template file from.
+    /// - `output_directory_path` - The path to the directory to output the
+    ///   file.
+    /// - `output_file_name` - The name of the output file.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Creates a new instance of `TemplateFile` for the `GitVersion.yml`
+    /// template file in a custom templates directory.
+    ///
+    /// ```rust
+    /// use std::{env, path::PathBuf};
+    ///
+    /// use vscodeconfigurator_lib::template_ops::{TemplateFile, TemplateSource};
+    ///
+    /// let templates_dir = PathBuf::from("/path/to/templates");
+    /// let output_directory_path = PathBuf::from(env::current_dir().unwrap());
+    ///
+    /// let template_file = TemplateFile::with_source(
+    ///     "csharp/GitVersion/GitVersion.yml",
+    ///     TemplateSource::Directory(templates_dir),
+    ///     &output_directory_path,
+    ///     "GitVersion.yml"
+    /// );
+    /// ```
+    pub fn with_source(
+        relative_template_file_path: &str,
+        template_source: TemplateSource,
+        output_directory_path: &Path,
+        output_file_name: &str
+    ) -> Self {
         let output_file_path = output_directory_path.join(output_file_name);
 
         let output_file_exists = output_file_path.exists();
 
         Self {
-            template_file_path,
+            relative_template_file_path: relative_template_file_path.to_string(),
+            template_source,
             output_file_path,
             output_file_name: output_file_name.to_string(),
             output_file_exists
         }
     }
 
-    /// Copies the template file to the output directory.
+    /// Reads the contents of the template file.
+    pub fn read_template(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        match self
+            .template_source
+            .read(&self.relative_template_file_path)?
+        {
+            Some(contents) => Ok(contents),
+            None => Err(CliError::new(
+                format!(
+                    "The template '{}' could not be found in {}.",
+                    self.relative_template_file_path, self.template_source
+                )
+                .as_str(),
+                CliErrorKind::TemplateNotFound
+            )
+            .into())
+        }
+    }
+
+    /// Reads the contents of the template file as a string.
+    pub fn read_template_string(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(String::from_utf8(self.read_template()?)?)
+    }
+
+    /// Renders the template file with the template engine.
+    ///
+    /// Values are escaped for the output file's format, which is picked from
+    /// its extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `context` - The variables available to the template.
+    pub fn render(
+        &self,
+        context: &TemplateContext
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let template_source = self.read_template_string()?;
+
+        let output_format = OutputFormat::from_file_name(&self.output_file_name);
+
+        match engine::render(&template_source, context, output_format) {
+            Ok(rendered) => Ok(rendered),
+            Err(e) => Err(e.in_file(&self.relative_template_file_path).into())
+        }
+    }
+
+    /// Renders the template file and writes it to the output directory.
+    ///
+    /// # Arguments
+    ///
+    /// - `context` - The variables available to the template.
     ///
     /// # Examples
     ///
     /// ## Example 01
     ///
-    /// Copies the 'GitVersion.yml' template file to the project root.
+    /// Writes the 'GitVersion.yml' template file to the project root.
     ///
-    /// ```rust
-    /// use vscodeconfigurator::template_ops::TemplateFile;
+    /// ```rust,ignore
+    /// use vscodeconfigurator_lib::template_ops::{engine::TemplateContext, TemplateFile};
     ///
     /// let relative_template_file_path = "csharp/GitVersion/GitVersion.yml";
     /// let output_directory_path = PathBuf::from(env::current_dir().unwrap());
@@ -90,19 +235,88 @@ impl TemplateFile {
     ///
     /// let template_file = TemplateFile::new(
     ///     relative_template_file_path,
-    ///     output_directory_path,
+    ///     &output_directory_path,
     ///     output_file_name
     /// );
     ///
-    /// template_file.copy_file();
+    /// template_file.write_file(&TemplateContext::new());
     /// ```
-    pub fn copy_file(&self) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn write_file(
+        &self,
+        context: &TemplateContext
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let contents = self.render(context)?;
+
         if self.output_file_exists {
             fs::remove_file(&self.output_file_path)?;
         }
 
-        fs::copy(&self.template_file_path, &self.output_file_path)?;
+        fs::write(&self.output_file_path, contents)?;
 
         Ok(())
     }
 }
+
+impl TemplateSource {
+    /// Reads a file from the template source.
+    ///
+    /// Returns `None` if the source does not hold the file.
+    ///
+    /// # Arguments
+    ///
+    /// - `relative_template_file_path` - The relative path to the template file
+    ///   from the root of the templates directory.
+    pub fn read(
+        &self,
+        relative_template_file_path: &str
+    ) -> io::Result<Option<Vec<u8>>> {
+        match self {
+            TemplateSource::Embedded => Ok(EMBEDDED_TEMPLATES
+                .get_file(relative_template_file_path)
+                .map(|file| file.contents().to_vec())),
+
+            TemplateSource::Directory(templates_dir) => {
+                match fs::read(templates_dir.join(relative_template_file_path)) {
+                    Ok(contents) => Ok(Some(contents)),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                    Err(e) => Err(e)
+                }
+            }
+        }
+    }
+}
+
+/// Converts a TOML parse error into a [`CliError`] that points at the file and
+/// line of the error.
+///
+/// # Arguments
+///
+/// - `error` - The TOML parse error.
+/// - `contents` - The contents of the file that was parsed.
+/// - `file_path` - The path to the file that was parsed.
+/// - `kind` - The kind of error to return.
+pub(crate) fn toml_parse_error(
+    error: &toml::de::Error,
+    contents: &str,
+    file_path: &str,
+    kind: CliErrorKind
+) -> CliError {
+    let cli_error = CliError::new(error.message(), kind).in_file(file_path);
+
+    match error.span() {
+        Some(span) => cli_error.at_line(contents[..span.start].matches('\n').count() + 1),
+        None => cli_error
+    }
+}
+
+impl std::fmt::Display for TemplateSource {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>
+    ) -> std::fmt::Result {
+        match self {
+            TemplateSource::Embedded => write!(f, "the built-in templates"),
+            TemplateSource::Directory(path) => write!(f, "'{}'", path.display())
+        }
+    }
+}
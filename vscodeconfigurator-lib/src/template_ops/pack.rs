@@ -0,0 +1,915 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    process
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+    engine::{self, OutputFormat, TemplateContext},
+    search_path::TemplateSearchPath,
+    toml_parse_error,
+    TemplateFile,
+    TemplateSource
+};
+use crate::{
+    error::{CliError, CliErrorKind},
+    external_procs::describe_planned_command,
+    io::describe_planned_write,
+    logging::{ConsoleLogger, OutputEmoji, OverwriteResponse},
+    vscode_ops::{jsonc::JsoncDocument, merge_json_values}
+};
+
+/// The name of the manifest file at the root of a template pack.
+pub const TEMPLATE_PACK_MANIFEST_FILE: &str = "template.toml";
+
+/// The manifest of a template pack (`template.toml`).
+///
+/// A template pack is a directory in the templates directory (e.g. `rust/`)
+/// with a `template.toml` file that lists the variables the pack needs and the
+/// steps to run, in order.
+///
+/// ```toml
+/// description = "A Rust workspace with a base package."
+///
+/// [[variables]]
+/// name = "basePackageName"
+/// description = "The name of the base package."
+/// prompt = "Base package name"
+///
+/// [[steps]]
+/// category = "VSCode"
+/// template = "VSCode/settings.json"
+/// destination = ".vscode/settings.json"
+///
+/// [[steps]]
+/// category = "Rust"
+/// run = ["cargo", "init", "--lib", "{{basePackageName}}"]
+/// description = "Initializing package for '{{basePackageName}}'..."
+/// creates = "{{basePackageName}}"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplatePackManifest {
+    /// A short description of the pack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The variables the pack needs.
+    #[serde(default)]
+    pub variables: Vec<TemplatePackVariable>,
+
+    /// The steps to run, in order.
+    #[serde(default)]
+    pub steps: Vec<TemplatePackStep>
+}
+
+/// A variable declared by a template pack.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplatePackVariable {
+    /// The name of the variable.
+    pub name: String,
+
+    /// A description of the variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The value to use if the variable isn't set.
+    ///
+    /// String values are rendered with the template engine, so they can refer
+    /// to other variables (e.g. `"{{outputDirectoryName}}"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<toml::Value>,
+
+    /// The prompt to ask the user with if the variable isn't set and has no
+    /// default value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>
+}
+
+/// A step in a template pack.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(
+    try_from = "TemplatePackStepDefinition",
+    into = "TemplatePackStepDefinition"
+)]
+pub struct TemplatePackStep {
+    /// The category the step is logged under (e.g. `VSCode`).
+    pub category: Option<String>,
+
+    /// The name of the variable that decides whether the step runs. A leading
+    /// `!` negates it.
+    pub when: Option<String>,
+
+    /// Whether to leave the destination alone, without asking, if it already
+    /// exists.
+    pub skip_if_exists: bool,
+
+    /// What the step does.
+    pub action: TemplatePackStepAction
+}
+
+/// What a template pack step does.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplatePackStepAction {
+    /// Render a template file and write it to the project.
+    File {
+        /// The path to the template file, relative to the pack.
+        template: String,
+
+        /// The path to write the file to, relative to the output directory.
+        destination: String
+    },
+
+    /// Run an external command in the output directory.
+    Command {
+        /// The program and its arguments.
+        run: Vec<String>,
+
+        /// The message logged while the command runs.
+        description: Option<String>,
+
+        /// The path the command creates, relative to the output directory.
+        creates: Option<String>
+    }
+}
+
+/// The fields of a step as they are written in `template.toml`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TemplatePackStepDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    when: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    skip_if_exists: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    destination: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creates: Option<String>
+}
+
+impl TryFrom<TemplatePackStepDefinition> for TemplatePackStep {
+    type Error = String;
+
+    fn try_from(definition: TemplatePackStepDefinition) -> Result<Self, Self::Error> {
+        let action = match (definition.template, definition.run) {
+            (Some(template), None) => {
+                if definition.description.is_some() || definition.creates.is_some() {
+                    return Err(
+                        "'description' and 'creates' can only be used with 'run'".to_string()
+                    );
+                }
+
+                let destination = match definition.destination {
+                    Some(destination) => destination,
+                    None => {
+                        return Err(format!("the step for '{}' needs a 'destination'", template))
+                    }
+                };
+
+                if !is_project_path(&destination) {
+                    return Err(format!(
+                        "the destination '{}' must be a relative file path inside the project",
+                        destination
+                    ));
+                }
+
+                TemplatePackStepAction::File {
+                    template,
+                    destination
+                }
+            }
+
+            (None, Some(run)) => {
+                if run.is_empty() {
+                    return Err("'run' needs at least the program to run".to_string());
+                }
+
+                if definition.destination.is_some() {
+                    return Err("'destination' can only be used with 'template'".to_string());
+                }
+
+                if let Some(creates) = definition.creates.as_ref().filter(|creates| {
+                    !is_project_path(creates)
+                }) {
+                    return Err(format!(
+                        "'creates' ('{}') must be a relative path inside the project",
+                        creates
+                    ));
+                }
+
+                TemplatePackStepAction::Command {
+                    run,
+                    description: definition.description,
+                    creates: definition.creates
+                }
+            }
+
+            _ => return Err("a step needs either a 'template' or a 'run'".to_string())
+        };
+
+        Ok(Self {
+            category: definition.category,
+            when: definition.when,
+            skip_if_exists: definition.skip_if_exists,
+            action
+        })
+    }
+}
+
+impl From<TemplatePackStep> for TemplatePackStepDefinition {
+    fn from(step: TemplatePackStep) -> Self {
+        let mut definition = Self {
+            category: step.category,
+            when: step.when,
+            skip_if_exists: step.skip_if_exists,
+            template: None,
+            destination: None,
+            run: None,
+            description: None,
+            creates: None
+        };
+
+        match step.action {
+            TemplatePackStepAction::File {
+                template,
+                destination
+            } => {
+                definition.template = Some(template);
+                definition.destination = Some(destination);
+            }
+
+            TemplatePackStepAction::Command {
+                run,
+                description,
+                creates
+            } => {
+                definition.run = Some(run);
+                definition.description = description;
+                definition.creates = creates;
+            }
+        }
+
+        definition
+    }
+}
+
+/// Whether a `bool` is `false`, for skipping default values when serializing.
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Represents a template pack.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplatePack {
+    /// The name of the pack, which is the name of its directory.
+    pub name: String,
+
+    /// Where the pack's manifest was read from.
+    pub source: TemplateSource,
+
+    /// The pack's manifest.
+    pub manifest: TemplatePackManifest
+}
+
+impl TemplatePack {
+    /// Loads a template pack from the first layer of the
+    /// [`TemplateSearchPath`](super::search_path::TemplateSearchPath) that
+    /// holds its manifest.
+    ///
+    /// The pack's template files are resolved through the search path one by
+    /// one, so a layer can override a single file of a pack.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the pack (e.g. `rust`).
+    /// - `project_directory` - A directory in the project.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Load the built-in `rust` pack.
+    ///
+    /// ```rust
+    /// use std::env;
+    ///
+    /// use vscodeconfigurator_lib::template_ops::pack::TemplatePack;
+    ///
+    /// let template_pack = TemplatePack::load("rust", &env::current_dir().unwrap()).unwrap();
+    ///
+    /// assert!(!template_pack.manifest.steps.is_empty());
+    /// ```
+    pub fn load(
+        name: &str,
+        project_directory: &Path
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let manifest_path = manifest_path(name);
+
+        let template_source =
+            match TemplateSearchPath::new(project_directory).resolve(&manifest_path) {
+                Some(layer) => layer.source.clone(),
+                None => {
+                    return Err(CliError::new(
+                        format!("The template pack '{}' could not be found.", name).as_str(),
+                        CliErrorKind::TemplateNotFound
+                    )
+                    .into())
+                }
+            };
+
+        Self::from_source(name, template_source)
+    }
+
+    /// Loads a template pack from a specific template source.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the pack (e.g. `rust`).
+    /// - `template_source` - Where to read the pack's manifest from.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// A step can't write or create a path outside of the project.
+    ///
+    /// ```rust
+    /// use std::fs;
+    ///
+    /// use vscodeconfigurator_lib::{
+    ///     error::{CliError, CliErrorKind},
+    ///     template_ops::{pack::TemplatePack, TemplateSource}
+    /// };
+    ///
+    /// let templates_directory = std::env::temp_dir().join("pack-destination-example");
+    ///
+    /// for (name, step) in [
+    ///     ("escape-destination", "template = \"file.txt\"\ndestination = \"../file.txt\""),
+    ///     ("escape-creates", "run = [\"cargo\", \"init\"]\ncreates = \"../..\"")
+    /// ] {
+    ///     fs::create_dir_all(templates_directory.join(name)).unwrap();
+    ///     fs::write(
+    ///         templates_directory.join(name).join("template.toml"),
+    ///         format!("[[steps]]\n{}\n", step)
+    ///     )
+    ///     .unwrap();
+    ///
+    ///     let error = TemplatePack::from_source(
+    ///         name,
+    ///         TemplateSource::Directory(templates_directory.clone())
+    ///     )
+    ///     .unwrap_err();
+    ///
+    ///     assert!(matches!(
+    ///         error.downcast_ref::<CliError>().unwrap().kind,
+    ///         CliErrorKind::InvalidTemplatePack
+    ///     ));
+    /// }
+    /// ```
+    pub fn from_source(
+        name: &str,
+        template_source: TemplateSource
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let manifest_path = manifest_path(name);
+
+        let manifest_contents = match template_source.read(&manifest_path)? {
+            Some(contents) => String::from_utf8(contents)?,
+            None => {
+                return Err(CliError::new(
+                    format!(
+                        "The template pack '{}' could not be found in {}.",
+                        name, template_source
+                    )
+                    .as_str(),
+                    CliErrorKind::TemplateNotFound
+                )
+                .into())
+            }
+        };
+
+        let manifest = match toml::from_str::<TemplatePackManifest>(&manifest_contents) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                return Err(toml_parse_error(
+                    &e,
+                    &manifest_contents,
+                    &manifest_path,
+                    CliErrorKind::InvalidTemplatePack
+                )
+                .into())
+            }
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            source: template_source,
+            manifest
+        })
+    }
+
+    /// Gets the path of one of the pack's template files, relative to the root
+    /// of the templates directory.
+    ///
+    /// # Arguments
+    ///
+    /// - `template` - The path to the template file, relative to the pack.
+    pub fn template_path(
+        &self,
+        template: &str
+    ) -> String {
+        format!("{}/{}", self.name, template)
+    }
+
+    /// Fills in the pack's variables that aren't set in the context.
+    ///
+    /// A variable gets its default value if it has one. Otherwise the user is
+    /// asked for it with its prompt.
+    ///
+    /// # Arguments
+    ///
+    /// - `template_context` - The variables set so far.
+    /// - `logger` - The [`ConsoleLogger`](crate::logging::ConsoleLogger)
+    ///   instance for prompting.
+    pub fn resolve_variables(
+        &self,
+        template_context: &mut TemplateContext,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        for variable in &self.manifest.variables {
+            if template_context.contains(&variable.name) {
+                continue;
+            }
+
+            let value = match (&variable.default, &variable.prompt) {
+                (Some(toml::Value::String(default)), _) => {
+                    match engine::render(default, template_context, OutputFormat::Plain) {
+                        Ok(rendered) => Value::String(rendered),
+                        Err(e) => return Err(e.in_file(&manifest_path(&self.name)).into())
+                    }
+                }
+
+                (Some(default), _) => serde_json::to_value(default)?,
+
+                (None, Some(prompt)) => Value::String(logger.ask_for_value(prompt)?),
+
+                (None, None) => {
+                    return Err(CliError::new(
+                        format!(
+                            "The template pack '{}' needs a value for the '{}' variable.",
+                            self.name, variable.name
+                        )
+                        .as_str(),
+                        CliErrorKind::MissingTemplateVariable
+                    )
+                    .into())
+                }
+            };
+
+            template_context.set(&variable.name, value);
+        }
+
+        Ok(())
+    }
+
+    /// Runs the pack's steps in the output directory.
+    ///
+    /// Steps whose `when` condition isn't met are skipped. The pack's variables
+    /// are resolved with
+    /// [`resolve_variables`](TemplatePack::resolve_variables) first.
+    ///
+    /// # Arguments
+    ///
+    /// - `output_directory` - The output directory of the project.
+    /// - `template_context` - The variables available to the templates.
+    /// - `logger` - The [`ConsoleLogger`](crate::logging::ConsoleLogger)
+    ///   instance for logging.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Run the built-in `rust` pack in the `my-project` directory in the temp
+    /// directory.
+    ///
+    /// ```rust,ignore
+    /// use vscodeconfigurator_lib::{
+    ///     logging::ConsoleLogger,
+    ///     template_ops::{pack::TemplatePack, variables::builtin_variables}
+    /// };
+    ///
+    /// let output_directory = std::env::temp_dir().join("my-project");
+    /// let template_context = builtin_variables(&output_directory)
+    ///     .with("basePackageName", "my_package");
+    /// let mut logger = ConsoleLogger::new(None, None);
+    ///
+    /// let template_pack = TemplatePack::load("rust", &output_directory)?;
+    ///
+    /// template_pack.run(&output_directory, &template_context, &mut logger)?;
+    /// ```
+    pub fn run(
+        &self,
+        output_directory: &Path,
+        template_context: &TemplateContext,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut template_context = template_context.clone();
+        self.resolve_variables(&mut template_context, logger)?;
+
+        let mut current_category: Option<&str> = None;
+
+        for step in &self.manifest.steps {
+            if !step.condition_is_met(&template_context) {
+                continue;
+            }
+
+            if step.category.as_deref() != current_category {
+                if current_category.is_some() {
+                    logger.write_newline()?;
+                }
+
+                if let Some(category) = &step.category {
+                    logger.write_operation_category(category)?;
+                }
+
+                current_category = step.category.as_deref();
+            }
+
+            match &step.action {
+                TemplatePackStepAction::File {
+                    template,
+                    destination
+                } => self.run_file_step(
+                    output_directory,
+                    &template_context,
+                    template,
+                    destination,
+                    step.skip_if_exists,
+                    logger
+                )?,
+
+                TemplatePackStepAction::Command {
+                    run,
+                    description,
+                    creates
+                } => self.run_command_step(
+                    output_directory,
+                    &template_context,
+                    run,
+                    description.as_deref(),
+                    creates.as_deref(),
+                    step.skip_if_exists,
+                    logger
+                )?
+            }
+        }
+
+        if current_category.is_some() {
+            logger.write_newline()?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders a template file and writes it to the project.
+    #[allow(clippy::too_many_arguments)]
+    fn run_file_step(
+        &self,
+        output_directory: &Path,
+        template_context: &TemplateContext,
+        template: &str,
+        destination: &str,
+        skip_if_exists: bool,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let destination = self.render_value(destination, template_context)?;
+        let destination_path = PathBuf::from(&destination);
+
+        // A variable can still move the rendered destination out of the project.
+        let output_file_name = match destination_path.file_name() {
+            Some(file_name) if is_project_path(&destination) => {
+                file_name.to_string_lossy().to_string()
+            }
+            _ => {
+                return Err(CliError::new(
+                    format!(
+                        "The destination '{}' is not a relative file path inside the project.",
+                        destination
+                    )
+                    .as_str(),
+                    CliErrorKind::InvalidTemplatePack
+                )
+                .in_file(&manifest_path(&self.name))
+                .into())
+            }
+        };
+
+        let destination_directory = destination_path.parent().unwrap_or(Path::new(""));
+
+        let template_file = TemplateFile::new(
+            &self.template_path(template),
+            &output_directory.join(destination_directory),
+            &output_file_name
+        );
+
+        if template_file.output_file_exists && skip_if_exists {
+            return Ok(());
+        }
+
+        ensure_dirs_exist(output_directory, destination_directory, logger)?;
+
+        let location = match destination_directory.as_os_str().is_empty() {
+            true => "project root".to_string(),
+            false => format!("'{}' directory", destination_directory.display())
+        };
+
+        logger.write_operation_log(
+            format!(
+                "Copying '{}' to {}...",
+                &template_file.output_file_name, location
+            )
+            .as_str(),
+            OutputEmoji::Document
+        )?;
+
+        let rendered_contents = template_file.render(template_context)?;
+
+        // The current contents are read before the file is replaced, for the
+        // overwrite prompt and the diff written after the operation's log.
+        let original_contents = match template_file.output_file_exists {
+            true => Some(fs::read_to_string(&template_file.output_file_path)?),
+            false => None
+        };
+
+        let mut updated_contents = rendered_contents;
+
+        if logger.dry_run {
+            logger.write_dry_run_log(&[describe_planned_write(
+                &template_file.output_file_path,
+                logger.on_conflict
+            )])?;
+        } else {
+            if let Some(original_contents) = &original_contents {
+                let merged_contents = merge_json_contents(original_contents, &updated_contents);
+
+                let overwrite_response = logger.resolve_file_conflict(
+                    &destination,
+                    original_contents,
+                    &updated_contents,
+                    merged_contents.is_some()
+                )?;
+
+                match overwrite_response {
+                    OverwriteResponse::Overwrite => {}
+                    OverwriteResponse::Skip => {
+                        logger.write_warning("Already exists 🟠\n".to_string())?;
+                        return Ok(());
+                    }
+                    OverwriteResponse::Merge => updated_contents = merged_contents.unwrap()
+                }
+            }
+
+            fs::write(&template_file.output_file_path, &updated_contents)?;
+
+            logger.write_operation_success_log()?;
+        }
+
+        if let Some(original_contents) = original_contents.filter(|_| logger.show_diff) {
+            logger.write_diff(&destination, &original_contents, &updated_contents)?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs an external command in the output directory.
+    #[allow(clippy::too_many_arguments)]
+    fn run_command_step(
+        &self,
+        output_directory: &Path,
+        template_context: &TemplateContext,
+        run: &[String],
+        description: Option<&str>,
+        creates: Option<&str>,
+        skip_if_exists: bool,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let proc_args = run
+            .iter()
+            .map(|arg| self.render_value(arg, template_context))
+            .collect::<Result<Vec<String>, CliError>>()?;
+
+        let created_path = match creates {
+            Some(creates) => {
+                let creates = self.render_value(creates, template_context)?;
+
+                // The path is deleted on a conflict, so a variable mustn't move
+                // it out of the project.
+                if !is_project_path(&creates) {
+                    return Err(CliError::new(
+                        format!(
+                            "The path '{}' the step creates is not a relative path inside the project.",
+                            creates
+                        )
+                        .as_str(),
+                        CliErrorKind::InvalidTemplatePack
+                    )
+                    .in_file(&manifest_path(&self.name))
+                    .into());
+                }
+
+                Some(output_directory.join(creates))
+            }
+            None => None
+        };
+
+        let created_path_exists = created_path.as_ref().is_some_and(|path| path.exists());
+
+        if created_path_exists && skip_if_exists {
+            return Ok(());
+        }
+
+        let message = match description {
+            Some(description) => self.render_value(description, template_context)?,
+            None => format!("Running '{}'...", proc_args.join(" "))
+        };
+
+        logger.write_operation_log(&message, OutputEmoji::Package)?;
+        logger.save_cursor_position()?;
+
+        if logger.dry_run {
+            let mut planned_actions = Vec::new();
+
+            if let Some(created_path) = &created_path {
+                planned_actions.push(describe_planned_write(created_path, logger.on_conflict));
+            }
+
+            planned_actions.push(describe_planned_command(
+                &proc_args[0],
+                &proc_args[1..],
+                Some(output_directory)
+            ));
+
+            logger.write_dry_run_log(&planned_actions)?;
+
+            return Ok(());
+        }
+
+        if let Some(created_path) = created_path.filter(|_| created_path_exists) {
+            if !logger.resolve_conflict(&created_path)? {
+                logger.write_warning("Already exists 🟠\n".to_string())?;
+                return Ok(());
+            }
+
+            match created_path.is_dir() {
+                true => fs::remove_dir_all(&created_path)?,
+                false => fs::remove_file(&created_path)?
+            }
+        }
+
+        let proc_output = process::Command::new(&proc_args[0])
+            .args(&proc_args[1..])
+            .current_dir(output_directory)
+            .output()?;
+
+        if !proc_output.status.success() {
+            let mut message = format!("'{}' failed ({}).", proc_args.join(" "), proc_output.status);
+
+            let proc_stderr = String::from_utf8_lossy(&proc_output.stderr);
+
+            if !proc_stderr.trim().is_empty() {
+                message = format!("{}\n{}", message, proc_stderr.trim());
+            }
+
+            return Err(CliError::new(&message, CliErrorKind::TemplateStepFailed).into());
+        }
+
+        logger.write_operation_success_log()?;
+
+        Ok(())
+    }
+
+    /// Renders a value from the manifest, like a destination or a command
+    /// argument.
+    fn render_value(
+        &self,
+        value: &str,
+        template_context: &TemplateContext
+    ) -> Result<String, CliError> {
+        engine::render(value, template_context, OutputFormat::Plain)
+            .map_err(|e| e.in_file(&manifest_path(&self.name)))
+    }
+}
+
+impl TemplatePackStep {
+    /// Whether the step's `when` condition is met.
+    ///
+    /// # Arguments
+    ///
+    /// - `template_context` - The variables to check the condition against.
+    pub fn condition_is_met(
+        &self,
+        template_context: &TemplateContext
+    ) -> bool {
+        match &self.when {
+            None => true,
+            Some(when) => match when.trim().strip_prefix('!') {
+                Some(name) => !template_context.is_truthy(name.trim()),
+                None => template_context.is_truthy(when.trim())
+            }
+        }
+    }
+}
+
+/// Gets the path of a pack's manifest, relative to the root of the templates
+/// directory.
+fn manifest_path(name: &str) -> String {
+    format!("{}/{}", name, TEMPLATE_PACK_MANIFEST_FILE)
+}
+
+/// Creates each missing directory of a relative path in the output directory.
+fn ensure_dirs_exist(
+    output_directory: &Path,
+    relative_directory: &Path,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    // The files planned on a dry run already show the directories they need.
+    if logger.dry_run {
+        return Ok(());
+    }
+
+    let mut current_relative_directory = PathBuf::new();
+
+    for component in relative_directory.components() {
+        if let Component::Normal(name) = component {
+            current_relative_directory.push(name);
+        } else {
+            return Err(CliError::new(
+                format!(
+                    "The directory '{}' is not inside the output directory.",
+                    relative_directory.display()
+                )
+                .as_str(),
+                CliErrorKind::InvalidTemplatePack
+            )
+            .into());
+        }
+
+        let directory_path = output_directory.join(&current_relative_directory);
+
+        if !directory_path.exists() {
+            logger.write_operation_log(
+                format!(
+                    "Creating '{}' directory...",
+                    current_relative_directory.display()
+                )
+                .as_str(),
+                OutputEmoji::Folder
+            )?;
+
+            fs::create_dir(&directory_path)?;
+            logger.write_operation_success_log()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a path is relative and made only of normal components, so it can't
+/// point outside of the project.
+fn is_project_path(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Merges the rendered contents of a JSON file into its current contents.
+///
+/// Only the values that change are rewritten, so the comments and formatting
+/// of the current contents are kept. Returns `None` if either of them isn't
+/// valid JSON, since only JSON files can be merged.
+fn merge_json_contents(
+    original_contents: &str,
+    rendered_contents: &str
+) -> Option<String> {
+    let mut document = JsoncDocument::parse(original_contents).ok()?;
+    let rendered_values = JsoncDocument::parse(rendered_contents).ok()?.value();
+
+    let mut merged_values = document.value();
+    merge_json_values(&mut merged_values, rendered_values);
+
+    document.update(&merged_values).ok()?;
+
+    Some(document.text().to_string())
+}
@@ -0,0 +1,212 @@
+use std::{
+    env,
+    fmt,
+    path::{Path, PathBuf}
+};
+
+use super::{
+    installed::InstalledTemplateSets,
+    TemplateSource,
+    EMBEDDED_TEMPLATES,
+    TEMPLATES_DIR_ENV_VAR
+};
+use crate::io::get_app_config_dir;
+
+/// The name of the directory, relative to a repository's root, that holds the
+/// repository's templates.
+pub const REPO_TEMPLATES_DIR: &str = ".vscode-configurator/templates";
+
+/// The kind of a layer in the template search path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemplateLayerKind {
+    /// The directory set in the `VSCODECONFIGURATOR_TEMPLATES` environment
+    /// variable.
+    Environment,
+
+    /// The user's templates directory in
+    /// `$XDG_CONFIG_HOME/vscode-configurator/templates`.
+    User,
+
+    /// The repository's templates directory in
+    /// `<repo>/.vscode-configurator/templates`.
+    Repository,
+
+    /// A template set installed with `template add`, in
+    /// `$XDG_CONFIG_HOME/vscode-configurator/sets/<name>`.
+    Installed,
+
+    /// The templates embedded in the binary.
+    BuiltIn
+}
+
+/// Represents a single layer in the template search path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateLayer {
+    /// The kind of layer.
+    pub kind: TemplateLayerKind,
+
+    /// Where the layer's templates are read from.
+    pub source: TemplateSource
+}
+
+impl TemplateLayer {
+    /// Whether the layer holds the template at the relative path.
+    ///
+    /// # Arguments
+    ///
+    /// - `relative_template_file_path` - The relative path to the template file
+    ///   from the root of the templates directory.
+    pub fn contains(
+        &self,
+        relative_template_file_path: &str
+    ) -> bool {
+        match &self.source {
+            TemplateSource::Embedded => EMBEDDED_TEMPLATES
+                .get_file(relative_template_file_path)
+                .is_some(),
+
+            TemplateSource::Directory(templates_dir) => {
+                templates_dir.join(relative_template_file_path).is_file()
+            }
+        }
+    }
+}
+
+/// The ordered list of places templates are looked up in.
+///
+/// The first layer that holds a given template wins. In order, the layers are:
+///
+/// 1. `$VSCODECONFIGURATOR_TEMPLATES`
+/// 2. `$XDG_CONFIG_HOME/vscode-configurator/templates`
+/// 3. `<repo>/.vscode-configurator/templates`
+/// 4. The installed template sets, in the order they were installed.
+/// 5. The built-in templates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateSearchPath {
+    /// The layers, in order of precedence.
+    pub layers: Vec<TemplateLayer>
+}
+
+impl TemplateSearchPath {
+    /// Creates the template search path for a project.
+    ///
+    /// Layers whose directory does not exist are left out.
+    ///
+    /// # Arguments
+    ///
+    /// - `project_directory` - A directory in the project. The repository layer
+    ///   is the closest `.vscode-configurator/templates` directory in it or any
+    ///   of its parent directories.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Find which layer the Rust `settings.json` template resolves from.
+    ///
+    /// ```rust
+    /// use std::env;
+    ///
+    /// use vscodeconfigurator_lib::template_ops::search_path::TemplateSearchPath;
+    ///
+    /// let search_path = TemplateSearchPath::new(&env::current_dir().unwrap());
+    ///
+    /// let layer = search_path.resolve("rust/VSCode/settings.json");
+    /// ```
+    pub fn new(project_directory: &Path) -> Self {
+        let mut layers = Vec::new();
+
+        if let Some(templates_dir) = env::var_os(TEMPLATES_DIR_ENV_VAR) {
+            layers.push(TemplateLayer {
+                kind: TemplateLayerKind::Environment,
+                source: TemplateSource::Directory(PathBuf::from(templates_dir))
+            });
+        }
+
+        if let Some(user_templates_dir) = get_user_templates_dir() {
+            layers.push(TemplateLayer {
+                kind: TemplateLayerKind::User,
+                source: TemplateSource::Directory(user_templates_dir)
+            });
+        }
+
+        if let Some(repo_templates_dir) = find_repo_templates_dir(project_directory) {
+            layers.push(TemplateLayer {
+                kind: TemplateLayerKind::Repository,
+                source: TemplateSource::Directory(repo_templates_dir)
+            });
+        }
+
+        if let Ok(installed_sets) = InstalledTemplateSets::load() {
+            for installed_set in &installed_sets.sets {
+                if let Some(set_directory) = installed_set.directory() {
+                    layers.push(TemplateLayer {
+                        kind: TemplateLayerKind::Installed,
+                        source: TemplateSource::Directory(set_directory)
+                    });
+                }
+            }
+        }
+
+        layers.push(TemplateLayer {
+            kind: TemplateLayerKind::BuiltIn,
+            source: TemplateSource::Embedded
+        });
+
+        layers.retain(|layer| match &layer.source {
+            TemplateSource::Embedded => true,
+            TemplateSource::Directory(templates_dir) => templates_dir.is_dir()
+        });
+
+        Self { layers }
+    }
+
+    /// Gets the first layer that holds the template at the relative path.
+    ///
+    /// # Arguments
+    ///
+    /// - `relative_template_file_path` - The relative path to the template file
+    ///   from the root of the templates directory.
+    pub fn resolve(
+        &self,
+        relative_template_file_path: &str
+    ) -> Option<&TemplateLayer> {
+        self.layers
+            .iter()
+            .find(|layer| layer.contains(relative_template_file_path))
+    }
+}
+
+/// Gets the user's templates directory, which is the `templates` directory in
+/// the VSCode Configurator's configuration directory.
+///
+/// Returns `None` if the configuration directory can't be found.
+pub fn get_user_templates_dir() -> Option<PathBuf> {
+    get_app_config_dir().map(|app_config_dir| app_config_dir.join("templates"))
+}
+
+/// Finds the closest `.vscode-configurator/templates` directory in the
+/// directory or any of its parent directories.
+fn find_repo_templates_dir(start_directory: &Path) -> Option<PathBuf> {
+    start_directory
+        .ancestors()
+        .map(|directory| directory.join(REPO_TEMPLATES_DIR))
+        .find(|templates_dir| templates_dir.is_dir())
+}
+
+impl fmt::Display for TemplateLayerKind {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>
+    ) -> fmt::Result {
+        let name = match self {
+            TemplateLayerKind::Environment => "environment",
+            TemplateLayerKind::User => "user",
+            TemplateLayerKind::Repository => "repository",
+            TemplateLayerKind::Installed => "installed",
+            TemplateLayerKind::BuiltIn => "built-in"
+        };
+
+        write!(f, "{}", name)
+    }
+}
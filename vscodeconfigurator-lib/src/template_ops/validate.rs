@@ -0,0 +1,522 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf}
+};
+
+use serde_json::Value;
+
+use super::{
+    engine::{self, OutputFormat, Template, TemplateContext},
+    pack::{TemplatePack, TemplatePackStepAction, TEMPLATE_PACK_MANIFEST_FILE},
+    variables::{builtin_variables, BUILTIN_VARIABLE_NAMES},
+    TemplateSource
+};
+use crate::{
+    error::{CliError, CliErrorKind},
+    vscode_ops::jsonc::JsoncDocument
+};
+
+/// The result of validating one template pack in a templates directory.
+#[derive(Debug)]
+pub struct TemplatePackValidation {
+    /// The name of the pack.
+    pub name: String,
+
+    /// The problems found in the pack.
+    pub problems: Vec<CliError>
+}
+
+/// Validates every template pack in a templates directory.
+///
+/// Each directory in the templates directory is a pack. A pack either has its
+/// own `template.toml` or overrides files of the built-in pack with the same
+/// name. For every pack, this checks that:
+///
+/// - The manifest parses.
+/// - Every template file used by a step exists, in the directory or in the
+///   built-in templates.
+/// - Every file in the pack's directory is used by a step.
+/// - Every variable used by a template or a step is a built-in variable or one
+///   the pack declares.
+/// - Every template renders with sample values, and `.json`/`.toml` output
+///   parses.
+///
+/// Templates are rendered twice: once with the default values and once with
+/// every boolean variable flipped, so both sides of `{{#if}}` blocks are
+/// checked.
+///
+/// # Arguments
+///
+/// - `templates_dir` - The templates directory to validate.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator_lib::template_ops::validate::validate_templates_dir;
+///
+/// let templates_dir = std::env::current_dir().unwrap().join("templates");
+///
+/// for pack_validation in validate_templates_dir(&templates_dir)? {
+///     for problem in pack_validation.problems {
+///         println!("{}", problem);
+///     }
+/// }
+/// ```
+pub fn validate_templates_dir(
+    templates_dir: &Path
+) -> Result<Vec<TemplatePackValidation>, Box<dyn std::error::Error>> {
+    if !templates_dir.is_dir() {
+        return Err(CliError::new(
+            format!(
+                "The templates directory '{}' does not exist.",
+                templates_dir.display()
+            )
+            .as_str(),
+            CliErrorKind::FilePathDoesNotExist
+        )
+        .into());
+    }
+
+    let mut pack_names = fs::read_dir(templates_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+
+    pack_names.sort();
+
+    // Templates are rendered with the same built-in variables they're applied
+    // with.
+    let builtin_context = builtin_variables(templates_dir);
+
+    let pack_validations = pack_names
+        .into_iter()
+        .map(|pack_name| {
+            let problems = validate_pack(templates_dir, &pack_name, &builtin_context);
+
+            TemplatePackValidation {
+                name: pack_name,
+                problems
+            }
+        })
+        .collect();
+
+    Ok(pack_validations)
+}
+
+/// Validates a single template pack in a templates directory.
+fn validate_pack(
+    templates_dir: &Path,
+    pack_name: &str,
+    builtin_context: &TemplateContext
+) -> Vec<CliError> {
+    let templates_dir_source = TemplateSource::Directory(templates_dir.to_path_buf());
+    let manifest_path = format!("{}/{}", pack_name, TEMPLATE_PACK_MANIFEST_FILE);
+
+    let manifest_source = match templates_dir.join(&manifest_path).is_file() {
+        true => templates_dir_source.clone(),
+        false => TemplateSource::Embedded
+    };
+
+    let template_pack = match TemplatePack::from_source(pack_name, manifest_source) {
+        Ok(template_pack) => template_pack,
+        Err(e) => {
+            let problem = match e.downcast::<CliError>() {
+                Ok(cli_error) if matches!(cli_error.kind, CliErrorKind::TemplateNotFound) => {
+                    CliError::new(
+                        format!(
+                            "'{}' has no '{}' and does not override a built-in pack.",
+                            pack_name, TEMPLATE_PACK_MANIFEST_FILE
+                        )
+                        .as_str(),
+                        CliErrorKind::InvalidTemplatePack
+                    )
+                    .in_file(&display_path(templates_dir, pack_name))
+                }
+
+                Ok(cli_error) => relocate_error(*cli_error, templates_dir, &manifest_path),
+
+                Err(e) => CliError::new(&e.to_string(), CliErrorKind::InvalidTemplatePack)
+                    .in_file(&display_path(templates_dir, &manifest_path))
+            };
+
+            return vec![problem];
+        }
+    };
+
+    let manifest_display_path = match template_pack.source {
+        TemplateSource::Embedded => format!("{} (built-in)", manifest_path),
+        TemplateSource::Directory(_) => display_path(templates_dir, &manifest_path)
+    };
+
+    let mut known_variables = BUILTIN_VARIABLE_NAMES
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<BTreeSet<String>>();
+
+    known_variables.extend(
+        template_pack
+            .manifest
+            .variables
+            .iter()
+            .map(|variable| variable.name.clone())
+    );
+
+    let mut problems = Vec::new();
+
+    let sample_contexts = match sample_contexts(&template_pack, builtin_context) {
+        Ok(sample_contexts) => sample_contexts,
+        Err(e) => {
+            problems.push(e.in_file(&manifest_display_path));
+            Vec::new()
+        }
+    };
+
+    let mut used_template_paths = BTreeSet::new();
+
+    for (step_index, step) in template_pack.manifest.steps.iter().enumerate() {
+        let step_number = step_index + 1;
+
+        if let Some(when) = &step.when {
+            let name = when.trim().trim_start_matches('!').trim();
+
+            if !known_variables.contains(name) {
+                problems.push(
+                    CliError::new(
+                        format!(
+                            "Step {} uses the variable '{}' in 'when', but the pack doesn't \
+                             declare it.",
+                            step_number, name
+                        )
+                        .as_str(),
+                        CliErrorKind::UnknownTemplateVariable
+                    )
+                    .in_file(&manifest_display_path)
+                );
+            }
+        }
+
+        let step_values = match &step.action {
+            TemplatePackStepAction::File { destination, .. } => vec![destination.clone()],
+            TemplatePackStepAction::Command {
+                run,
+                description,
+                creates
+            } => run
+                .iter()
+                .chain(description.iter())
+                .chain(creates.iter())
+                .cloned()
+                .collect()
+        };
+
+        for step_value in step_values {
+            match Template::parse(&step_value) {
+                Ok(template) => {
+                    for (path, _) in template.variable_paths_with_lines() {
+                        let name = path.split('.').next().unwrap_or_default();
+
+                        if !known_variables.contains(name) {
+                            problems.push(
+                                CliError::new(
+                                    format!(
+                                        "Step {} uses the variable '{}', but the pack doesn't \
+                                         declare it.",
+                                        step_number, name
+                                    )
+                                    .as_str(),
+                                    CliErrorKind::UnknownTemplateVariable
+                                )
+                                .in_file(&manifest_display_path)
+                            );
+                        }
+                    }
+                }
+
+                Err(e) => problems.push(
+                    CliError::new(
+                        format!("Step {}: {}", step_number, e.message).as_str(),
+                        e.kind
+                    )
+                    .in_file(&manifest_display_path)
+                )
+            }
+        }
+
+        let TemplatePackStepAction::File {
+            template,
+            destination
+        } = &step.action
+        else {
+            continue;
+        };
+
+        let template_path = template_pack.template_path(template);
+        used_template_paths.insert(PathBuf::from(&template_path));
+
+        let (template_source, template_display_path) =
+            match templates_dir.join(&template_path).is_file() {
+                true => (
+                    templates_dir_source.clone(),
+                    display_path(templates_dir, &template_path)
+                ),
+                false => (
+                    TemplateSource::Embedded,
+                    format!("{} (built-in)", template_path)
+                )
+            };
+
+        let template_contents = match template_source.read(&template_path) {
+            Ok(Some(contents)) => String::from_utf8_lossy(&contents).to_string(),
+            Ok(None) => {
+                problems.push(
+                    CliError::new(
+                        format!(
+                            "Step {} uses the template '{}', which doesn't exist.",
+                            step_number, template_path
+                        )
+                        .as_str(),
+                        CliErrorKind::TemplateNotFound
+                    )
+                    .in_file(&manifest_display_path)
+                );
+                continue;
+            }
+            Err(e) => {
+                problems.push(
+                    CliError::new(&e.to_string(), CliErrorKind::TemplateNotFound)
+                        .in_file(&template_display_path)
+                );
+                continue;
+            }
+        };
+
+        let parsed_template = match Template::parse(&template_contents) {
+            Ok(parsed_template) => parsed_template,
+            Err(e) => {
+                problems.push(e.in_file(&template_display_path));
+                continue;
+            }
+        };
+
+        let mut has_unknown_variables = false;
+
+        for (path, line) in parsed_template.variable_paths_with_lines() {
+            let name = path.split('.').next().unwrap_or_default();
+
+            if !known_variables.contains(name) {
+                has_unknown_variables = true;
+
+                problems.push(
+                    CliError::new(
+                        format!(
+                            "'{}' is not a built-in variable or a variable the '{}' pack declares.",
+                            name, template_pack.name
+                        )
+                        .as_str(),
+                        CliErrorKind::UnknownTemplateVariable
+                    )
+                    .in_file(&template_display_path)
+                    .at_line(line)
+                );
+            }
+        }
+
+        if has_unknown_variables {
+            continue;
+        }
+
+        let output_format = OutputFormat::from_file_name(destination);
+
+        let mut template_problems: Vec<CliError> = Vec::new();
+
+        for (sample_description, sample_context) in &sample_contexts {
+            let problem = match parsed_template.render(sample_context, output_format) {
+                Ok(rendered) => check_rendered_output(&rendered, output_format)
+                    .err()
+                    .map(|e| {
+                        CliError::new(
+                            format!("{} (rendered {})", e.message, sample_description).as_str(),
+                            e.kind
+                        )
+                        .in_file(&template_display_path)
+                    }),
+                Err(e) => Some(e.in_file(&template_display_path))
+            };
+
+            if let Some(problem) = problem {
+                if !template_problems
+                    .iter()
+                    .any(|existing| existing.to_string() == problem.to_string())
+                {
+                    template_problems.push(problem);
+                }
+            }
+        }
+
+        problems.extend(template_problems);
+    }
+
+    for unused_file_path in find_unused_files(templates_dir, pack_name, &used_template_paths) {
+        problems.push(
+            CliError::new(
+                "The file isn't used by any step of the pack, so it is never written.",
+                CliErrorKind::InvalidTemplatePack
+            )
+            .in_file(&display_path(
+                templates_dir,
+                &unused_file_path.to_string_lossy()
+            ))
+        );
+    }
+
+    problems
+}
+
+/// Creates the contexts templates are rendered with, with a description of
+/// each: one with the default values and one with every boolean variable
+/// flipped.
+fn sample_contexts(
+    template_pack: &TemplatePack,
+    builtin_context: &TemplateContext
+) -> Result<Vec<(&'static str, TemplateContext)>, CliError> {
+    let mut default_context = builtin_context.clone();
+
+    for variable in &template_pack.manifest.variables {
+        let value = match &variable.default {
+            Some(toml::Value::String(default)) => Value::String(engine::render(
+                default,
+                &default_context,
+                OutputFormat::Plain
+            )?),
+            Some(default) => serde_json::to_value(default).unwrap_or(Value::Null),
+            None => Value::String(format!("sample_{}", variable.name))
+        };
+
+        default_context.set(&variable.name, value);
+    }
+
+    let mut flipped_context = default_context.clone();
+
+    for variable in &template_pack.manifest.variables {
+        if let Some(Value::Bool(value)) = default_context.get(&variable.name) {
+            flipped_context.set(&variable.name, !value);
+        }
+    }
+
+    match flipped_context == default_context {
+        true => Ok(vec![("with the default values", default_context)]),
+        false => Ok(vec![
+            ("with the default values", default_context),
+            ("with every boolean variable flipped", flipped_context),
+        ])
+    }
+}
+
+/// Checks that rendered output parses in its format.
+fn check_rendered_output(
+    rendered: &str,
+    output_format: OutputFormat
+) -> Result<(), CliError> {
+    match output_format {
+        // VSCode's JSON files allow comments and trailing commas.
+        OutputFormat::Json => match JsoncDocument::parse(rendered) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(CliError::new(
+                format!(
+                    "The rendered output is not valid JSON (line {} of the output): {}",
+                    e.line.unwrap_or(1),
+                    e.message
+                )
+                .as_str(),
+                CliErrorKind::TemplateRenderError
+            ))
+        },
+
+        OutputFormat::Toml => match toml::from_str::<toml::Table>(rendered) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let output_line = e
+                    .span()
+                    .map(|span| rendered[..span.start].matches('\n').count() + 1)
+                    .unwrap_or(1);
+
+                Err(CliError::new(
+                    format!(
+                        "The rendered output is not valid TOML (line {} of the output): {}",
+                        output_line,
+                        e.message()
+                    )
+                    .as_str(),
+                    CliErrorKind::TemplateRenderError
+                ))
+            }
+        },
+
+        OutputFormat::Plain => Ok(())
+    }
+}
+
+/// Finds the files in a pack's directory that aren't used by any step.
+fn find_unused_files(
+    templates_dir: &Path,
+    pack_name: &str,
+    used_template_paths: &BTreeSet<PathBuf>
+) -> Vec<PathBuf> {
+    let mut unused_file_paths = Vec::new();
+    let mut pending_dirs = vec![PathBuf::from(pack_name)];
+
+    while let Some(relative_dir) = pending_dirs.pop() {
+        let Ok(entries) = fs::read_dir(templates_dir.join(&relative_dir)) else {
+            continue;
+        };
+
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            let relative_path = relative_dir.join(entry.file_name());
+
+            if entry.path().is_dir() {
+                pending_dirs.push(relative_path);
+            } else if relative_path != Path::new(pack_name).join(TEMPLATE_PACK_MANIFEST_FILE)
+                && !used_template_paths.contains(&relative_path)
+            {
+                unused_file_paths.push(relative_path);
+            }
+        }
+    }
+
+    unused_file_paths.sort();
+
+    unused_file_paths
+}
+
+/// Points an error about a file relative to the templates directory at the
+/// file in the templates directory.
+fn relocate_error(
+    error: CliError,
+    templates_dir: &Path,
+    relative_path: &str
+) -> CliError {
+    let line = error.line;
+    let mut relocated_error = CliError::new(&error.message, error.kind)
+        .in_file(&display_path(templates_dir, relative_path));
+
+    if let Some(line) = line {
+        relocated_error = relocated_error.at_line(line);
+    }
+
+    relocated_error
+}
+
+/// Gets the path to display for a file in the templates directory.
+fn display_path(
+    templates_dir: &Path,
+    relative_path: &str
+) -> String {
+    templates_dir
+        .join(relative_path)
+        .to_string_lossy()
+        .to_string()
+}
@@ -0,0 +1,261 @@
+use std::{
+    fs,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use serde_json::Value;
+
+use super::{engine::TemplateContext, toml_parse_error};
+use crate::{
+    error::{CliError, CliErrorKind},
+    external_procs::git
+};
+
+/// The names of the variables set by [`builtin_variables`].
+pub const BUILTIN_VARIABLE_NAMES: &[&str] = &[
+    "gitUserName",
+    "gitUserEmail",
+    "authors",
+    "year",
+    "outputDirectoryName",
+    "gitRemoteUrl",
+    "repositoryUrl",
+    "homepage"
+];
+
+/// Creates a context with the built-in variables for a project.
+///
+/// | Variable | Value |
+/// | --- | --- |
+/// | `gitUserName` | The `user.name` value from the Git configuration. |
+/// | `gitUserEmail` | The `user.email` value from the Git configuration. |
+/// | `authors` | A list with `gitUserName <gitUserEmail>`, or an empty list if `gitUserName` is not set. |
+/// | `year` | The current year. |
+/// | `outputDirectoryName` | The name of the output directory. |
+/// | `gitRemoteUrl` | The URL of the repository's `origin` remote. |
+/// | `repositoryUrl` | `gitRemoteUrl` as an `https://` URL. |
+/// | `homepage` | The same as `repositoryUrl`. |
+///
+/// Variables whose value can't be found are set to an empty string, so
+/// templates can still be rendered.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust
+/// use vscodeconfigurator_lib::template_ops::variables::builtin_variables;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+///
+/// let context = builtin_variables(&output_directory);
+///
+/// assert_eq!(context.get("outputDirectoryName").unwrap(), "my-project");
+/// ```
+pub fn builtin_variables(output_directory: &Path) -> TemplateContext {
+    let git_user_name = git::get_config_value(output_directory, "user.name").unwrap_or_default();
+    let git_user_email = git::get_config_value(output_directory, "user.email").unwrap_or_default();
+    let git_remote_url = git::get_remote_url(output_directory).unwrap_or_default();
+    let repository_url = normalize_repository_url(&git_remote_url);
+
+    let authors = match (git_user_name.is_empty(), git_user_email.is_empty()) {
+        (true, _) => vec![],
+        (false, true) => vec![git_user_name.clone()],
+        (false, false) => vec![format!("{} <{}>", git_user_name, git_user_email)]
+    };
+
+    let output_directory_name = output_directory
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    TemplateContext::new()
+        .with("gitUserName", git_user_name)
+        .with("gitUserEmail", git_user_email)
+        .with("authors", authors)
+        .with("year", current_year())
+        .with("outputDirectoryName", output_directory_name)
+        .with("gitRemoteUrl", git_remote_url)
+        .with("repositoryUrl", repository_url.clone())
+        .with("homepage", repository_url)
+}
+
+/// Parses a `key=value` variable assignment.
+///
+/// The values `true` and `false` are parsed as booleans, so they can be used in
+/// `{{#if}}` blocks. Every other value is a string.
+///
+/// # Arguments
+///
+/// - `assignment` - The variable assignment.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust
+/// use serde_json::Value;
+/// use vscodeconfigurator_lib::template_ops::variables::parse_var_assignment;
+///
+/// let (name, value) = parse_var_assignment("homepage=https://example.com").unwrap();
+///
+/// assert_eq!(name, "homepage");
+/// assert_eq!(value, Value::from("https://example.com"));
+///
+/// assert!(parse_var_assignment("homepage").is_err());
+/// ```
+pub fn parse_var_assignment(assignment: &str) -> Result<(String, Value), CliError> {
+    let (name, value) = match assignment.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => (name.trim(), value),
+        _ => {
+            return Err(CliError::new(
+                format!(
+                    "The variable '{}' must be in the form 'key=value'.",
+                    assignment
+                )
+                .as_str(),
+                CliErrorKind::InvalidTemplateVariable
+            ))
+        }
+    };
+
+    let value = match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::String(value.to_string())
+    };
+
+    Ok((name.to_string(), value))
+}
+
+/// Loads variables from a TOML file.
+///
+/// Each top-level key of the file is a variable. Values can be strings,
+/// numbers, booleans, arrays or tables.
+///
+/// # Arguments
+///
+/// - `vars_file_path` - The path to the TOML file.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator_lib::template_ops::variables::load_vars_file;
+///
+/// let vars_file_path = std::env::current_dir().unwrap().join("vars.toml");
+///
+/// let context = load_vars_file(&vars_file_path);
+/// ```
+pub fn load_vars_file(
+    vars_file_path: &Path
+) -> Result<TemplateContext, Box<dyn std::error::Error>> {
+    let vars_file_contents = match fs::read_to_string(vars_file_path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            return Err(CliError::new(
+                format!("Unable to read the variables file: {}", e).as_str(),
+                CliErrorKind::InvalidTemplateVariable
+            )
+            .in_file(&vars_file_path.to_string_lossy())
+            .into())
+        }
+    };
+
+    let variables = match toml::from_str::<toml::Table>(&vars_file_contents) {
+        Ok(variables) => variables,
+        Err(e) => {
+            return Err(toml_parse_error(
+                &e,
+                &vars_file_contents,
+                &vars_file_path.to_string_lossy(),
+                CliErrorKind::InvalidTemplateVariable
+            )
+            .into())
+        }
+    };
+
+    let mut context = TemplateContext::new();
+
+    for (name, value) in variables {
+        context.set(&name, serde_json::to_value(value)?);
+    }
+
+    Ok(context)
+}
+
+/// Converts a Git remote URL to an `https://` URL.
+///
+/// SSH remotes (`git@github.com:owner/repo.git`) are rewritten, and the `.git`
+/// suffix is removed. URLs that aren't recognized are returned unchanged.
+///
+/// # Arguments
+///
+/// - `git_remote_url` - The Git remote URL.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust
+/// use vscodeconfigurator_lib::template_ops::variables::normalize_repository_url;
+///
+/// assert_eq!(
+///     normalize_repository_url("git@github.com:owner/repo.git"),
+///     "https://github.com/owner/repo"
+/// );
+/// assert_eq!(
+///     normalize_repository_url("https://github.com/owner/repo.git"),
+///     "https://github.com/owner/repo"
+/// );
+/// ```
+pub fn normalize_repository_url(git_remote_url: &str) -> String {
+    let git_remote_url = git_remote_url.trim();
+
+    let https_url = if let Some(ssh_path) = git_remote_url.strip_prefix("ssh://git@") {
+        format!("https://{}", ssh_path)
+    } else if let Some(scp_path) = git_remote_url.strip_prefix("git@") {
+        match scp_path.split_once(':') {
+            Some((host, path)) => format!("https://{}/{}", host, path),
+            None => git_remote_url.to_string()
+        }
+    } else {
+        git_remote_url.to_string()
+    };
+
+    match https_url.starts_with("https://") || https_url.starts_with("http://") {
+        true => https_url.trim_end_matches(".git").to_string(),
+        false => https_url
+    }
+}
+
+/// Gets the current year in UTC.
+fn current_year() -> i64 {
+    let days_since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+
+    // Converts days since 1970-01-01 to a year in the proleptic Gregorian
+    // calendar (see http://howardhinnant.github.io/date_algorithms.html).
+    let shifted_days = days_since_epoch + 719_468;
+    let era = shifted_days.div_euclid(146_097);
+    let day_of_era = shifted_days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+
+    let year = year_of_era + era * 400;
+
+    match month_index < 10 {
+        true => year,
+        false => year + 1
+    }
+}
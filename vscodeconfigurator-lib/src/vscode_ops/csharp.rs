@@ -1,14 +1,46 @@
-use std::path::PathBuf;
+use std::{
+    fs,
+    path::{Path, PathBuf}
+};
 
 use serde_json::{json, Value};
 
 use crate::{
+    dotnet::{msbuild::MsBuildFile, solution::SolutionFile},
+    error::{CliError, CliErrorKind},
     lang_options::CsharpLspOption,
     logging::{ConsoleLogger, OutputEmoji},
-    vscode_ops::{VSCodeSettingsFile, VSCodeTasksFile}
+    vscode_ops::{
+        launch::{LaunchConfig, LaunchConfiguration},
+        tasks::{ProblemMatcher, Task, TaskInputOption, TaskOptions},
+        VSCodeExtensionsFile,
+        VSCodeLaunchFile,
+        VSCodeSettingsFile,
+        VSCodeTasksFile
+    }
 };
 
-/// Updates the C# LSP option in the `.vscode/settings.json` file.
+/// The C# extension, which has the C# language server and OmniSharp.
+const CSHARP_EXTENSION: &str = "ms-dotnettools.csharp";
+
+/// The C# Dev Kit extension, which only works with the C# language server.
+const CSHARP_DEV_KIT_EXTENSION: &str = "ms-dotnettools.csdevkit";
+
+/// The IDs of the inputs in `.vscode/tasks.json` that have an option for each
+/// project.
+const PROJECT_INPUT_IDS: [&str; 4] = [
+    "projectItem",
+    "watchProject",
+    "runProject",
+    "nativeAotCompileProject"
+];
+
+/// The ID of the input in `.vscode/launch.json` that prompts for the path to a
+/// project's built `.dll`.
+const PROGRAM_PATH_INPUT_ID: &str = "programPath";
+
+/// Updates the C# LSP option in the `.vscode/settings.json` file, and the
+/// recommended extensions in the `.vscode/extensions.json` file to match it.
 ///
 /// # Arguments
 ///
@@ -25,31 +57,39 @@ use crate::{
 /// Update the C# LSP option in the `.vscode/settings.json` file to use
 /// OmniSharp.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     lang_options::CsharpLspOption,
+///     logging::ConsoleLogger,
+///     vscode_ops::csharp::update_csharp_lsp
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("my-project");
 /// let csharp_lsp = CsharpLspOption::OmniSharp;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// update_csharp_lsp(&output_directory, csharp_lsp, logger);
+/// update_csharp_lsp(&output_directory, csharp_lsp, &mut logger).unwrap();
 /// ```
 ///
 /// ## Example 02
 ///
 /// Update the C# LSP option in the `.vscode/settings.json` file to use C# LSP.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     lang_options::CsharpLspOption,
+///     logging::ConsoleLogger,
+///     vscode_ops::csharp::update_csharp_lsp
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("my-project");
 /// let csharp_lsp = CsharpLspOption::CsharpLsp;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
-/// update_csharp_lsp(&output_directory, csharp_lsp, logger);
+/// update_csharp_lsp(&output_directory, csharp_lsp, &mut logger).unwrap();
 /// ```
 pub fn update_csharp_lsp(
-    output_directory: &PathBuf,
+    output_directory: &Path,
     csharp_lsp: CsharpLspOption,
     logger: &mut ConsoleLogger
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -61,23 +101,118 @@ pub fn update_csharp_lsp(
     let mut vscode_settings =
         VSCodeSettingsFile::new(output_directory.join(".vscode/settings.json"))?;
 
-    vscode_settings.values["dotnet.server.useOmnisharp"] = match csharp_lsp {
-        CsharpLspOption::CsharpLsp => Value::Bool(false),
-        CsharpLspOption::OmniSharp => Value::Bool(true)
-    };
+    vscode_settings.set(
+        "dotnet.server.useOmnisharp",
+        match csharp_lsp {
+            CsharpLspOption::CsharpLsp => Value::Bool(false),
+            CsharpLspOption::OmniSharp => Value::Bool(true)
+        }
+    );
 
-    vscode_settings.values["dotnet.server.path"] = match csharp_lsp {
-        CsharpLspOption::CsharpLsp => Value::String("".to_string()),
-        CsharpLspOption::OmniSharp => Value::String("latest".to_string())
-    };
+    vscode_settings.set(
+        "dotnet.server.path",
+        match csharp_lsp {
+            CsharpLspOption::CsharpLsp => Value::String("".to_string()),
+            CsharpLspOption::OmniSharp => Value::String("latest".to_string())
+        }
+    );
+
+    vscode_settings.save(output_directory, logger)?;
+
+    update_csharp_extension_recommendations(output_directory, csharp_lsp, logger)?;
+
+    Ok(())
+}
+
+/// Updates the recommended extensions in the `.vscode/extensions.json` file
+/// to match the C# language server, creating the file if it doesn't exist.
+///
+/// The C# extension is always recommended. The C# Dev Kit is recommended with
+/// the C# language server, and recommended against with OmniSharp, which it
+/// doesn't work with. The recommendations are merged into the file, so the
+/// other extensions in it are kept.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `csharp_lsp` - The C# language server to use.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let csharp_lsp = CsharpLspOption::OmniSharp;
+/// let mut logger = ConsoleLogger::new();
+///
+/// update_csharp_extension_recommendations(&output_directory, csharp_lsp, logger);
+/// ```
+pub fn update_csharp_extension_recommendations(
+    output_directory: &Path,
+    csharp_lsp: CsharpLspOption,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        "Updating recommended extensions in extensions.json...",
+        OutputEmoji::Document
+    )?;
 
-    vscode_settings.write_settings()?;
+    let mut vscode_extensions =
+        VSCodeExtensionsFile::new_or_default(output_directory.join(".vscode/extensions.json"))?;
 
-    logger.write_operation_success_log()?;
+    match csharp_lsp {
+        CsharpLspOption::CsharpLsp => {
+            vscode_extensions
+                .values
+                .recommend(CSHARP_DEV_KIT_EXTENSION);
+            vscode_extensions.values.recommend(CSHARP_EXTENSION);
+        }
+        CsharpLspOption::OmniSharp => {
+            vscode_extensions.values.recommend(CSHARP_EXTENSION);
+            vscode_extensions
+                .values
+                .mark_unwanted(CSHARP_DEV_KIT_EXTENSION);
+        }
+    }
+
+    vscode_extensions.save(output_directory, logger)?;
 
     Ok(())
 }
 
+/// Reads the C# language server that the `.vscode/settings.json` file uses.
+///
+/// The C# language server is used if the file doesn't exist or doesn't set
+/// `dotnet.server.useOmnisharp`.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+pub fn read_csharp_lsp(
+    output_directory: &Path
+) -> Result<CsharpLspOption, Box<dyn std::error::Error>> {
+    let settings_file_path = output_directory.join(".vscode/settings.json");
+
+    if !settings_file_path.exists() {
+        return Ok(CsharpLspOption::CsharpLsp);
+    }
+
+    let vscode_settings = VSCodeSettingsFile::new(settings_file_path)?;
+
+    let csharp_lsp = match vscode_settings.values.get("dotnet.server.useOmnisharp") {
+        Some(Value::Bool(true)) => CsharpLspOption::OmniSharp,
+        _ => CsharpLspOption::CsharpLsp
+    };
+
+    Ok(csharp_lsp)
+}
+
 /// Adds a C# project to the `.vscode/tasks.json` file.
 ///
 /// # Arguments
@@ -85,7 +220,8 @@ pub fn update_csharp_lsp(
 /// - `output_directory` - The output directory of the project.
 /// - `project_path` - The path to the C# project.
 /// - `project_friendly_name` - The friendly name of the project.
-/// - `is_runnable` - Whether the project is runnable.
+/// - `is_runnable` - Whether the project is runnable. A runnable project also
+///   gets a task that builds it, which its launch configuration runs first.
 /// - `is_watchable` - Whether the project is watchable.
 /// - `logger` - The
 ///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
@@ -98,15 +234,18 @@ pub fn update_csharp_lsp(
 /// Add a C# project named `ConsoleApp` in the `ConsoleApp/` directory to the
 /// `.vscode/tasks.json` file.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{
+///     logging::ConsoleLogger,
+///     vscode_ops::csharp::add_csharp_project_to_tasks
+/// };
 ///
 /// let output_directory = std::env::temp_dir().join("my-project");
 /// let project_path = std::env::temp_dir().join("my-project/ConsoleApp");
 /// let project_friendly_name = "ConsoleApp";
 /// let is_runnable = true;
 /// let is_watchable = true;
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
 /// add_csharp_project_to_tasks(
 ///     &output_directory,
@@ -114,12 +253,13 @@ pub fn update_csharp_lsp(
 ///     project_friendly_name,
 ///     is_runnable,
 ///     is_watchable,
-///     logger
-/// );
+///     &mut logger
+/// )
+/// .unwrap();
 /// ```
 pub fn add_csharp_project_to_tasks(
-    output_directory: &PathBuf,
-    project_path: &PathBuf,
+    output_directory: &Path,
+    project_path: &Path,
     project_friendly_name: &str,
     is_runnable: bool,
     is_watchable: bool,
@@ -129,36 +269,750 @@ pub fn add_csharp_project_to_tasks(
 
     let mut vscode_tasks = VSCodeTasksFile::new(output_directory.join(".vscode/tasks.json"))?;
 
-    let inputs_node = vscode_tasks.values["inputs"].as_array_mut().unwrap();
+    let csharp_project_input =
+        TaskInputOption::labeled(project_friendly_name, &project_path.to_string_lossy());
 
-    let csharp_project_input = json!({
-        "label": project_friendly_name,
-        "value": project_path.to_string_lossy().to_string()
-    });
+    vscode_tasks
+        .values
+        .input_mut("projectItem")?
+        .add_option(csharp_project_input.clone())?;
 
-    for input_node_item in inputs_node.iter_mut() {
-        if input_node_item["id"] == "projectItem" {
-            let input_node_options = input_node_item["options"].as_array_mut().unwrap();
+    if is_watchable {
+        vscode_tasks
+            .values
+            .input_mut("watchProject")?
+            .add_option(csharp_project_input.clone())?;
+    }
 
-            input_node_options.push(csharp_project_input.clone());
-        }
+    if is_runnable {
+        vscode_tasks
+            .values
+            .input_mut("runProject")?
+            .add_option(csharp_project_input)?;
+
+        vscode_tasks
+            .values
+            .add_task(project_build_task(project_path, project_friendly_name));
+    }
+
+    vscode_tasks.save(output_directory, logger)?;
+
+    Ok(())
+}
+
+/// Adds a launch configuration for a runnable C# project to the
+/// `.vscode/launch.json` file, creating the file if it doesn't exist.
+///
+/// The configuration launches the project's built `.dll` with the `coreclr`
+/// debugger, after building the project with the task that
+/// [`add_csharp_project_to_tasks`] adds for it. The path to the `.dll` is
+/// worked out from the project's target framework and assembly name in its
+/// `.csproj` file, and from whether the solution uses the artifacts output
+/// layout. If the project has no target framework, the path is prompted for
+/// with the `programPath` input instead. A configuration with the same name is
+/// replaced.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `project_path` - The path to the C# project.
+/// - `project_friendly_name` - The friendly name of the project.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Add a launch configuration for the C# project named `ConsoleApp` in the
+/// `ConsoleApp/` directory to the `.vscode/launch.json` file.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let project_path = std::path::PathBuf::from("ConsoleApp");
+/// let project_friendly_name = "ConsoleApp";
+/// let mut logger = ConsoleLogger::new();
+///
+/// add_csharp_project_to_launch(
+///     &output_directory,
+///     &project_path,
+///     project_friendly_name,
+///     logger
+/// );
+/// ```
+pub fn add_csharp_project_to_launch(
+    output_directory: &Path,
+    project_path: &Path,
+    project_friendly_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log("Adding C# project to launch.json...", OutputEmoji::Document)?;
 
-        if is_watchable && input_node_item["id"] == "watchProject" {
-            let input_node_options = input_node_item["options"].as_array_mut().unwrap();
+    let mut vscode_launch =
+        VSCodeLaunchFile::new_or_default(output_directory.join(".vscode/launch.json"))?;
 
-            input_node_options.push(csharp_project_input.clone());
+    let program_path = match project_program_path(output_directory, project_path)? {
+        Some(program_path) => program_path,
+        None => {
+            add_program_path_input(&mut vscode_launch.values);
+
+            format!("${{input:{}}}", PROGRAM_PATH_INPUT_ID)
         }
+    };
+
+    let mut configuration = LaunchConfiguration::new(
+        project_launch_configuration_name(project_friendly_name).as_str(),
+        "coreclr",
+        "launch"
+    );
+
+    configuration.pre_launch_task = Some(project_build_task_label(project_friendly_name));
+    configuration.program = Some(format!("${{workspaceFolder}}/{}", program_path));
+    configuration.args = Some(Vec::new());
+    configuration.cwd = Some(format!(
+        "${{workspaceFolder}}/{}",
+        project_path.to_string_lossy()
+    ));
+    configuration
+        .other
+        .insert("console".to_string(), json!("internalConsole"));
+    configuration
+        .other
+        .insert("stopAtEntry".to_string(), json!(false));
+
+    vscode_launch.values.add_configuration(configuration);
+
+    vscode_launch.save(output_directory, logger)?;
+
+    Ok(())
+}
+
+/// Reads the friendly name that a C# project was added to the
+/// `.vscode/tasks.json` file with, which is the label of its option in the
+/// project inputs.
+///
+/// Returns `None` if the file doesn't exist or the project isn't in it.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `project_path` - The path to the C# project.
+pub fn read_csharp_project_friendly_name(
+    output_directory: &Path,
+    project_path: &Path
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let tasks_file_path = output_directory.join(".vscode/tasks.json");
+
+    if !tasks_file_path.exists() {
+        return Ok(None);
+    }
+
+    let vscode_tasks = VSCodeTasksFile::new(tasks_file_path)?;
+
+    let project_friendly_name = PROJECT_INPUT_IDS
+        .iter()
+        .filter_map(|input_id| vscode_tasks.values.input(input_id))
+        .flat_map(|input| input.options.iter().flatten())
+        .find(|option| is_same_project_path(option.value(), project_path))
+        .map(|option| option.label().to_string());
+
+    Ok(project_friendly_name)
+}
+
+/// Removes a C# project from the `.vscode/tasks.json` file.
+///
+/// The project's option is removed from the `projectItem`, `watchProject`,
+/// `runProject` and `nativeAotCompileProject` inputs, and the task that
+/// [`add_csharp_project_to_tasks`] adds to build a runnable project is
+/// removed. What was and wasn't found is written after the operation's log,
+/// and the file is left as it is if nothing was found.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `project_path` - The path to the C# project.
+/// - `project_friendly_name` - The friendly name of the project.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Remove the C# project named `ConsoleApp` in the `ConsoleApp/` directory
+/// from the `.vscode/tasks.json` file.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let project_path = std::path::PathBuf::from("ConsoleApp");
+/// let project_friendly_name = "ConsoleApp";
+/// let mut logger = ConsoleLogger::new();
+///
+/// remove_csharp_project_from_tasks(
+///     &output_directory,
+///     &project_path,
+///     project_friendly_name,
+///     logger
+/// );
+/// ```
+pub fn remove_csharp_project_from_tasks(
+    output_directory: &Path,
+    project_path: &Path,
+    project_friendly_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log("Removing C# project from tasks.json...", OutputEmoji::Document)?;
+
+    let tasks_file_path = output_directory.join(".vscode/tasks.json");
+
+    if !tasks_file_path.exists() {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
+
+    let mut vscode_tasks = VSCodeTasksFile::new(tasks_file_path)?;
+
+    let mut removed_items = Vec::new();
+    let mut missing_items = Vec::new();
 
-        if is_runnable && input_node_item["id"] == "runProject" {
-            let input_node_options = input_node_item["options"].as_array_mut().unwrap();
+    for input_id in PROJECT_INPUT_IDS {
+        let Ok(input) = vscode_tasks.values.input_mut(input_id) else {
+            missing_items.push(format!("Not in the '{}' input", input_id));
+            continue;
+        };
+
+        let project_values = input
+            .options
+            .iter()
+            .flatten()
+            .map(|option| option.value().to_string())
+            .filter(|value| is_same_project_path(value, project_path))
+            .collect::<Vec<String>>();
+
+        for project_value in &project_values {
+            input.remove_option(project_value);
+        }
 
-            input_node_options.push(csharp_project_input.clone());
+        match project_values.is_empty() {
+            true => missing_items.push(format!("Not in the '{}' input", input_id)),
+            false => removed_items.push(format!("Removed from the '{}' input", input_id))
         }
     }
 
-    vscode_tasks.write_tasks()?;
+    let build_task_label = project_build_task_label(project_friendly_name);
+
+    match vscode_tasks.values.remove_task(&build_task_label) {
+        true => removed_items.push(format!("Removed the \"{}\" task", build_task_label)),
+        false => missing_items.push(format!("No \"{}\" task", build_task_label))
+    }
+
+    if removed_items.is_empty() {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+    } else {
+        vscode_tasks.save(output_directory, logger)?;
+    }
+
+    for item in removed_items.iter().chain(missing_items.iter()) {
+        logger.write_info(format!("    {}\n", item))?;
+    }
+
+    Ok(())
+}
+
+/// Removes the launch configuration of a C# project from the
+/// `.vscode/launch.json` file.
+///
+/// The configuration removed is the one that
+/// [`add_csharp_project_to_launch`] adds. The file is left as it is if it
+/// doesn't have the configuration.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `project_friendly_name` - The friendly name of the project.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Remove the launch configuration for the C# project named `ConsoleApp` from
+/// the `.vscode/launch.json` file.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let project_friendly_name = "ConsoleApp";
+/// let mut logger = ConsoleLogger::new();
+///
+/// remove_csharp_project_from_launch(&output_directory, project_friendly_name, logger);
+/// ```
+pub fn remove_csharp_project_from_launch(
+    output_directory: &Path,
+    project_friendly_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        "Removing C# project from launch.json...",
+        OutputEmoji::Document
+    )?;
+
+    let launch_file_path = output_directory.join(".vscode/launch.json");
+
+    if !launch_file_path.exists() {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
+
+    let mut vscode_launch = VSCodeLaunchFile::new_or_default(launch_file_path)?;
+
+    if !vscode_launch
+        .values
+        .remove_configuration(&project_launch_configuration_name(project_friendly_name))
+    {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
 
-    logger.write_operation_success_log()?;
+    vscode_launch.save(output_directory, logger)?;
 
     Ok(())
 }
+
+/// A project in a C# solution, with the inputs in `.vscode/tasks.json` it
+/// belongs in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsharpSolutionProject {
+    /// The path to the project's directory, relative to the solution's
+    /// directory and separated with `/`. It's the path to the `.csproj` file
+    /// if the project is in the solution's directory.
+    pub path: String,
+
+    /// The name of the project's `.csproj` file, without the extension.
+    pub name: String,
+
+    /// Whether the project is runnable (`OutputType` is `Exe` or `WinExe`,
+    /// or it uses the web SDK).
+    pub is_runnable: bool,
+
+    /// Whether the project is watchable (it uses the `Microsoft.NET.Sdk.Web`
+    /// SDK).
+    pub is_watchable: bool,
+
+    /// Whether the project can be compiled with Native AOT (`PublishAot` is
+    /// `true`).
+    pub is_native_aot: bool
+}
+
+impl CsharpSolutionProject {
+    /// Checks whether the project belongs in an input of the
+    /// `.vscode/tasks.json` file.
+    fn belongs_in_input(
+        &self,
+        input_id: &str
+    ) -> bool {
+        match input_id {
+            "watchProject" => self.is_watchable,
+            "runProject" => self.is_runnable,
+            "nativeAotCompileProject" => self.is_native_aot,
+            _ => true
+        }
+    }
+}
+
+/// Reads the C# projects in a `.sln` or `.slnx` solution file, and inspects
+/// each project's `.csproj` file.
+///
+/// Projects that aren't C# projects, and solution folders, are skipped. A
+/// project whose `.csproj` file can't be read is listed as a project that
+/// isn't runnable or watchable.
+///
+/// # Arguments
+///
+/// - `solution_file_path` - The path to the solution file.
+pub fn read_csharp_solution_projects(
+    solution_file_path: &Path
+) -> Result<Vec<CsharpSolutionProject>, Box<dyn std::error::Error>> {
+    let solution_directory = solution_file_path.parent().unwrap_or(Path::new(""));
+    let solution = SolutionFile::read(solution_file_path)?;
+
+    let projects = solution
+        .projects()
+        .into_iter()
+        .map(|project| project.path)
+        .filter(|project_file_path| project_file_path.ends_with(".csproj"))
+        .map(|project_file_path| {
+            let project_file = MsBuildFile::read(&solution_directory.join(&project_file_path)).ok();
+            let read_property = |name: &str| {
+                project_file
+                    .as_ref()
+                    .and_then(|project_file| project_file.property(name))
+            };
+
+            let (path, file_name) = project_file_path
+                .rsplit_once('/')
+                .unwrap_or((project_file_path.as_str(), project_file_path.as_str()));
+
+            let is_web_project = project_file
+                .as_ref()
+                .and_then(MsBuildFile::sdk)
+                .is_some_and(|sdk| sdk == "Microsoft.NET.Sdk.Web");
+
+            let is_exe = read_property("OutputType").is_some_and(|output_type| {
+                output_type.eq_ignore_ascii_case("Exe")
+                    || output_type.eq_ignore_ascii_case("WinExe")
+            });
+
+            let is_native_aot = read_property("PublishAot")
+                .is_some_and(|publish_aot| publish_aot.eq_ignore_ascii_case("true"));
+
+            CsharpSolutionProject {
+                path: path.to_string(),
+                name: file_name.trim_end_matches(".csproj").to_string(),
+                is_runnable: is_exe || is_web_project,
+                is_watchable: is_web_project,
+                is_native_aot
+            }
+        })
+        .collect();
+
+    Ok(projects)
+}
+
+/// Syncs the project inputs in the `.vscode/tasks.json` file with the
+/// projects in a C# solution.
+///
+/// Every project is in the `projectItem` input, runnable projects are in the
+/// `runProject` input, watchable projects are in the `watchProject` input,
+/// and Native AOT projects are in the `nativeAotCompileProject` input.
+/// Projects that aren't in an input they belong in are added with their name
+/// as their label, and projects that don't belong in an input are removed.
+/// Options that are kept keep their labels, and options that aren't projects
+/// (like the solution in `projectItem`) are kept. Inputs that aren't in the
+/// file are skipped. The added and removed projects are written after the
+/// operation's log.
+///
+/// Returns `true` if the inputs were already in sync.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `projects` - The projects in the solution.
+/// - `check` - Whether to only check the inputs, without changing the file.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Sync the project inputs with the projects in `MySolution.sln`.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("MySolution");
+/// let projects = read_csharp_solution_projects(&output_directory.join("MySolution.sln"))?;
+/// let mut logger = ConsoleLogger::new();
+///
+/// sync_csharp_projects_in_tasks(&output_directory, &projects, false, logger);
+/// ```
+pub fn sync_csharp_projects_in_tasks(
+    output_directory: &Path,
+    projects: &[CsharpSolutionProject],
+    check: bool,
+    logger: &mut ConsoleLogger
+) -> Result<bool, Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        match check {
+            true => "Checking C# projects in tasks.json...",
+            false => "Syncing C# projects in tasks.json..."
+        },
+        OutputEmoji::Document
+    )?;
+
+    let mut vscode_tasks = VSCodeTasksFile::new(output_directory.join(".vscode/tasks.json"))?;
+
+    let (added_verb, removed_verb) = match check {
+        true => ("Would add", "Would remove"),
+        false => ("Added", "Removed")
+    };
+
+    let mut changes = Vec::new();
+
+    for input_id in PROJECT_INPUT_IDS {
+        let Ok(input) = vscode_tasks.values.input_mut(input_id) else {
+            continue;
+        };
+
+        let existing_values = input
+            .options
+            .iter()
+            .flatten()
+            .map(|option| option.value().to_string())
+            .collect::<Vec<String>>();
+
+        for existing_value in &existing_values {
+            if is_solution_file_path(existing_value) {
+                continue;
+            }
+
+            let belongs_in_input = projects.iter().any(|project| {
+                project.belongs_in_input(input_id)
+                    && is_same_project_path(existing_value, Path::new(&project.path))
+            });
+
+            if !belongs_in_input {
+                input.remove_option(existing_value);
+                changes.push(format!(
+                    "{} '{}' from '{}'",
+                    removed_verb, existing_value, input_id
+                ));
+            }
+        }
+
+        for project in projects
+            .iter()
+            .filter(|project| project.belongs_in_input(input_id))
+        {
+            let is_in_input = existing_values.iter().any(|existing_value| {
+                is_same_project_path(existing_value, Path::new(&project.path))
+            });
+
+            if !is_in_input {
+                input.add_option(TaskInputOption::labeled(&project.name, &project.path))?;
+                changes.push(format!("{} '{}' to '{}'", added_verb, project.path, input_id));
+            }
+        }
+    }
+
+    if changes.is_empty() {
+        logger.write_success(format!("In sync {}\n", OutputEmoji::CheckMark))?;
+        return Ok(true);
+    }
+
+    match check {
+        true => logger.write_warning("Out of sync 🟠\n".to_string())?,
+        false => vscode_tasks.save(output_directory, logger)?
+    }
+
+    for change in changes {
+        logger.write_info(format!("    {}\n", change))?;
+    }
+
+    Ok(false)
+}
+
+/// Gets the name of the launch configuration for a runnable C# project.
+fn project_launch_configuration_name(project_friendly_name: &str) -> String {
+    format!("Launch '{}'", project_friendly_name)
+}
+
+/// Gets the label of the task that builds a runnable C# project.
+fn project_build_task_label(project_friendly_name: &str) -> String {
+    format!("Build '{}'", project_friendly_name)
+}
+
+/// Creates the task that builds a runnable C# project in the `Debug`
+/// configuration, so its launch configuration doesn't ask which project to
+/// build.
+fn project_build_task(
+    project_path: &Path,
+    project_friendly_name: &str
+) -> Task {
+    Task {
+        label: Some(project_build_task_label(project_friendly_name)),
+        task_type: Some("process".to_string()),
+        command: Some("dotnet".to_string()),
+        args: Some(vec![
+            json!("build"),
+            json!(format!(
+                "${{workspaceFolder}}/{}",
+                project_path.to_string_lossy()
+            )),
+            json!("--configuration"),
+            json!("Debug"),
+        ]),
+        options: Some(TaskOptions {
+            cwd: Some("${workspaceFolder}".to_string()),
+            ..Default::default()
+        }),
+        problem_matcher: Some(ProblemMatcher::Named("$msCompile".to_string())),
+        ..Default::default()
+    }
+}
+
+/// Gets the path of a C# project's built `.dll` in the `Debug` configuration,
+/// relative to the output directory.
+///
+/// With the artifacts output layout (`UseArtifactsOutput` in
+/// `Directory.Build.props`), the `.dll` is in
+/// `artifacts/bin/<project>/debug/`, or `debug_<framework>/` if the project
+/// has more than one target framework. Otherwise, it's in
+/// `<project path>/bin/Debug/<framework>/`. The first target framework is
+/// used, from the `.csproj` file or else from the root `Directory.Build.props`.
+///
+/// Returns `None` if neither file sets a target framework.
+fn project_program_path(
+    output_directory: &Path,
+    project_path: &Path
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let project_file_path = find_project_file(&output_directory.join(project_path))?;
+    let project_file = MsBuildFile::read(&project_file_path)?;
+    let build_props = MsBuildFile::read(&output_directory.join("Directory.Build.props")).ok();
+
+    let project_name = project_file_path
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let assembly_name = project_file
+        .property("AssemblyName")
+        .unwrap_or_else(|| project_name.clone());
+
+    let target_frameworks = std::iter::once(&project_file)
+        .chain(build_props.as_ref())
+        .map(msbuild_target_frameworks)
+        .find(|target_frameworks| !target_frameworks.is_empty())
+        .unwrap_or_default();
+
+    let Some(target_framework) = target_frameworks.first() else {
+        return Ok(None);
+    };
+
+    let uses_artifacts_output = build_props
+        .and_then(|build_props| build_props.property("UseArtifactsOutput"))
+        .is_some_and(|use_artifacts_output| use_artifacts_output.eq_ignore_ascii_case("true"));
+
+    let program_path = match uses_artifacts_output {
+        true if target_frameworks.len() > 1 => format!(
+            "artifacts/bin/{}/debug_{}/{}.dll",
+            project_name, target_framework, assembly_name
+        ),
+        true => format!("artifacts/bin/{}/debug/{}.dll", project_name, assembly_name),
+        false => format!(
+            "{}/bin/Debug/{}/{}.dll",
+            project_path.to_string_lossy(),
+            target_framework,
+            assembly_name
+        )
+    };
+
+    Ok(Some(program_path))
+}
+
+/// Gets the target frameworks set in an MSBuild file, from `TargetFramework`
+/// or else from `TargetFrameworks`.
+fn msbuild_target_frameworks(msbuild_file: &MsBuildFile) -> Vec<String> {
+    match msbuild_file.property("TargetFramework") {
+        Some(target_framework) => vec![target_framework],
+        None => msbuild_file
+            .property("TargetFrameworks")
+            .map(|target_frameworks| {
+                target_frameworks
+                    .split(';')
+                    .map(|target_framework| target_framework.trim().to_string())
+                    .filter(|target_framework| !target_framework.is_empty())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Adds the `programPath` input, which prompts for the path to the built
+/// `.dll`, to a launch file if it doesn't have it.
+fn add_program_path_input(launch: &mut LaunchConfig) {
+    let inputs = launch
+        .other
+        .entry("inputs")
+        .or_insert_with(|| json!([]));
+
+    let Some(inputs) = inputs.as_array_mut() else {
+        return;
+    };
+
+    if inputs
+        .iter()
+        .any(|input| input.get("id").and_then(Value::as_str) == Some(PROGRAM_PATH_INPUT_ID))
+    {
+        return;
+    }
+
+    inputs.push(json!({
+        "id": PROGRAM_PATH_INPUT_ID,
+        "type": "promptString",
+        "description": "The path to the built program (.dll) to launch",
+        "default": "artifacts/bin/"
+    }));
+}
+
+/// Finds the `.csproj` file of a C# project, which can be the path to the
+/// file or to the directory it's in.
+fn find_project_file(project_path: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if project_path.is_file() {
+        return Ok(project_path.to_path_buf());
+    }
+
+    fs::read_dir(project_path)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "csproj")
+        })
+        .ok_or_else(|| {
+            CliError::new(
+                "No project file found in the project directory.",
+                CliErrorKind::InvalidProjectFile
+            )
+            .in_file(&project_path.to_string_lossy())
+            .into()
+        })
+}
+
+/// Checks whether a project path in `.vscode/tasks.json` is the path to a C#
+/// project, ignoring `./`, trailing slashes and the kind of separators. The
+/// path to a `.csproj` file matches the path to the directory it's in.
+fn is_same_project_path(
+    value: &str,
+    project_path: &Path
+) -> bool {
+    let value = normalize_project_path(value);
+    let project_path = normalize_project_path(&project_path.to_string_lossy());
+
+    let project_directory = |path: &str| -> Option<String> {
+        path.ends_with(".csproj")
+            .then(|| path.rsplit_once('/').map(|(directory, _)| directory).unwrap_or(""))
+            .map(|directory| directory.to_string())
+    };
+
+    value == project_path
+        || project_directory(&value).as_deref() == Some(project_path.as_str())
+        || project_directory(&project_path).as_deref() == Some(value.as_str())
+}
+
+/// Normalizes a project path, so that paths like `./ConsoleApp/` and
+/// `ConsoleApp` match.
+fn normalize_project_path(path: &str) -> String {
+    path.replace('\\', "/")
+        .split('/')
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+/// Checks whether an option in a project input is a solution file (like the
+/// `Entire solution` option in `projectItem`) instead of a project.
+fn is_solution_file_path(value: &str) -> bool {
+    value.ends_with(".sln") || value.ends_with(".slnx")
+}
@@ -0,0 +1,208 @@
+use std::{fs, path::Path};
+
+use crate::{
+    lang_options::CsharpLspOption,
+    logging::ConsoleLogger,
+    template_ops::{engine::TemplateContext, pack::TemplatePack},
+    vscode_ops::jsonc::JsoncDocument
+};
+
+/// The name of the template pack with the dev container for a Rust workspace.
+const RUST_DEVCONTAINER_PACK: &str = "rust-devcontainer";
+
+/// The name of the template pack with the dev container for a .NET solution.
+const CSHARP_DEVCONTAINER_PACK: &str = "csharp-devcontainer";
+
+/// Adds a dev container for a Rust workspace to the `.devcontainer/`
+/// directory, by running the `rust-devcontainer` template pack.
+///
+/// The container installs the toolchain pinned in `rust-toolchain.toml` (or
+/// `rust-toolchain`), or the latest one if there isn't one. A `rustToolchain`
+/// variable in the template context takes precedence.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `template_context` - The variables available to the templates.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator_lib::{
+///     logging::ConsoleLogger,
+///     template_ops::variables::builtin_variables
+/// };
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let template_context = builtin_variables(&output_directory);
+/// let mut logger = ConsoleLogger::new(None, None);
+///
+/// add_rust_devcontainer(&output_directory, &template_context, &mut logger)?;
+/// ```
+pub fn add_rust_devcontainer(
+    output_directory: &Path,
+    template_context: &TemplateContext,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut template_context = template_context.clone();
+
+    if !template_context.contains("rustToolchain") {
+        if let Some(rust_toolchain) = read_rust_toolchain(output_directory) {
+            template_context.set("rustToolchain", rust_toolchain);
+        }
+    }
+
+    let template_pack = TemplatePack::load(RUST_DEVCONTAINER_PACK, output_directory)?;
+
+    template_pack.run(output_directory, &template_context, logger)
+}
+
+/// Adds a dev container for a .NET solution to the `.devcontainer/`
+/// directory, by running the `csharp-devcontainer` template pack.
+///
+/// The container uses the .NET image for the SDK version pinned in
+/// `global.json` and installs that exact SDK, or uses the latest image if
+/// there's no `global.json`. The C# extensions recommended for the container
+/// match the C# language server. The .NET local tools are restored when the
+/// container is created if the project has a tool manifest, or if GitVersion
+/// is being added to it.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `template_context` - The variables available to the templates.
+/// - `csharp_lsp` - The C# language server to use.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator_lib::{
+///     logging::ConsoleLogger,
+///     template_ops::variables::builtin_variables
+/// };
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let template_context = builtin_variables(&output_directory);
+/// let mut logger = ConsoleLogger::new(None, None);
+///
+/// add_csharp_devcontainer(
+///     &output_directory,
+///     &template_context,
+///     CsharpLspOption::CsharpLsp,
+///     &mut logger
+/// )?;
+/// ```
+pub fn add_csharp_devcontainer(
+    output_directory: &Path,
+    template_context: &TemplateContext,
+    csharp_lsp: CsharpLspOption,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut template_context = template_context.clone();
+
+    if let Some(sdk_version) = read_dotnet_sdk_version(output_directory) {
+        let sdk_channel = sdk_version
+            .split('.')
+            .take(2)
+            .collect::<Vec<&str>>()
+            .join(".");
+
+        if !template_context.contains("dotnetImageTag") {
+            template_context.set("dotnetImageTag", format!("1-{}-bookworm", sdk_channel));
+        }
+
+        if !template_context.contains("dotnetSdkVersion") {
+            template_context.set("dotnetSdkVersion", sdk_version);
+        }
+    }
+
+    template_context.set("useOmnisharp", csharp_lsp == CsharpLspOption::OmniSharp);
+
+    if !template_context.contains("restoreDotnetTools") {
+        template_context.set(
+            "restoreDotnetTools",
+            output_directory.join(".config/dotnet-tools.json").exists()
+                || template_context.is_truthy("addGitversion")
+        );
+    }
+
+    let template_pack = TemplatePack::load(CSHARP_DEVCONTAINER_PACK, output_directory)?;
+
+    template_pack.run(output_directory, &template_context, logger)
+}
+
+/// Reads the Rust toolchain pinned for a project, from the `channel` in its
+/// `rust-toolchain.toml` file or its legacy `rust-toolchain` file.
+///
+/// The `stable` channel is read as `latest`, which is what the dev container
+/// feature calls it.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust
+/// use vscodeconfigurator_lib::vscode_ops::devcontainer::read_rust_toolchain;
+///
+/// let output_directory = std::env::temp_dir().join("read-rust-toolchain-example");
+/// std::fs::create_dir_all(&output_directory).unwrap();
+/// std::fs::write(
+///     output_directory.join("rust-toolchain.toml"),
+///     "[toolchain]\nchannel = \"1.84.0\"\n"
+/// )
+/// .unwrap();
+///
+/// assert_eq!(read_rust_toolchain(&output_directory), Some("1.84.0".to_string()));
+/// ```
+pub fn read_rust_toolchain(output_directory: &Path) -> Option<String> {
+    let toolchain_file = fs::read_to_string(output_directory.join("rust-toolchain.toml"))
+        .or_else(|_| fs::read_to_string(output_directory.join("rust-toolchain")))
+        .ok()?;
+
+    // The legacy file can also be just the name of the channel.
+    let channel = match toml::from_str::<toml::Table>(&toolchain_file) {
+        Ok(toolchain_file) => toolchain_file
+            .get("toolchain")
+            .and_then(|toolchain| toolchain.get("channel"))
+            .and_then(|channel| channel.as_str())
+            .map(|channel| channel.to_string())?,
+        Err(_) => toolchain_file.trim().to_string()
+    };
+
+    match channel.as_str() {
+        "" => None,
+        "stable" => Some("latest".to_string()),
+        _ => Some(channel)
+    }
+}
+
+/// Reads the .NET SDK version pinned in a project's `global.json` file, which
+/// can have comments.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+pub fn read_dotnet_sdk_version(output_directory: &Path) -> Option<String> {
+    let global_json = fs::read_to_string(output_directory.join("global.json")).ok()?;
+    let global_json = JsoncDocument::parse(&global_json).ok()?.value();
+
+    global_json
+        .get("sdk")
+        .and_then(|sdk| sdk.get("version"))
+        .and_then(|version| version.as_str())
+        .map(|version| version.to_string())
+}
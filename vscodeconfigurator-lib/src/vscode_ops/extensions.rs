@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The contents of a `.vscode/extensions.json` file.
+///
+/// Fields that aren't modeled are kept in `other`, so they're written back
+/// as they were.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionsConfig {
+    /// The IDs of the extensions VSCode recommends for the workspace (e.g.
+    /// `rust-lang.rust-analyzer`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommendations: Option<Vec<String>>,
+
+    /// The IDs of the extensions VSCode recommends against for the workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unwanted_recommendations: Option<Vec<String>>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+impl ExtensionsConfig {
+    /// Recommends an extension, removing it from the unwanted extensions if
+    /// it's there.
+    ///
+    /// # Arguments
+    ///
+    /// - `extension_id` - The ID of the extension.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::vscode_ops::extensions::ExtensionsConfig;
+    ///
+    /// let mut extensions = ExtensionsConfig::default();
+    ///
+    /// extensions.mark_unwanted("ms-dotnettools.csdevkit");
+    /// extensions.recommend("ms-dotnettools.csdevkit");
+    ///
+    /// assert_eq!(
+    ///     extensions.recommendations.unwrap(),
+    ///     vec!["ms-dotnettools.csdevkit"]
+    /// );
+    /// assert!(extensions.unwanted_recommendations.unwrap().is_empty());
+    /// ```
+    pub fn recommend(
+        &mut self,
+        extension_id: &str
+    ) {
+        remove_extension(&mut self.unwanted_recommendations, extension_id);
+        add_extension(&mut self.recommendations, extension_id);
+    }
+
+    /// Recommends against an extension, removing it from the recommended
+    /// extensions if it's there.
+    ///
+    /// # Arguments
+    ///
+    /// - `extension_id` - The ID of the extension.
+    pub fn mark_unwanted(
+        &mut self,
+        extension_id: &str
+    ) {
+        remove_extension(&mut self.recommendations, extension_id);
+        add_extension(&mut self.unwanted_recommendations, extension_id);
+    }
+}
+
+/// Adds an extension to a list of extensions if it isn't in it. Extension IDs
+/// are compared without case, like VSCode does.
+fn add_extension(
+    extension_ids: &mut Option<Vec<String>>,
+    extension_id: &str
+) {
+    let extension_ids = extension_ids.get_or_insert_with(Vec::new);
+
+    if !extension_ids
+        .iter()
+        .any(|existing_id| existing_id.eq_ignore_ascii_case(extension_id))
+    {
+        extension_ids.push(extension_id.to_string());
+    }
+}
+
+/// Removes an extension from a list of extensions.
+fn remove_extension(
+    extension_ids: &mut Option<Vec<String>>,
+    extension_id: &str
+) {
+    if let Some(extension_ids) = extension_ids {
+        extension_ids.retain(|existing_id| !existing_id.eq_ignore_ascii_case(extension_id));
+    }
+}
@@ -0,0 +1,885 @@
+use serde_json::{Map, Value};
+
+use crate::error::{CliError, CliErrorKind};
+
+/// A JSON document that allows comments and trailing commas, like VSCode's
+/// `settings.json` and `tasks.json`.
+///
+/// The original text is kept, so updating the document only rewrites the
+/// values that changed. Comments, indentation and everything else are left
+/// as they were.
+#[derive(Debug, Clone)]
+pub struct JsoncDocument {
+    /// The text of the document.
+    text: String,
+
+    /// The root value of the document.
+    root: JsoncNode
+}
+
+impl JsoncDocument {
+    /// Parses a JSONC document.
+    ///
+    /// # Arguments
+    ///
+    /// - `text` - The text of the document.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use vscodeconfigurator_lib::vscode_ops::jsonc::JsoncDocument;
+    ///
+    /// let document = JsoncDocument::parse(
+    ///     "{\n\t// Use tabs.\n\t\"editor.insertSpaces\": false,\n}\n"
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(document.value(), json!({ "editor.insertSpaces": false }));
+    /// ```
+    ///
+    /// ## Example 02
+    ///
+    /// A leading byte order mark is skipped, but kept in the text.
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use vscodeconfigurator_lib::vscode_ops::jsonc::JsoncDocument;
+    ///
+    /// let mut document = JsoncDocument::parse("\u{FEFF}{ \"editor.tabSize\": 2 }").unwrap();
+    ///
+    /// document.update(&json!({ "editor.tabSize": 4 })).unwrap();
+    ///
+    /// assert_eq!(document.text(), "\u{FEFF}{ \"editor.tabSize\": 4 }");
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, CliError> {
+        // The byte order mark isn't part of the JSON, but stays in the text so
+        // it's written back with the document.
+        let position = match text.starts_with('\u{FEFF}') {
+            true => '\u{FEFF}'.len_utf8(),
+            false => 0
+        };
+
+        let mut parser = JsoncParser { text, position };
+
+        parser.skip_trivia()?;
+        let root = parser.parse_value()?;
+        parser.skip_trivia()?;
+
+        if parser.position < text.len() {
+            return Err(parser.error("Unexpected text after the end of the document."));
+        }
+
+        Ok(Self {
+            text: text.to_string(),
+            root
+        })
+    }
+
+    /// Gets the text of the document.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the values in the document, without the comments.
+    pub fn value(&self) -> Value {
+        self.root.value()
+    }
+
+    /// Updates the document to hold new values.
+    ///
+    /// Only the parts of the text for values that changed are rewritten. New
+    /// object members and array items are indented like the ones next to
+    /// them.
+    ///
+    /// # Arguments
+    ///
+    /// - `value` - The new values of the document.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use vscodeconfigurator_lib::vscode_ops::jsonc::JsoncDocument;
+    ///
+    /// let mut document = JsoncDocument::parse(
+    ///     "{\n\t// The tab size.\n\t\"editor.tabSize\": 2,\n}\n"
+    /// )
+    /// .unwrap();
+    ///
+    /// document
+    ///     .update(&json!({ "editor.tabSize": 4, "files.trimTrailingWhitespace": true }))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     document.text(),
+    ///     "{\n\t// The tab size.\n\t\"editor.tabSize\": 4,\n\t\"files.trimTrailingWhitespace\": true,\n}\n"
+    /// );
+    /// ```
+    pub fn update(
+        &mut self,
+        value: &Value
+    ) -> Result<(), CliError> {
+        let mut edits = Vec::new();
+
+        self.diff_node(&self.root, value, &mut edits);
+
+        if edits.is_empty() {
+            return Ok(());
+        }
+
+        // Edits never overlap, so applying them from the end of the text keeps
+        // the positions of the rest valid. A replacement is applied before an
+        // insertion at the same position, so the insertion ends up in front of
+        // it.
+        edits.sort_by(|a, b| b.start.cmp(&a.start).then(b.end.cmp(&a.end)));
+
+        let mut text = self.text.clone();
+
+        for edit in edits {
+            text.replace_range(edit.start..edit.end, &edit.replacement);
+        }
+
+        *self = Self::parse(&text)?;
+
+        Ok(())
+    }
+
+    /// Adds the edits that turn a node into a new value.
+    fn diff_node(
+        &self,
+        node: &JsoncNode,
+        value: &Value,
+        edits: &mut Vec<TextEdit>
+    ) {
+        match (&node.kind, value) {
+            (JsoncNodeKind::Object(members), Value::Object(new_members)) => {
+                self.diff_object(node, members, new_members, edits)
+            }
+
+            (JsoncNodeKind::Array(items), Value::Array(new_items)) => {
+                self.diff_array(node, items, new_items, edits)
+            }
+
+            _ => {
+                if node.value() != *value {
+                    edits.push(TextEdit {
+                        start: node.start,
+                        end: node.end,
+                        replacement: self.format_value(
+                            value,
+                            &self.line_indent(node.start),
+                            &self.indent_unit()
+                        )
+                    });
+                }
+            }
+        }
+    }
+
+    /// Adds the edits that turn an object into a new one.
+    fn diff_object(
+        &self,
+        node: &JsoncNode,
+        members: &[JsoncMember],
+        new_members: &Map<String, Value>,
+        edits: &mut Vec<TextEdit>
+    ) {
+        let mut kept = Vec::with_capacity(members.len());
+
+        for member in members {
+            match new_members.get(&member.key) {
+                Some(new_value) => {
+                    self.diff_node(&member.value, new_value, edits);
+                    kept.push(true);
+                }
+                None => kept.push(false)
+            }
+        }
+
+        let spans = members
+            .iter()
+            .map(|member| (member.start, member.value.end))
+            .collect::<Vec<(usize, usize)>>();
+
+        let added = new_members
+            .iter()
+            .filter(|(key, _)| !members.iter().any(|member| &member.key == *key))
+            .map(|(key, value)| (format!("{}: ", Value::String(key.clone())), value))
+            .collect::<Vec<(String, &Value)>>();
+
+        self.diff_items(node, &spans, &kept, &[], &added, edits);
+    }
+
+    /// Adds the edits that turn an array into a new one.
+    ///
+    /// The items are aligned by their longest common subsequence, so items
+    /// that are only moved over by an insertion or a removal aren't
+    /// rewritten. A changed item is edited in place.
+    fn diff_array(
+        &self,
+        node: &JsoncNode,
+        items: &[JsoncNode],
+        new_items: &[Value],
+        edits: &mut Vec<TextEdit>
+    ) {
+        let old_values = items
+            .iter()
+            .map(|item| item.value())
+            .collect::<Vec<Value>>();
+
+        let mut kept = vec![false; items.len()];
+        let mut inserted_before = Vec::new();
+        let mut appended = Vec::new();
+
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+
+        for operation in align_items(&old_values, new_items) {
+            match operation {
+                AlignOperation::Keep(old_index) => {
+                    for new_value in
+                        self.pair_changed_items(items, &removed, &added, &mut kept, edits)
+                    {
+                        inserted_before.push((old_index, new_value));
+                    }
+
+                    removed.clear();
+                    added.clear();
+                    kept[old_index] = true;
+                }
+                AlignOperation::Remove(old_index) => removed.push(old_index),
+                AlignOperation::Add(new_index) => added.push(&new_items[new_index])
+            }
+        }
+
+        for new_value in self.pair_changed_items(items, &removed, &added, &mut kept, edits) {
+            appended.push((String::new(), new_value));
+        }
+
+        let spans = items
+            .iter()
+            .map(|item| (item.start, item.end))
+            .collect::<Vec<(usize, usize)>>();
+
+        self.diff_items(node, &spans, &kept, &inserted_before, &appended, edits);
+    }
+
+    /// Pairs up the items removed and added between two kept items, editing
+    /// each removed item in place to become an added one.
+    ///
+    /// Returns the added items that are left over. The removed items that are
+    /// left over stay removed.
+    fn pair_changed_items<'a>(
+        &self,
+        items: &[JsoncNode],
+        removed: &[usize],
+        added: &[&'a Value],
+        kept: &mut [bool],
+        edits: &mut Vec<TextEdit>
+    ) -> Vec<&'a Value> {
+        for (old_index, new_value) in removed.iter().zip(added.iter()) {
+            self.diff_node(&items[*old_index], new_value, edits);
+            kept[*old_index] = true;
+        }
+
+        added.iter().skip(removed.len()).copied().collect()
+    }
+
+    /// Adds the edits that remove and insert the items of an object or array.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The object or array.
+    /// - `spans` - Where each item starts and ends, including an object
+    ///   member's key.
+    /// - `kept` - Whether each item is kept.
+    /// - `inserted_before` - New items to insert before a kept item.
+    /// - `appended` - New items to add after the last kept item, with the text
+    ///   that goes before their value (an object member's key).
+    fn diff_items(
+        &self,
+        node: &JsoncNode,
+        spans: &[(usize, usize)],
+        kept: &[bool],
+        inserted_before: &[(usize, &Value)],
+        appended: &[(String, &Value)],
+        edits: &mut Vec<TextEdit>
+    ) {
+        let last_kept = kept.iter().rposition(|is_kept| *is_kept);
+        let indent_unit = self.container_indent_unit(node, spans);
+
+        let Some(last_kept) = last_kept else {
+            // Nothing is kept, so the contents of the object or array are
+            // written again.
+            if spans.is_empty() && appended.is_empty() {
+                return;
+            }
+
+            let container_indent = self.line_indent(node.start);
+            let item_indent = format!("{}{}", container_indent, indent_unit);
+            let line_ending = self.line_ending();
+
+            let replacement = match appended.is_empty() {
+                true => String::new(),
+                false => format!(
+                    "{}{}{}{}",
+                    line_ending,
+                    appended
+                        .iter()
+                        .map(|(prefix, value)| format!(
+                            "{}{}{}",
+                            item_indent,
+                            prefix,
+                            self.format_value(value, &item_indent, &indent_unit)
+                        ))
+                        .collect::<Vec<String>>()
+                        .join(&format!(",{}", line_ending)),
+                    line_ending,
+                    container_indent
+                )
+            };
+
+            edits.push(TextEdit {
+                start: node.start + 1,
+                end: node.end - 1,
+                replacement
+            });
+
+            return;
+        };
+
+        // Removed items before the last kept item are removed up to the start
+        // of the next item, which takes their place.
+        for index in 0..last_kept {
+            if !kept[index] {
+                edits.push(TextEdit {
+                    start: spans[index].0,
+                    end: spans[index + 1].0,
+                    replacement: String::new()
+                });
+            }
+        }
+
+        let item_separator = self.item_separator(spans, last_kept);
+
+        for (before_index, value) in inserted_before {
+            let item_indent = self.line_indent(spans[*before_index].0);
+
+            edits.push(TextEdit {
+                start: spans[*before_index].0,
+                end: spans[*before_index].0,
+                replacement: format!(
+                    "{}{}",
+                    self.format_value(value, &item_indent, &indent_unit),
+                    item_separator
+                )
+            });
+        }
+
+        // Removed items after the last kept item are removed from the end of
+        // the last kept item, along with the separators in front of them. New
+        // items are added in the same place.
+        let tail_start = spans[last_kept].1;
+        let tail_end = spans.last().map(|span| span.1).unwrap_or(tail_start);
+
+        if tail_start == tail_end && appended.is_empty() {
+            return;
+        }
+
+        let item_indent = self.line_indent(spans[last_kept].0);
+
+        let replacement = appended
+            .iter()
+            .map(|(prefix, value)| {
+                format!(
+                    "{}{}{}",
+                    item_separator,
+                    prefix,
+                    self.format_value(value, &item_indent, &indent_unit)
+                )
+            })
+            .collect::<String>();
+
+        edits.push(TextEdit {
+            start: tail_start,
+            end: tail_end,
+            replacement
+        });
+    }
+
+    /// Gets the text between two items of an object or array: a comma and a
+    /// new line with the indentation of the item if the items are on their
+    /// own lines, or a comma and a space otherwise.
+    fn item_separator(
+        &self,
+        spans: &[(usize, usize)],
+        item_index: usize
+    ) -> String {
+        let item_start = spans[item_index].0;
+
+        match self.starts_line(item_start) {
+            true => format!(",{}{}", self.line_ending(), self.line_indent(item_start)),
+            false => ", ".to_string()
+        }
+    }
+
+    /// Formats a value to insert in the document, with the lines after the
+    /// first one indented by `indent`, and `indent_unit` for each level of
+    /// indentation inside the value.
+    fn format_value(
+        &self,
+        value: &Value,
+        indent: &str,
+        indent_unit: &str
+    ) -> String {
+        let is_empty_or_scalar = match value {
+            Value::Object(members) => members.is_empty(),
+            Value::Array(items) => items.is_empty(),
+            _ => true
+        };
+
+        if is_empty_or_scalar {
+            return value.to_string();
+        }
+
+        let mut buffer = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent_unit.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+
+        serde::Serialize::serialize(value, &mut serializer)
+            .expect("Serializing a JSON value can't fail.");
+
+        String::from_utf8(buffer)
+            .expect("Serialized JSON is valid UTF-8.")
+            .split('\n')
+            .collect::<Vec<&str>>()
+            .join(&format!("{}{}", self.line_ending(), indent))
+    }
+
+    /// Gets the whitespace at the start of the line a position is on.
+    fn line_indent(
+        &self,
+        position: usize
+    ) -> String {
+        let line_start = self.text[..position]
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or(0);
+
+        self.text[line_start..]
+            .chars()
+            .take_while(|character| *character == ' ' || *character == '\t')
+            .collect()
+    }
+
+    /// Gets the text used for one level of indentation inside an object or
+    /// array: the difference between the indentation of its first item and
+    /// its own. If that doesn't work out, a tab if the first item is indented
+    /// with tabs, or the document's indentation otherwise.
+    fn container_indent_unit(
+        &self,
+        node: &JsoncNode,
+        spans: &[(usize, usize)]
+    ) -> String {
+        let container_indent = self.line_indent(node.start);
+
+        let item_indent = spans
+            .first()
+            .filter(|(item_start, _)| self.starts_line(*item_start))
+            .map(|(item_start, _)| self.line_indent(*item_start));
+
+        match item_indent {
+            Some(item_indent)
+                if item_indent.len() > container_indent.len()
+                    && item_indent.starts_with(&container_indent) =>
+            {
+                item_indent[container_indent.len()..].to_string()
+            }
+            Some(item_indent) if item_indent.starts_with('\t') => "\t".to_string(),
+            _ => self.indent_unit()
+        }
+    }
+
+    /// Gets the text used for one level of indentation in the document: the
+    /// most common step in indentation from one line to the next, or four
+    /// spaces if no line is indented.
+    fn indent_unit(&self) -> String {
+        let mut step_counts: Vec<(String, usize)> = Vec::new();
+        let mut previous_indent = String::new();
+
+        for line in self.text.lines().filter(|line| !line.trim().is_empty()) {
+            let indent = line
+                .chars()
+                .take_while(|character| *character == ' ' || *character == '\t')
+                .collect::<String>();
+
+            if indent.len() > previous_indent.len() && indent.starts_with(&previous_indent) {
+                let step = indent[previous_indent.len()..].to_string();
+
+                match step_counts
+                    .iter_mut()
+                    .find(|(counted_step, _)| *counted_step == step)
+                {
+                    Some((_, count)) => *count += 1,
+                    None => step_counts.push((step, 1))
+                }
+            }
+
+            previous_indent = indent;
+        }
+
+        step_counts
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+            .map(|(step, _)| step)
+            .unwrap_or_else(|| "    ".to_string())
+    }
+
+    /// Checks whether only whitespace comes before a position on its line.
+    fn starts_line(
+        &self,
+        position: usize
+    ) -> bool {
+        let line_start = self.text[..position]
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or(0);
+
+        line_start > 0 && self.text[line_start..position].trim().is_empty()
+    }
+
+    /// Gets the line ending used in the document.
+    fn line_ending(&self) -> &'static str {
+        match self.text.contains("\r\n") {
+            true => "\r\n",
+            false => "\n"
+        }
+    }
+}
+
+/// A value in a JSONC document and where it is in the text.
+#[derive(Debug, Clone)]
+struct JsoncNode {
+    /// The position of the first character of the value.
+    start: usize,
+
+    /// The position after the last character of the value.
+    end: usize,
+
+    /// The kind of value.
+    kind: JsoncNodeKind
+}
+
+impl JsoncNode {
+    /// Gets the value of the node.
+    fn value(&self) -> Value {
+        match &self.kind {
+            JsoncNodeKind::Object(members) => Value::Object(
+                members
+                    .iter()
+                    .map(|member| (member.key.clone(), member.value.value()))
+                    .collect()
+            ),
+            JsoncNodeKind::Array(items) => {
+                Value::Array(items.iter().map(|item| item.value()).collect())
+            }
+            JsoncNodeKind::Scalar(value) => value.clone()
+        }
+    }
+}
+
+/// The kind of a value in a JSONC document.
+#[derive(Debug, Clone)]
+enum JsoncNodeKind {
+    /// An object and its members.
+    Object(Vec<JsoncMember>),
+
+    /// An array and its items.
+    Array(Vec<JsoncNode>),
+
+    /// A string, number, boolean or null.
+    Scalar(Value)
+}
+
+/// A member of an object in a JSONC document.
+#[derive(Debug, Clone)]
+struct JsoncMember {
+    /// The position of the member's key.
+    start: usize,
+
+    /// The member's key.
+    key: String,
+
+    /// The member's value.
+    value: JsoncNode
+}
+
+/// A replacement of part of the text of a document.
+struct TextEdit {
+    /// The position of the first character to replace.
+    start: usize,
+
+    /// The position after the last character to replace.
+    end: usize,
+
+    /// The text to replace it with.
+    replacement: String
+}
+
+/// An operation that turns one array into another.
+enum AlignOperation {
+    /// Keep the item of the old array at the index.
+    Keep(usize),
+
+    /// Remove the item of the old array at the index.
+    Remove(usize),
+
+    /// Add the item of the new array at the index.
+    Add(usize)
+}
+
+/// Aligns the items of two arrays by their longest common subsequence.
+fn align_items(
+    old_items: &[Value],
+    new_items: &[Value]
+) -> Vec<AlignOperation> {
+    let mut lengths = vec![vec![0usize; new_items.len() + 1]; old_items.len() + 1];
+
+    for old in (0..old_items.len()).rev() {
+        for new in (0..new_items.len()).rev() {
+            lengths[old][new] = match old_items[old] == new_items[new] {
+                true => lengths[old + 1][new + 1] + 1,
+                false => lengths[old + 1][new].max(lengths[old][new + 1])
+            };
+        }
+    }
+
+    let mut operations = Vec::new();
+    let (mut old, mut new) = (0, 0);
+
+    while old < old_items.len() && new < new_items.len() {
+        if old_items[old] == new_items[new] {
+            operations.push(AlignOperation::Keep(old));
+            old += 1;
+            new += 1;
+        } else if lengths[old + 1][new] >= lengths[old][new + 1] {
+            operations.push(AlignOperation::Remove(old));
+            old += 1;
+        } else {
+            operations.push(AlignOperation::Add(new));
+            new += 1;
+        }
+    }
+
+    operations.extend((old..old_items.len()).map(AlignOperation::Remove));
+    operations.extend((new..new_items.len()).map(AlignOperation::Add));
+
+    operations
+}
+
+/// Parses the text of a JSONC document.
+struct JsoncParser<'a> {
+    /// The text being parsed.
+    text: &'a str,
+
+    /// The position of the next character to parse.
+    position: usize
+}
+
+impl JsoncParser<'_> {
+    /// Parses the value at the current position.
+    fn parse_value(&mut self) -> Result<JsoncNode, CliError> {
+        let start = self.position;
+
+        let kind = match self.peek() {
+            Some(b'{') => JsoncNodeKind::Object(self.parse_object()?),
+            Some(b'[') => JsoncNodeKind::Array(self.parse_array()?),
+            Some(b'"') => JsoncNodeKind::Scalar(Value::String(self.parse_string()?)),
+            Some(_) => JsoncNodeKind::Scalar(self.parse_literal()?),
+            None => return Err(self.error("Expected a value, but the document ended."))
+        };
+
+        Ok(JsoncNode {
+            start,
+            end: self.position,
+            kind
+        })
+    }
+
+    /// Parses the object at the current position.
+    fn parse_object(&mut self) -> Result<Vec<JsoncMember>, CliError> {
+        let mut members = Vec::new();
+
+        self.position += 1;
+
+        loop {
+            self.skip_trivia()?;
+
+            match self.peek() {
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(members);
+                }
+                Some(b'"') => {}
+                _ => return Err(self.error("Expected a property name or '}'."))
+            }
+
+            let start = self.position;
+            let key = self.parse_string()?;
+
+            self.skip_trivia()?;
+
+            if self.peek() != Some(b':') {
+                return Err(self.error(&format!("Expected ':' after the property '{}'.", key)));
+            }
+
+            self.position += 1;
+            self.skip_trivia()?;
+
+            let value = self.parse_value()?;
+
+            members.push(JsoncMember { start, key, value });
+
+            self.skip_trivia()?;
+
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {}
+                _ => return Err(self.error("Expected ',' or '}' after a property."))
+            }
+        }
+    }
+
+    /// Parses the array at the current position.
+    fn parse_array(&mut self) -> Result<Vec<JsoncNode>, CliError> {
+        let mut items = Vec::new();
+
+        self.position += 1;
+
+        loop {
+            self.skip_trivia()?;
+
+            if self.peek() == Some(b']') {
+                self.position += 1;
+                return Ok(items);
+            }
+
+            items.push(self.parse_value()?);
+
+            self.skip_trivia()?;
+
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {}
+                _ => return Err(self.error("Expected ',' or ']' after an item."))
+            }
+        }
+    }
+
+    /// Parses the string at the current position.
+    fn parse_string(&mut self) -> Result<String, CliError> {
+        let start = self.position;
+        let bytes = self.text.as_bytes();
+
+        self.position += 1;
+
+        while self.position < bytes.len() {
+            match bytes[self.position] {
+                b'\\' => self.position += 2,
+                b'"' => {
+                    self.position += 1;
+
+                    return serde_json::from_str::<String>(&self.text[start..self.position])
+                        .map_err(|e| self.error_at(start, &format!("Invalid string: {}", e)));
+                }
+                b'\n' => break,
+                _ => self.position += 1
+            }
+        }
+
+        Err(self.error_at(start, "Unterminated string."))
+    }
+
+    /// Parses the number, boolean or null at the current position.
+    fn parse_literal(&mut self) -> Result<Value, CliError> {
+        let start = self.position;
+
+        while let Some(byte) = self.peek() {
+            match byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'+' | b'.') {
+                true => self.position += 1,
+                false => break
+            }
+        }
+
+        let literal = &self.text[start..self.position];
+
+        match literal.is_empty() {
+            true => Err(self.error("Expected a value.")),
+            false => serde_json::from_str::<Value>(literal)
+                .map_err(|_| self.error_at(start, &format!("Invalid value '{}'.", literal)))
+        }
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_trivia(&mut self) -> Result<(), CliError> {
+        let bytes = self.text.as_bytes();
+
+        while self.position < bytes.len() {
+            match (bytes[self.position], bytes.get(self.position + 1)) {
+                (b' ' | b'\t' | b'\n' | b'\r', _) => self.position += 1,
+
+                (b'/', Some(b'/')) => {
+                    while self.position < bytes.len() && bytes[self.position] != b'\n' {
+                        self.position += 1;
+                    }
+                }
+
+                (b'/', Some(b'*')) => {
+                    let start = self.position;
+
+                    match self.text[self.position + 2..].find("*/") {
+                        Some(comment_length) => self.position += comment_length + 4,
+                        None => return Err(self.error_at(start, "Unterminated comment."))
+                    }
+                }
+
+                _ => break
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the character at the current position.
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    /// Creates an error at the current position.
+    fn error(
+        &self,
+        message: &str
+    ) -> CliError {
+        self.error_at(self.position, message)
+    }
+
+    /// Creates an error at a position.
+    fn error_at(
+        &self,
+        position: usize,
+        message: &str
+    ) -> CliError {
+        let line = self.text[..position.min(self.text.len())]
+            .matches('\n')
+            .count()
+            + 1;
+
+        CliError::new(message, CliErrorKind::InvalidJsonc).at_line(line)
+    }
+}
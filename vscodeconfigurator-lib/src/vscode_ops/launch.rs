@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::{CliError, CliErrorKind};
+
+/// The contents of a `.vscode/launch.json` file.
+///
+/// Fields that aren't modeled are kept in `other`, so they're written back
+/// as they were.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchConfig {
+    /// The version of the launch schema (e.g. `0.2.0`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// The launch configurations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configurations: Option<Vec<LaunchConfiguration>>,
+
+    /// The compound configurations, which launch several configurations at
+    /// once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compounds: Option<Vec<LaunchCompound>>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+impl Default for LaunchConfig {
+    /// Creates the contents of a new launch file, with no configurations.
+    fn default() -> Self {
+        Self {
+            version: Some("0.2.0".to_string()),
+            configurations: Some(Vec::new()),
+            compounds: None,
+            other: Map::new()
+        }
+    }
+}
+
+impl LaunchConfig {
+    /// Gets the launch configuration with a name.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the configuration.
+    pub fn configuration(
+        &self,
+        name: &str
+    ) -> Option<&LaunchConfiguration> {
+        self.configurations
+            .as_ref()?
+            .iter()
+            .find(|configuration| configuration.name == name)
+    }
+
+    /// Gets the launch configuration with a name to change it.
+    ///
+    /// Returns an error if there's no configuration with the name.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the configuration.
+    pub fn configuration_mut(
+        &mut self,
+        name: &str
+    ) -> Result<&mut LaunchConfiguration, CliError> {
+        self.configurations
+            .iter_mut()
+            .flatten()
+            .find(|configuration| configuration.name == name)
+            .ok_or_else(|| {
+                CliError::new(
+                    format!(
+                        "There's no launch configuration named '{}' in launch.json.",
+                        name
+                    )
+                    .as_str(),
+                    CliErrorKind::VSCodeItemNotFound
+                )
+            })
+    }
+
+    /// Adds a launch configuration, replacing the configuration with the same
+    /// name if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// - `configuration` - The configuration to add.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::vscode_ops::launch::{LaunchConfig, LaunchConfiguration};
+    ///
+    /// let mut launch = LaunchConfig::default();
+    ///
+    /// launch.add_configuration(LaunchConfiguration::new("Debug app", "lldb", "launch"));
+    /// launch.add_configuration(LaunchConfiguration::new("Debug app", "lldb", "launch"));
+    ///
+    /// assert_eq!(launch.configurations.unwrap().len(), 1);
+    /// ```
+    pub fn add_configuration(
+        &mut self,
+        configuration: LaunchConfiguration
+    ) {
+        let configurations = self.configurations.get_or_insert_with(Vec::new);
+
+        match configurations
+            .iter_mut()
+            .find(|existing_configuration| existing_configuration.name == configuration.name)
+        {
+            Some(existing_configuration) => *existing_configuration = configuration,
+            None => configurations.push(configuration)
+        }
+    }
+
+    /// Removes the launch configuration with a name.
+    ///
+    /// Returns `true` if there was one.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the configuration.
+    pub fn remove_configuration(
+        &mut self,
+        name: &str
+    ) -> bool {
+        let Some(configurations) = &mut self.configurations else {
+            return false;
+        };
+
+        let configuration_count = configurations.len();
+        configurations.retain(|configuration| configuration.name != name);
+
+        configurations.len() != configuration_count
+    }
+}
+
+/// A launch configuration in a `.vscode/launch.json` file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchConfiguration {
+    /// The name of the configuration, shown in the UI.
+    pub name: String,
+
+    /// The type of debugger (e.g. `lldb` or `coreclr`).
+    #[serde(rename = "type")]
+    pub configuration_type: String,
+
+    /// Whether the debugger launches the program or attaches to it (`launch`
+    /// or `attach`).
+    pub request: String,
+
+    /// The program to debug.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program: Option<String>,
+
+    /// The arguments for the program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<Value>>,
+
+    /// The directory to run the program in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// The environment variables for the program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<Map<String, Value>>,
+
+    /// The label of the task to run before launching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_launch_task: Option<String>,
+
+    /// The fields that aren't modeled, including the ones specific to the type
+    /// of debugger.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+impl LaunchConfiguration {
+    /// Creates a launch configuration.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the configuration.
+    /// - `configuration_type` - The type of debugger.
+    /// - `request` - `launch` or `attach`.
+    pub fn new(
+        name: &str,
+        configuration_type: &str,
+        request: &str
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            configuration_type: configuration_type.to_string(),
+            request: request.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A compound configuration in a `.vscode/launch.json` file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchCompound {
+    /// The name of the compound, shown in the UI.
+    pub name: String,
+
+    /// The configurations to launch. Each one is the name of a configuration
+    /// or an object with its name and workspace folder.
+    pub configurations: Vec<Value>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
@@ -1,84 +1,327 @@
 pub mod csharp;
+pub mod devcontainer;
+pub mod extensions;
+pub mod jsonc;
+pub mod launch;
 pub mod rust;
+pub mod tasks;
+pub mod workspace;
 
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf}
+};
 
-use serde_json::Value;
+use extensions::ExtensionsConfig;
+use jsonc::JsoncDocument;
+use launch::LaunchConfig;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use tasks::TasksConfig;
+use workspace::WorkspaceConfig;
 
-/// Represents the settings file for a Visual Studio Code workspace.
-pub struct VSCodeSettingsFile {
-    /// The path to the settings file.
+use crate::{
+    error::{CliError, CliErrorKind},
+    logging::ConsoleLogger
+};
+
+/// Represents a JSON file of a Visual Studio Code workspace, like
+/// `.vscode/tasks.json` or a `.code-workspace` file.
+///
+/// The file is kept as it was read, so its comments and formatting are kept
+/// when the values are written back.
+pub struct VSCodeJsonFile<T> {
+    /// The path to the file.
     pub file_path: PathBuf,
 
-    /// The values in the settings file.
-    pub values: Value
+    /// The values in the file.
+    pub values: T,
+
+    /// The file as it was read. It's `None` if the file doesn't exist yet.
+    pub document: Option<JsoncDocument>
 }
 
-impl VSCodeSettingsFile {
-    /// Creates a new `VSCodeSettings` instance.
+/// Represents the settings file for a Visual Studio Code workspace.
+pub type VSCodeSettingsFile = VSCodeJsonFile<Map<String, Value>>;
+
+/// Represents the tasks file for a Visual Studio Code workspace.
+pub type VSCodeTasksFile = VSCodeJsonFile<TasksConfig>;
+
+/// Represents the launch file for a Visual Studio Code workspace.
+pub type VSCodeLaunchFile = VSCodeJsonFile<LaunchConfig>;
+
+/// Represents the extensions file for a Visual Studio Code workspace, which
+/// lists the extensions VSCode recommends for it.
+pub type VSCodeExtensionsFile = VSCodeJsonFile<ExtensionsConfig>;
+
+/// Represents a multi-root `.code-workspace` file, which lists the folders
+/// of a workspace and has the settings, tasks and launch configurations for
+/// all of them.
+pub type VSCodeWorkspaceFile = VSCodeJsonFile<WorkspaceConfig>;
+
+impl<T: Serialize + DeserializeOwned + Default> VSCodeJsonFile<T> {
+    /// Reads a file, which must exist.
     ///
     /// # Arguments
     ///
-    /// - `file_path` - The path to the settings file.
+    /// - `file_path` - The path to the file.
     pub fn new(file_path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
-        if !file_path.exists() {
-            panic!("The settings file does not exist.");
-        }
+        let document = read_jsonc_file(&file_path)?;
 
-        let settings_json = fs::read_to_string(&file_path)?;
-        let settings = serde_json::from_str(&settings_json.as_str())?;
+        let values = serde_json::from_value(document.value()).map_err(|e| {
+            CliError::new(
+                format!("The file doesn't have the structure it should: {}", e).as_str(),
+                CliErrorKind::InvalidVSCodeFile
+            )
+            .in_file(&file_path.to_string_lossy())
+        })?;
 
         Ok(Self {
-            file_path: file_path,
-            values: settings
+            file_path,
+            values,
+            document: Some(document)
         })
     }
 
-    /// Writes the settings to the settings file.
-    pub fn write_settings(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let updated_settings_json = serde_json::to_string_pretty(&self.values)?;
+    /// Reads a file, or starts with the default values if it doesn't exist.
+    /// A missing file is created when it's saved.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_path` - The path to the file.
+    pub fn new_or_default(file_path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
+        match file_path.exists() {
+            true => Self::new(file_path),
+            false => Ok(Self {
+                file_path,
+                values: T::default(),
+                document: None
+            })
+        }
+    }
 
-        fs::write(&self.file_path, updated_settings_json)?;
+    /// Gets the values as they would be written to the file.
+    ///
+    /// If the file exists, only the values that changed since it was read are
+    /// rewritten, so the rest of the file is kept as it was.
+    pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let values = serde_json::to_value(&self.values)?;
 
-        Ok(())
+        match &self.document {
+            Some(document) => {
+                let mut document = document.clone();
+                document.update(&values)?;
+
+                Ok(document.text().to_string())
+            }
+            None => new_json_file_contents(&values)
+        }
     }
-}
 
-/// Represents the tasks file for a Visual Studio Code workspace.
-pub struct VSCodeTasksFile {
-    /// The path to the tasks file.
-    pub file_path: PathBuf,
+    /// Writes the values to the file.
+    pub fn write(&self) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(&self.file_path, self.to_json()?)?;
 
-    /// The values in the tasks file.
-    pub values: Value
-}
+        Ok(())
+    }
 
-impl VSCodeTasksFile {
-    /// Creates a new `VSCodeTasks` instance.
+    /// Writes the values to the file as the result of an operation, creating
+    /// the file if it doesn't exist.
+    ///
+    /// On a dry run, the file isn't written and the log says that it would be
+    /// created or modified. If diffs were requested, a diff of the change is
+    /// written after the operation's log.
     ///
     /// # Arguments
     ///
-    /// - `file_path` - The path to the tasks file.
-    pub fn new(file_path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
-        if !file_path.exists() {
-            panic!("The tasks file does not exist.");
-        }
+    /// - `output_directory` - The directory the path in the diff is relative
+    ///   to.
+    /// - `logger` - The [`ConsoleLogger`](crate::logging::ConsoleLogger)
+    ///   instance for logging.
+    pub fn save(
+        &self,
+        output_directory: &Path,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        save_file(&self.file_path, &self.to_json()?, output_directory, logger)
+    }
+}
 
-        let tasks_json = fs::read_to_string(&file_path)?;
-        let tasks = serde_json::from_str(&tasks_json.as_str())?;
+impl VSCodeSettingsFile {
+    /// Sets the value of a setting, adding the setting if it isn't in the
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the setting (e.g. `editor.tabSize`).
+    /// - `value` - The value of the setting.
+    pub fn set(
+        &mut self,
+        name: &str,
+        value: Value
+    ) {
+        self.values.insert(name.to_string(), value);
+    }
 
-        Ok(Self {
-            file_path: file_path,
-            values: tasks
-        })
+    /// Writes the settings to the settings file.
+    pub fn write_settings(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.write()
     }
+}
 
+impl VSCodeTasksFile {
     /// Writes the tasks to the tasks file.
     pub fn write_tasks(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let updated_tasks_json = serde_json::to_string_pretty(&self.values)?;
+        self.write()
+    }
+}
 
-        fs::write(&self.file_path, updated_tasks_json)?;
+/// Gets the contents of a new JSON file, indented with four spaces like the
+/// built-in templates.
+fn new_json_file_contents(value: &Value) -> Result<String, Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+    serde::Serialize::serialize(value, &mut serializer)?;
 
-        Ok(())
+    Ok(format!("{}\n", String::from_utf8(buffer)?))
+}
+
+/// Reads a VSCode JSON file, which can have comments and trailing commas.
+fn read_jsonc_file(file_path: &Path) -> Result<JsoncDocument, Box<dyn std::error::Error>> {
+    if !file_path.exists() {
+        return Err(CliError::new(
+            "The file does not exist.",
+            CliErrorKind::FilePathDoesNotExist
+        )
+        .in_file(&file_path.to_string_lossy())
+        .into());
+    }
+
+    let json = fs::read_to_string(file_path)?;
+
+    JsoncDocument::parse(&json).map_err(|e| e.in_file(&file_path.to_string_lossy()).into())
+}
+
+/// Writes the updated contents of a file as the result of an operation, or
+/// logs that it would be created or modified on a dry run, then writes a
+/// diff of the change if diffs were requested.
+fn save_file(
+    file_path: &Path,
+    updated_contents: &str,
+    output_directory: &Path,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file_exists = file_path.exists();
+
+    let original_contents = match file_exists {
+        true => fs::read_to_string(file_path)?,
+        false => String::new()
+    };
+
+    match logger.dry_run {
+        true => logger.write_dry_run_log(&[format!(
+            "Would {} '{}'",
+            if file_exists { "modify" } else { "create" },
+            file_path.display()
+        )])?,
+        false => {
+            if let Some(parent_directory) = file_path.parent() {
+                fs::create_dir_all(parent_directory)?;
+            }
+
+            fs::write(file_path, updated_contents)?;
+            logger.write_operation_success_log()?;
+        }
+    }
+
+    if logger.show_diff {
+        let relative_file_path = file_path
+            .strip_prefix(output_directory)
+            .unwrap_or(file_path);
+
+        logger.write_diff(
+            &relative_file_path.to_string_lossy(),
+            &original_contents,
+            updated_contents
+        )?;
     }
+
+    Ok(())
+}
+
+/// Deep-merges the values of a JSON file into the values of an existing one.
+///
+/// Objects are merged key by key, with `incoming` winning for values that
+/// aren't objects or arrays. Arrays of objects identified by a `label` (like
+/// tasks), an `id` (like task inputs) or a `name` (like launch
+/// configurations) are merged item by item, and other
+/// arrays get the items from `incoming` that they don't have yet. Values only
+/// in `existing` are kept.
+///
+/// # Arguments
+///
+/// - `existing` - The values of the existing file.
+/// - `incoming` - The values to merge into the existing file.
+///
+/// # Example
+///
+/// ```rust
+/// use serde_json::json;
+/// use vscodeconfigurator_lib::vscode_ops::merge_json_values;
+///
+/// let mut existing = json!({ "editor.tabSize": 2, "files.exclude": { "target": true } });
+/// let incoming = json!({ "editor.tabSize": 4, "files.exclude": { "bin": true } });
+///
+/// merge_json_values(&mut existing, incoming);
+///
+/// assert_eq!(
+///     existing,
+///     json!({ "editor.tabSize": 4, "files.exclude": { "target": true, "bin": true } })
+/// );
+/// ```
+pub fn merge_json_values(
+    existing: &mut Value,
+    incoming: Value
+) {
+    match (existing, incoming) {
+        (Value::Object(existing_map), Value::Object(incoming_map)) => {
+            for (key, incoming_value) in incoming_map {
+                match existing_map.get_mut(&key) {
+                    Some(existing_value) => merge_json_values(existing_value, incoming_value),
+                    None => {
+                        existing_map.insert(key, incoming_value);
+                    }
+                }
+            }
+        }
+        (Value::Array(existing_items), Value::Array(incoming_items)) => {
+            for incoming_item in incoming_items {
+                let matching_item = match json_item_key(&incoming_item) {
+                    Some(item_key) => existing_items
+                        .iter_mut()
+                        .find(|existing_item| json_item_key(existing_item) == Some(item_key)),
+                    None => None
+                };
+
+                match matching_item {
+                    Some(existing_item) => merge_json_values(existing_item, incoming_item),
+                    None => {
+                        if !existing_items.contains(&incoming_item) {
+                            existing_items.push(incoming_item);
+                        }
+                    }
+                }
+            }
+        }
+        (existing, incoming) => *existing = incoming
+    }
+}
+
+/// Gets the value that identifies an item of a JSON array, like a task's
+/// `label`, an input's `id` or a launch configuration's `name`.
+fn json_item_key(item: &Value) -> Option<&Value> {
+    item.get("label")
+        .or_else(|| item.get("id"))
+        .or_else(|| item.get("name"))
 }
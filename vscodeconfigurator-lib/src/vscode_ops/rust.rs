@@ -1,12 +1,38 @@
-use std::path::PathBuf;
+use std::{
+    fs,
+    path::{Component, Path, PathBuf}
+};
 
-use serde_json::json;
+use serde_json::{json, Map};
 
 use crate::{
+    error::{CliError, CliErrorKind},
     logging::{ConsoleLogger, OutputEmoji},
-    vscode_ops::VSCodeTasksFile
+    vscode_ops::{
+        launch::LaunchConfiguration,
+        tasks::TaskInputOption,
+        VSCodeExtensionsFile,
+        VSCodeLaunchFile,
+        VSCodeTasksFile
+    }
 };
 
+/// The label of the task in `.vscode/tasks.json` that builds a package.
+const BUILD_PACKAGE_TASK_LABEL: &str = "Build package";
+
+/// The extensions recommended for a Rust workspace: rust-analyzer, CodeLLDB
+/// for the launch configurations, and PowerShell for the `tools/*.ps1`
+/// scripts.
+const RECOMMENDED_EXTENSIONS: [&str; 3] = [
+    "rust-lang.rust-analyzer",
+    "vadimcn.vscode-lldb",
+    "ms-vscode.powershell"
+];
+
+/// The extensions recommended against for a Rust workspace. The deprecated
+/// Rust extension conflicts with rust-analyzer.
+const UNWANTED_EXTENSIONS: [&str; 1] = ["rust-lang.rust"];
+
 /// Adds a Rust package to the `.vscode/tasks.json` file.
 ///
 /// # Arguments
@@ -24,23 +50,24 @@ use crate::{
 ///
 /// Adds the package named `my_package` to the `.vscode/tasks.json` file.
 ///
-/// ```rust
-/// use vscodeconfigurator::logger::ConsoleLogger;
+/// ```rust,no_run
+/// use vscodeconfigurator_lib::{logging::ConsoleLogger, vscode_ops::rust::add_package_to_tasks};
 ///
 /// let output_directory = std::env::temp_dir().join("my-project");
 /// let package_name = "my_package";
 /// let package_friendly_name = "My Package";
-/// let mut logger = ConsoleLogger::new();
+/// let mut logger = ConsoleLogger::new(None, None);
 ///
 /// add_package_to_tasks(
 ///     &output_directory,
 ///     package_name,
 ///     package_friendly_name,
-///     logger
-/// );
+///     &mut logger
+/// )
+/// .unwrap();
 /// ```
 pub fn add_package_to_tasks(
-    output_directory: &PathBuf,
+    output_directory: &Path,
     package_name: &str,
     package_friendly_name: &str,
     logger: &mut ConsoleLogger
@@ -49,24 +76,832 @@ pub fn add_package_to_tasks(
 
     let mut vscode_tasks = VSCodeTasksFile::new(output_directory.join(".vscode/tasks.json"))?;
 
-    let inputs_node = vscode_tasks.values["inputs"].as_array_mut().unwrap();
+    vscode_tasks
+        .values
+        .input_mut("packageName")?
+        .add_option(TaskInputOption::labeled(
+            package_friendly_name,
+            package_name
+        ))?;
+
+    vscode_tasks.save(output_directory, logger)?;
+
+    Ok(())
+}
+
+/// Syncs the packages in the `packageName` input of the `.vscode/tasks.json`
+/// file with the member packages of the workspace.
+///
+/// Packages that aren't in the input yet are added with their name as their
+/// label, and packages that aren't in the workspace anymore are removed. The
+/// packages that are kept keep their labels, so custom friendly names aren't
+/// lost. The added and removed packages are written after the operation's
+/// log.
+///
+/// Returns `true` if the input was already in sync.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `package_names` - The names of the workspace's member packages.
+/// - `check` - Whether to only check the input, without changing the file.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Sync the `packageName` input with the packages `my_package` and
+/// `my_other_package`.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let package_names = vec!["my_package".to_string(), "my_other_package".to_string()];
+/// let mut logger = ConsoleLogger::new();
+///
+/// sync_packages_in_tasks(&output_directory, &package_names, false, logger);
+/// ```
+pub fn sync_packages_in_tasks(
+    output_directory: &Path,
+    package_names: &[String],
+    check: bool,
+    logger: &mut ConsoleLogger
+) -> Result<bool, Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        match check {
+            true => "Checking packages in tasks.json...",
+            false => "Syncing packages in tasks.json..."
+        },
+        OutputEmoji::Document
+    )?;
+
+    let mut vscode_tasks = VSCodeTasksFile::new(output_directory.join(".vscode/tasks.json"))?;
+
+    let package_input = vscode_tasks.values.input_mut("packageName")?;
+
+    let existing_package_names = package_input
+        .options
+        .iter()
+        .flatten()
+        .map(|option| option.value().to_string())
+        .collect::<Vec<String>>();
+
+    let removed_package_names = existing_package_names
+        .iter()
+        .filter(|package_name| !package_names.contains(package_name))
+        .cloned()
+        .collect::<Vec<String>>();
+
+    let added_package_names = package_names
+        .iter()
+        .filter(|package_name| !existing_package_names.contains(package_name))
+        .cloned()
+        .collect::<Vec<String>>();
+
+    if removed_package_names.is_empty() && added_package_names.is_empty() {
+        logger.write_success(format!("In sync {}\n", OutputEmoji::CheckMark))?;
+        return Ok(true);
+    }
+
+    for package_name in &removed_package_names {
+        package_input.remove_option(package_name);
+    }
+
+    for package_name in &added_package_names {
+        package_input.add_option(TaskInputOption::labeled(package_name, package_name))?;
+    }
+
+    match check {
+        true => logger.write_warning("Out of sync 🟠\n".to_string())?,
+        false => vscode_tasks.save(output_directory, logger)?
+    }
+
+    let (added_verb, removed_verb) = match check {
+        true => ("Would add", "Would remove"),
+        false => ("Added", "Removed")
+    };
+
+    for package_name in &added_package_names {
+        logger.write_info(format!("    {} '{}'\n", added_verb, package_name))?;
+    }
+
+    for package_name in &removed_package_names {
+        logger.write_info(format!("    {} '{}'\n", removed_verb, package_name))?;
+    }
+
+    Ok(false)
+}
+
+/// Adds the debug configurations for a Rust package to the
+/// `.vscode/launch.json` file, creating the file if it doesn't exist.
+///
+/// A CodeLLDB configuration is added for each binary target of the package
+/// and for the package's unit tests. Each one builds the package with the
+/// `Build package` task before launching. Configurations with the same name
+/// are replaced.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `package_name` - The name of the package.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Adds the debug configurations for the package named `my_package` to the
+/// `.vscode/launch.json` file.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let package_name = "my_package";
+/// let mut logger = ConsoleLogger::new();
+///
+/// add_package_to_launch(&output_directory, package_name, logger);
+/// ```
+pub fn add_package_to_launch(
+    output_directory: &Path,
+    package_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log("Adding package to launch.json...", OutputEmoji::Document)?;
+
+    let mut vscode_launch =
+        VSCodeLaunchFile::new_or_default(output_directory.join(".vscode/launch.json"))?;
+
+    let package_targets =
+        CargoPackageTargets::read(&output_directory.join(package_name), package_name)?;
+
+    for binary_name in &package_targets.binary_names {
+        vscode_launch
+            .values
+            .add_configuration(binary_launch_configuration(binary_name));
+    }
+
+    vscode_launch
+        .values
+        .add_configuration(unit_tests_launch_configuration(
+            package_name,
+            &package_targets
+        ));
+
+    vscode_launch.save(output_directory, logger)?;
+
+    Ok(())
+}
+
+/// Adds the extensions recommended for a Rust workspace to the
+/// `.vscode/extensions.json` file, creating the file if it doesn't exist.
+///
+/// The recommendations are merged into the file, so the extensions that are
+/// already in it are kept.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let mut logger = ConsoleLogger::new();
+///
+/// add_extension_recommendations(&output_directory, logger);
+/// ```
+pub fn add_extension_recommendations(
+    output_directory: &Path,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        "Adding recommended extensions to extensions.json...",
+        OutputEmoji::Document
+    )?;
+
+    let mut vscode_extensions =
+        VSCodeExtensionsFile::new_or_default(output_directory.join(".vscode/extensions.json"))?;
+
+    for extension_id in RECOMMENDED_EXTENSIONS {
+        vscode_extensions.values.recommend(extension_id);
+    }
+
+    for extension_id in UNWANTED_EXTENSIONS {
+        vscode_extensions.values.mark_unwanted(extension_id);
+    }
+
+    vscode_extensions.save(output_directory, logger)?;
+
+    Ok(())
+}
+
+/// Removes a Rust package from the `.vscode/tasks.json` file.
+///
+/// The package's option is removed from every `pickString` input, and an
+/// input that defaulted to the package defaults to its first option left.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `package_name` - The name of the package.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Removes the package named `my_package` from the `.vscode/tasks.json` file.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let package_name = "my_package";
+/// let mut logger = ConsoleLogger::new();
+///
+/// remove_package_from_tasks(&output_directory, package_name, logger);
+/// ```
+pub fn remove_package_from_tasks(
+    output_directory: &Path,
+    package_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log("Removing package from tasks.json...", OutputEmoji::Document)?;
+
+    let mut vscode_tasks = VSCodeTasksFile::new(output_directory.join(".vscode/tasks.json"))?;
+
+    if vscode_tasks.values.remove_input_option(package_name).is_empty() {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
 
-    let package_input = json!({
-        "label": package_friendly_name,
-        "value": package_name
-    });
+    vscode_tasks.save(output_directory, logger)?;
 
-    for input_node_item in inputs_node.iter_mut() {
-        if input_node_item["id"] == "packageName" {
-            let input_node_options = input_node_item["options"].as_array_mut().unwrap();
+    Ok(())
+}
 
-            input_node_options.push(package_input.clone());
+/// Removes the debug configurations of a Rust package from the
+/// `.vscode/launch.json` file.
+///
+/// The configurations removed are the ones that
+/// [`add_package_to_launch`] adds: the one for the package's unit tests and
+/// one for each of its binary targets. The package's binary targets are read
+/// from its directory, so this should be done before the package is deleted.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `package_name` - The name of the package.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Removes the debug configurations for the package named `my_package` from
+/// the `.vscode/launch.json` file.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let package_name = "my_package";
+/// let mut logger = ConsoleLogger::new();
+///
+/// remove_package_from_launch(&output_directory, package_name, logger);
+/// ```
+pub fn remove_package_from_launch(
+    output_directory: &Path,
+    package_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log("Removing package from launch.json...", OutputEmoji::Document)?;
+
+    let launch_file_path = output_directory.join(".vscode/launch.json");
+
+    if !launch_file_path.exists() {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
+
+    let mut vscode_launch = VSCodeLaunchFile::new_or_default(launch_file_path)?;
+
+    let package_targets =
+        CargoPackageTargets::read(&output_directory.join(package_name), package_name)?;
+
+    let mut configuration_names = vec![format!("Debug unit tests in '{}'", package_name)];
+
+    configuration_names.extend(
+        package_targets
+            .binary_names
+            .iter()
+            .chain(std::iter::once(&package_name.to_string()))
+            .map(|binary_name| format!("Debug '{}'", binary_name))
+    );
+
+    let mut removed_configuration = false;
+
+    for configuration_name in &configuration_names {
+        removed_configuration |= vscode_launch.values.remove_configuration(configuration_name);
+    }
+
+    if !removed_configuration {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
+
+    vscode_launch.save(output_directory, logger)?;
+
+    Ok(())
+}
+
+/// Removes a Rust package from the members of the workspace in the root
+/// `Cargo.toml` file.
+///
+/// The package is removed from `members` and `default-members`. Only paths
+/// that match the package's directory are removed, so a glob like
+/// `crates/*` is kept. The rest of the file keeps its formatting.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `package_name` - The name of the package.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Removes the package named `my_package` from the workspace in
+/// `Cargo.toml`.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let package_name = "my_package";
+/// let mut logger = ConsoleLogger::new();
+///
+/// remove_package_from_workspace(&output_directory, package_name, logger);
+/// ```
+pub fn remove_package_from_workspace(
+    output_directory: &Path,
+    package_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        "Removing package from the workspace in Cargo.toml...",
+        OutputEmoji::Package
+    )?;
+
+    let manifest_path = output_directory.join("Cargo.toml");
+
+    if !manifest_path.exists() {
+        return Err(CliError::new(
+            "There's no 'Cargo.toml' file in the output directory.",
+            CliErrorKind::FilePathDoesNotExist
+        )
+        .into());
+    }
+
+    let mut manifest = fs::read_to_string(&manifest_path)?
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|e| {
+            CliError::new(
+                format!("The 'Cargo.toml' file couldn't be parsed: {}", e).as_str(),
+                CliErrorKind::InvalidProjectFile
+            )
+            .in_file(&manifest_path.to_string_lossy())
+        })?;
+
+    let package_path = normalize_member_path(package_name);
+
+    let mut removed_member = false;
+
+    if let Some(workspace) = manifest
+        .get_mut("workspace")
+        .and_then(|workspace| workspace.as_table_like_mut())
+    {
+        for key in ["members", "default-members"] {
+            let Some(members) = workspace
+                .get_mut(key)
+                .and_then(|members| members.as_array_mut())
+            else {
+                continue;
+            };
+
+            let mut index = 0;
+
+            while index < members.len() {
+                let is_package = members
+                    .get(index)
+                    .and_then(|member| member.as_str())
+                    .is_some_and(|member| normalize_member_path(member) == package_path);
+
+                if !is_package {
+                    index += 1;
+                    continue;
+                }
+
+                let removed = members.remove(index);
+                removed_member = true;
+
+                // The next member takes the removed one's place, so it takes
+                // its leading whitespace too (e.g. no space after `[`).
+                if let (Some(prefix), Some(next_member)) =
+                    (removed.decor().prefix(), members.get_mut(index))
+                {
+                    next_member.decor_mut().set_prefix(prefix.clone());
+                }
+            }
         }
     }
 
-    vscode_tasks.write_tasks()?;
+    if !removed_member {
+        logger.write_warning("Not found 🟠\n".to_string())?;
+        return Ok(());
+    }
+
+    super::save_file(
+        &manifest_path,
+        &manifest.to_string(),
+        output_directory,
+        logger
+    )?;
+
+    Ok(())
+}
+
+/// Deletes the directory of a Rust package.
+///
+/// Only a directory inside the output directory that has a `Cargo.toml` file,
+/// or is a member of the workspace, is deleted. On a dry run, the directory
+/// isn't deleted and the log says that it would be.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `package_name` - The name of the package.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Deletes the package named `my_package`.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let package_name = "my_package";
+/// let mut logger = ConsoleLogger::new();
+///
+/// delete_package(&output_directory, package_name, logger);
+/// ```
+pub fn delete_package(
+    output_directory: &Path,
+    package_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        format!("Deleting package '{}'...", package_name).as_str(),
+        OutputEmoji::Folder
+    )?;
+
+    let package_directory = match find_package_directory(output_directory, package_name)? {
+        Some(package_directory) => package_directory,
+        None => {
+            logger.write_warning("Not found 🟠\n".to_string())?;
+            return Ok(());
+        }
+    };
+
+    if logger.dry_run {
+        logger.write_dry_run_log(&[format!(
+            "Would delete '{}'",
+            package_directory.display()
+        )])?;
+
+        return Ok(());
+    }
+
+    fs::remove_dir_all(&package_directory)?;
 
     logger.write_operation_success_log()?;
 
     Ok(())
 }
+
+/// Gets the directory of a Rust package that can be deleted.
+///
+/// Returns `None` if the directory doesn't exist. The package name must be a
+/// relative path inside the output directory, and the directory must have a
+/// `Cargo.toml` file or be a member of the workspace.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `package_name` - The name of the package.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// A package name can't point outside of the output directory.
+///
+/// ```rust
+/// use vscodeconfigurator_lib::vscode_ops::rust::find_package_directory;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+///
+/// assert!(find_package_directory(&output_directory, "../my_package").is_err());
+/// assert!(find_package_directory(&output_directory, ".").is_err());
+/// ```
+pub fn find_package_directory(
+    output_directory: &Path,
+    package_name: &str
+) -> Result<Option<PathBuf>, Box<dyn std::error::Error>> {
+    let is_relative_path = !package_name.is_empty()
+        && Path::new(package_name)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+    if !is_relative_path {
+        return Err(CliError::new(
+            format!(
+                "The package name '{}' isn't a relative path inside the output directory.",
+                package_name
+            )
+            .as_str(),
+            CliErrorKind::InvalidPackageName
+        )
+        .into());
+    }
+
+    let package_directory = output_directory.join(package_name);
+
+    if !package_directory.exists() {
+        return Ok(None);
+    }
+
+    // A symbolic link can still point back at the output directory.
+    if package_directory.canonicalize()? == output_directory.canonicalize()? {
+        return Err(CliError::new(
+            format!(
+                "The package name '{}' points to the output directory.",
+                package_name
+            )
+            .as_str(),
+            CliErrorKind::InvalidPackageName
+        )
+        .into());
+    }
+
+    if !package_directory.join("Cargo.toml").is_file()
+        && !is_workspace_member(output_directory, package_name)
+    {
+        return Err(CliError::new(
+            format!(
+                "'{}' has no 'Cargo.toml' file and isn't a member of the workspace.",
+                package_directory.display()
+            )
+            .as_str(),
+            CliErrorKind::InvalidPackageName
+        )
+        .into());
+    }
+
+    Ok(Some(package_directory))
+}
+
+/// Whether a package is listed in the workspace members of the root
+/// `Cargo.toml`.
+fn is_workspace_member(
+    output_directory: &Path,
+    package_name: &str
+) -> bool {
+    let Some(manifest) = fs::read_to_string(output_directory.join("Cargo.toml"))
+        .ok()
+        .and_then(|manifest| manifest.parse::<toml_edit::DocumentMut>().ok())
+    else {
+        return false;
+    };
+
+    let package_path = normalize_member_path(package_name);
+
+    manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+        .is_some_and(|members| {
+            members
+                .iter()
+                .filter_map(|member| member.as_str())
+                .any(|member| normalize_member_path(member) == package_path)
+        })
+}
+
+/// Normalizes a path in the members of a workspace, so that paths like
+/// `./my_package/` and `my_package` match.
+fn normalize_member_path(path: &str) -> String {
+    path.replace('\\', "/")
+        .split('/')
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+/// Creates the configuration that debugs a binary target.
+fn binary_launch_configuration(binary_name: &str) -> LaunchConfiguration {
+    let program = format!("${{workspaceFolder}}/target/debug/{}", binary_name);
+
+    let mut windows_options = Map::new();
+    windows_options.insert("program".to_string(), json!(format!("{}.exe", program)));
+
+    let mut configuration = LaunchConfiguration::new(
+        format!("Debug '{}'", binary_name).as_str(),
+        "lldb",
+        "launch"
+    );
+
+    configuration.program = Some(program);
+    configuration.args = Some(Vec::new());
+    configuration.cwd = Some("${workspaceFolder}".to_string());
+    configuration.pre_launch_task = Some(BUILD_PACKAGE_TASK_LABEL.to_string());
+    configuration
+        .other
+        .insert("windows".to_string(), windows_options.into());
+
+    configuration
+}
+
+/// Creates the configuration that debugs the unit tests of a package.
+///
+/// CodeLLDB builds the test binary with `cargo test --no-run`, since its file
+/// name has a hash that isn't known beforehand. The library's tests are used
+/// if the package has a library, otherwise the first binary's tests are.
+fn unit_tests_launch_configuration(
+    package_name: &str,
+    package_targets: &CargoPackageTargets
+) -> LaunchConfiguration {
+    let (target_arg, target_name, target_kind) = match (
+        package_targets.has_library,
+        package_targets.binary_names.first()
+    ) {
+        (false, Some(binary_name)) => {
+            (format!("--bin={}", binary_name), binary_name.clone(), "bin")
+        }
+        _ => ("--lib".to_string(), package_name.replace('-', "_"), "lib")
+    };
+
+    let mut configuration = LaunchConfiguration::new(
+        format!("Debug unit tests in '{}'", package_name).as_str(),
+        "lldb",
+        "launch"
+    );
+
+    configuration.args = Some(Vec::new());
+    configuration.cwd = Some("${workspaceFolder}".to_string());
+    configuration.pre_launch_task = Some(BUILD_PACKAGE_TASK_LABEL.to_string());
+    configuration.other.insert(
+        "cargo".to_string(),
+        json!({
+            "args": [
+                "test",
+                "--no-run",
+                target_arg,
+                format!("--package={}", package_name)
+            ],
+            "filter": {
+                "name": target_name,
+                "kind": target_kind
+            }
+        })
+    );
+
+    configuration
+}
+
+/// The targets of a Cargo package that can be debugged.
+struct CargoPackageTargets {
+    /// Whether the package has a library target.
+    has_library: bool,
+
+    /// The names of the binary targets.
+    binary_names: Vec<String>
+}
+
+impl CargoPackageTargets {
+    /// Reads the targets of a package from its `Cargo.toml` and the files
+    /// that Cargo discovers targets from (`src/lib.rs`, `src/main.rs` and
+    /// `src/bin/`).
+    ///
+    /// A package that doesn't exist yet (e.g. on a dry run) is treated as a
+    /// library.
+    ///
+    /// # Arguments
+    ///
+    /// - `package_directory` - The root directory of the package.
+    /// - `package_name` - The name of the package, used if its `Cargo.toml`
+    ///   can't be read.
+    fn read(
+        package_directory: &Path,
+        package_name: &str
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let manifest_path = package_directory.join("Cargo.toml");
+
+        if !manifest_path.exists() {
+            return Ok(Self {
+                has_library: true,
+                binary_names: Vec::new()
+            });
+        }
+
+        let manifest = fs::read_to_string(&manifest_path)?.parse::<toml::Table>()?;
+
+        let manifest_package_name = manifest
+            .get("package")
+            .and_then(|package| package.get("name"))
+            .and_then(|name| name.as_str())
+            .unwrap_or(package_name);
+
+        let auto_binaries = manifest
+            .get("package")
+            .and_then(|package| package.get("autobins"))
+            .and_then(|autobins| autobins.as_bool())
+            .unwrap_or(true);
+
+        let source_directory = package_directory.join("src");
+
+        let mut binary_names = manifest
+            .get("bin")
+            .and_then(|binaries| binaries.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|binary| binary.get("name").and_then(|name| name.as_str()))
+            .map(|name| name.to_string())
+            .collect::<Vec<String>>();
+
+        if auto_binaries {
+            let mut discovered_binary_names = Vec::new();
+
+            if source_directory.join("main.rs").exists() {
+                discovered_binary_names.push(manifest_package_name.to_string());
+            }
+
+            if let Ok(entries) = fs::read_dir(source_directory.join("bin")) {
+                let mut bin_directory_names = entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter_map(|path| match path.is_dir() {
+                        true if path.join("main.rs").exists() => path
+                            .file_name()
+                            .map(|name| name.to_string_lossy().to_string()),
+                        false if path.extension().is_some_and(|extension| extension == "rs") => {
+                            path.file_stem()
+                                .map(|name| name.to_string_lossy().to_string())
+                        }
+                        _ => None
+                    })
+                    .collect::<Vec<String>>();
+
+                bin_directory_names.sort();
+                discovered_binary_names.extend(bin_directory_names);
+            }
+
+            for binary_name in discovered_binary_names {
+                if !binary_names.contains(&binary_name) {
+                    binary_names.push(binary_name);
+                }
+            }
+        }
+
+        Ok(Self {
+            has_library: manifest.contains_key("lib") || source_directory.join("lib.rs").exists(),
+            binary_names
+        })
+    }
+}
@@ -0,0 +1,592 @@
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::error::{CliError, CliErrorKind};
+
+/// Implements `Serialize` and `Deserialize` for types whose derived
+/// implementations are generated with `#[serde(remote = "Self")]`, so that
+/// fields set to `null` are kept in `other`.
+///
+/// The derived implementations read a `null` field as `None`, which would
+/// drop it from the file when it's written back.
+macro_rules! keep_null_fields {
+    ($($type:ty),+) => {$(
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S
+            ) -> Result<S::Ok, S::Error> {
+                let mut typed = self.clone();
+                let other = std::mem::take(&mut typed.other);
+
+                let mut value = <$type>::serialize(&typed, serde_json::value::Serializer)
+                    .map_err(ser::Error::custom)?;
+
+                // A field that was `null` when read is only written as `null` if
+                // it's still unset.
+                if let Value::Object(fields) = &mut value {
+                    for (name, field_value) in other {
+                        fields.entry(name).or_insert(field_value);
+                    }
+                }
+
+                value.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let (null_fields, fields): (Map<String, Value>, Map<String, Value>) =
+                    Map::deserialize(deserializer)?
+                        .into_iter()
+                        .partition(|(_, field_value)| field_value.is_null());
+
+                let mut typed = <$type>::deserialize(Value::Object(fields))
+                    .map_err(de::Error::custom)?;
+                typed.other.extend(null_fields);
+
+                Ok(typed)
+            }
+        }
+    )+};
+}
+
+keep_null_fields!(
+    TasksConfig,
+    Task,
+    TaskOptions,
+    TaskPresentation,
+    ProblemMatcherDefinition,
+    TaskInput,
+    LabeledTaskInputOption
+);
+
+/// The contents of a `.vscode/tasks.json` file.
+///
+/// Fields that aren't modeled, or are set to `null`, are kept in `other`, so
+/// they're written back as they were.
+///
+/// ```rust
+/// use serde_json::json;
+/// use vscodeconfigurator_lib::vscode_ops::tasks::TasksConfig;
+///
+/// let tasks_json = json!({
+///     "version": "2.0.0",
+///     "inputs": [{ "id": "packageName", "type": "pickString", "description": null }]
+/// });
+///
+/// let tasks: TasksConfig = serde_json::from_value(tasks_json.clone()).unwrap();
+///
+/// assert_eq!(serde_json::to_value(&tasks).unwrap(), tasks_json);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct TasksConfig {
+    /// The version of the tasks schema (e.g. `2.0.0`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// The tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<Vec<Task>>,
+
+    /// The inputs the tasks can ask for, like the package to build.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<TaskInput>>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+impl TasksConfig {
+    /// Gets the task with a label.
+    ///
+    /// # Arguments
+    ///
+    /// - `label` - The label of the task.
+    pub fn task(
+        &self,
+        label: &str
+    ) -> Option<&Task> {
+        self.tasks
+            .as_ref()?
+            .iter()
+            .find(|task| task.label.as_deref() == Some(label))
+    }
+
+    /// Gets the task with a label to change it.
+    ///
+    /// Returns an error if there's no task with the label.
+    ///
+    /// # Arguments
+    ///
+    /// - `label` - The label of the task.
+    pub fn task_mut(
+        &mut self,
+        label: &str
+    ) -> Result<&mut Task, CliError> {
+        self.tasks
+            .iter_mut()
+            .flatten()
+            .find(|task| task.label.as_deref() == Some(label))
+            .ok_or_else(|| {
+                CliError::new(
+                    format!("There's no task labeled '{}' in tasks.json.", label).as_str(),
+                    CliErrorKind::VSCodeItemNotFound
+                )
+            })
+    }
+
+    /// Adds a task, replacing the task with the same label if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// - `task` - The task to add.
+    pub fn add_task(
+        &mut self,
+        task: Task
+    ) {
+        let tasks = self.tasks.get_or_insert_with(Vec::new);
+
+        match tasks.iter_mut().find(|existing_task| {
+            existing_task.label.is_some() && existing_task.label == task.label
+        }) {
+            Some(existing_task) => *existing_task = task,
+            None => tasks.push(task)
+        }
+    }
+
+    /// Removes the task with a label.
+    ///
+    /// Returns `true` if there was one.
+    ///
+    /// # Arguments
+    ///
+    /// - `label` - The label of the task.
+    pub fn remove_task(
+        &mut self,
+        label: &str
+    ) -> bool {
+        let Some(tasks) = &mut self.tasks else {
+            return false;
+        };
+
+        let task_count = tasks.len();
+        tasks.retain(|task| task.label.as_deref() != Some(label));
+
+        tasks.len() != task_count
+    }
+
+    /// Gets the input with an ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `id` - The ID of the input.
+    pub fn input(
+        &self,
+        id: &str
+    ) -> Option<&TaskInput> {
+        self.inputs.as_ref()?.iter().find(|input| input.id == id)
+    }
+
+    /// Removes the option with a value from every `pickString` input, fixing
+    /// up the inputs' defaults.
+    ///
+    /// Returns the IDs of the inputs that had the option.
+    ///
+    /// # Arguments
+    ///
+    /// - `value` - The value of the option.
+    pub fn remove_input_option(
+        &mut self,
+        value: &str
+    ) -> Vec<String> {
+        self.inputs
+            .iter_mut()
+            .flatten()
+            .filter_map(|input| match input.remove_option(value) {
+                true => Some(input.id.clone()),
+                false => None
+            })
+            .collect()
+    }
+
+    /// Gets the input with an ID to change it.
+    ///
+    /// Returns an error if there's no input with the ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `id` - The ID of the input.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// Add a package to the `packageName` input.
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::vscode_ops::tasks::{TaskInputOption, TasksConfig};
+    ///
+    /// let mut tasks: TasksConfig = serde_json::from_str(
+    ///     r#"{ "inputs": [{ "id": "packageName", "type": "pickString", "options": [] }] }"#
+    /// )
+    /// .unwrap();
+    ///
+    /// tasks
+    ///     .input_mut("packageName")
+    ///     .unwrap()
+    ///     .add_option(TaskInputOption::labeled("My Package", "my_package"))
+    ///     .unwrap();
+    ///
+    /// assert!(tasks.input_mut("projectItem").is_err());
+    /// ```
+    pub fn input_mut(
+        &mut self,
+        id: &str
+    ) -> Result<&mut TaskInput, CliError> {
+        self.inputs
+            .iter_mut()
+            .flatten()
+            .find(|input| input.id == id)
+            .ok_or_else(|| {
+                CliError::new(
+                    format!("There's no input with the ID '{}' in tasks.json.", id).as_str(),
+                    CliErrorKind::VSCodeItemNotFound
+                )
+            })
+    }
+}
+
+/// A task in a `.vscode/tasks.json` file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct Task {
+    /// The label of the task, shown in the UI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// The type of the task (e.g. `process` or `shell`).
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub task_type: Option<String>,
+
+    /// The command to run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// The arguments for the command. Each one is a string or an object with
+    /// a value and how to quote it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<Value>>,
+
+    /// The options for running the command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<TaskOptions>,
+
+    /// How the task is shown in the terminal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation: Option<TaskPresentation>,
+
+    /// The problem matchers that scan the task's output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub problem_matcher: Option<ProblemMatcher>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+/// The options for running a task's command.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct TaskOptions {
+    /// The directory to run the command in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// The environment variables for the command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<Map<String, Value>>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+/// How a task is shown in the terminal.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct TaskPresentation {
+    /// Whether the command is echoed in the terminal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub echo: Option<bool>,
+
+    /// When the terminal is revealed (`always`, `silent` or `never`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reveal: Option<String>,
+
+    /// Whether the terminal takes focus.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus: Option<bool>,
+
+    /// Whether the terminal is shared with other tasks (`shared`,
+    /// `dedicated` or `new`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panel: Option<String>,
+
+    /// Whether the "Terminal will be reused by tasks" message is shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_reuse_message: Option<bool>,
+
+    /// Whether the terminal is cleared before the task runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear: Option<bool>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+/// The problem matchers of a task, which can be the name of a problem matcher
+/// (e.g. `$rustc`), a definition or a list of either.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ProblemMatcher {
+    /// The name of a problem matcher.
+    Named(String),
+
+    /// A problem matcher definition.
+    Definition(Box<ProblemMatcherDefinition>),
+
+    /// A list of problem matchers.
+    List(Vec<ProblemMatcher>)
+}
+
+/// The definition of a problem matcher.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct ProblemMatcherDefinition {
+    /// The name of the problem matcher this one extends (e.g. `$msCompile`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+
+    /// The owner of the problems the matcher finds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+
+    /// How the file paths in the problems are resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_location: Option<Value>,
+
+    /// The patterns that match problems in the output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<Value>,
+
+    /// The patterns that detect when a background task starts and ends.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<Value>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+/// An input in a `.vscode/tasks.json` file, which tasks refer to with
+/// `${input:id}`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct TaskInput {
+    /// The ID of the input.
+    pub id: String,
+
+    /// The type of the input (e.g. `pickString` or `promptString`).
+    #[serde(rename = "type")]
+    pub input_type: String,
+
+    /// The description shown when asking for the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The default value of the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+
+    /// The options to pick from, for `pickString` inputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<TaskInputOption>>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+impl TaskInput {
+    /// Adds an option to pick from.
+    ///
+    /// Nothing changes if there's already an option with the same value.
+    /// Returns an error if the input isn't a `pickString` input.
+    ///
+    /// # Arguments
+    ///
+    /// - `option` - The option to add.
+    pub fn add_option(
+        &mut self,
+        option: TaskInputOption
+    ) -> Result<(), CliError> {
+        if self.input_type != "pickString" {
+            return Err(CliError::new(
+                format!(
+                    "The input '{}' in tasks.json is a '{}' input, so it has no options to pick from.",
+                    self.id, self.input_type
+                )
+                .as_str(),
+                CliErrorKind::InvalidVSCodeFile
+            ));
+        }
+
+        let options = self.options.get_or_insert_with(Vec::new);
+
+        if !options
+            .iter()
+            .any(|existing_option| existing_option.value() == option.value())
+        {
+            options.push(option);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the option with a value.
+    ///
+    /// If the input's default is the removed value, the first option left
+    /// becomes the default, or the default is removed if there are no options
+    /// left. Returns `true` if there was an option with the value.
+    ///
+    /// # Arguments
+    ///
+    /// - `value` - The value of the option.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use vscodeconfigurator_lib::vscode_ops::tasks::TaskInput;
+    ///
+    /// let mut input: TaskInput = serde_json::from_value(json!({
+    ///     "id": "packageName",
+    ///     "type": "pickString",
+    ///     "default": "old_package",
+    ///     "options": [
+    ///         { "label": "Old Package", "value": "old_package" },
+    ///         { "label": "New Package", "value": "new_package" }
+    ///     ]
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert!(input.remove_option("old_package"));
+    /// assert!(!input.remove_option("old_package"));
+    /// assert_eq!(input.default, Some(json!("new_package")));
+    /// ```
+    pub fn remove_option(
+        &mut self,
+        value: &str
+    ) -> bool {
+        let Some(options) = &mut self.options else {
+            return false;
+        };
+
+        let option_count = options.len();
+        options.retain(|option| option.value() != value);
+
+        if options.len() == option_count {
+            return false;
+        }
+
+        if self.default.as_ref().and_then(|default| default.as_str()) == Some(value) {
+            self.default = options
+                .first()
+                .map(|option| Value::String(option.value().to_string()));
+        }
+
+        true
+    }
+}
+
+/// An option of a `pickString` input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TaskInputOption {
+    /// An option that shows its value.
+    Value(String),
+
+    /// An option with a value and, usually, a label to show instead of it.
+    Labeled(LabeledTaskInputOption)
+}
+
+impl TaskInputOption {
+    /// Creates an option with a label to show instead of its value.
+    ///
+    /// # Arguments
+    ///
+    /// - `label` - The label to show.
+    /// - `value` - The value of the option.
+    pub fn labeled(
+        label: &str,
+        value: &str
+    ) -> Self {
+        Self::Labeled(LabeledTaskInputOption {
+            label: Some(label.to_string()),
+            value: value.to_string(),
+            other: Map::new()
+        })
+    }
+
+    /// Gets the value of the option.
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Value(value) => value,
+            Self::Labeled(option) => &option.value
+        }
+    }
+
+    /// Gets the label of the option, which is its value if it doesn't have
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use vscodeconfigurator_lib::vscode_ops::tasks::TaskInputOption;
+    ///
+    /// let option: TaskInputOption = serde_json::from_value(json!({ "value": "my_package" })).unwrap();
+    ///
+    /// assert_eq!(option.label(), "my_package");
+    /// ```
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Value(value) => value,
+            Self::Labeled(option) => option.label.as_deref().unwrap_or(&option.value)
+        }
+    }
+}
+
+/// An option of a `pickString` input written as an object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct LabeledTaskInputOption {
+    /// The label to show, which is the value if it isn't set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// The value of the option.
+    pub value: String,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
@@ -0,0 +1,551 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf}
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::{CliError, CliErrorKind},
+    logging::{ConsoleLogger, OutputEmoji},
+    vscode_ops::{
+        extensions::ExtensionsConfig,
+        launch::LaunchConfig,
+        merge_json_values,
+        tasks::TasksConfig,
+        VSCodeSettingsFile,
+        VSCodeWorkspaceFile
+    }
+};
+
+/// The file extension of a multi-root workspace file.
+pub const WORKSPACE_FILE_EXTENSION: &str = "code-workspace";
+
+/// The contents of a multi-root `.code-workspace` file.
+///
+/// Fields that aren't modeled are kept in `other`, so they're written back
+/// as they were.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceConfig {
+    /// The root folders of the workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folders: Option<Vec<WorkspaceFolder>>,
+
+    /// The settings for the whole workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Map<String, Value>>,
+
+    /// The tasks for the whole workspace, which have the same structure as a
+    /// `.vscode/tasks.json` file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<TasksConfig>,
+
+    /// The launch configurations for the whole workspace, which have the same
+    /// structure as a `.vscode/launch.json` file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch: Option<LaunchConfig>,
+
+    /// The extensions recommended for the whole workspace, which have the same
+    /// structure as a `.vscode/extensions.json` file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<ExtensionsConfig>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+impl Default for WorkspaceConfig {
+    /// Creates the contents of a new workspace file, like
+    /// [`new_workspace`](WorkspaceConfig::new_workspace).
+    fn default() -> Self {
+        Self::new_workspace()
+    }
+}
+
+impl WorkspaceConfig {
+    /// Creates the contents of a new workspace file, with no folders, no
+    /// settings and empty tasks and launch sections.
+    pub fn new_workspace() -> Self {
+        Self {
+            folders: Some(Vec::new()),
+            settings: Some(Map::new()),
+            tasks: Some(TasksConfig {
+                version: Some("2.0.0".to_string()),
+                tasks: Some(Vec::new()),
+                ..Default::default()
+            }),
+            launch: Some(LaunchConfig::default()),
+            extensions: None,
+            other: Map::new()
+        }
+    }
+
+    /// Gets the folder with a path.
+    ///
+    /// Paths are compared without a leading `./`, trailing slashes or the
+    /// difference between `/` and `\`.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the folder, relative to the workspace file.
+    pub fn folder(
+        &self,
+        path: &str
+    ) -> Option<&WorkspaceFolder> {
+        let path = normalize_folder_path(path);
+
+        self.folders
+            .as_ref()?
+            .iter()
+            .find(|folder| normalize_folder_path(&folder.path) == path)
+    }
+
+    /// Adds a folder, or renames the folder with the same path if there is
+    /// one.
+    ///
+    /// # Arguments
+    ///
+    /// - `folder` - The folder to add.
+    ///
+    /// # Examples
+    ///
+    /// ## Example 01
+    ///
+    /// ```rust
+    /// use vscodeconfigurator_lib::vscode_ops::workspace::{WorkspaceConfig, WorkspaceFolder};
+    ///
+    /// let mut workspace = WorkspaceConfig::new_workspace();
+    ///
+    /// workspace.add_folder(WorkspaceFolder::new("./src/dotnet/", None));
+    /// workspace.add_folder(WorkspaceFolder::new("src/dotnet", Some(".NET")));
+    ///
+    /// let folders = workspace.folders.unwrap();
+    ///
+    /// assert_eq!(folders.len(), 1);
+    /// assert_eq!(folders[0].name.as_deref(), Some(".NET"));
+    /// ```
+    pub fn add_folder(
+        &mut self,
+        folder: WorkspaceFolder
+    ) {
+        let folders = self.folders.get_or_insert_with(Vec::new);
+        let path = normalize_folder_path(&folder.path);
+
+        match folders
+            .iter_mut()
+            .find(|existing_folder| normalize_folder_path(&existing_folder.path) == path)
+        {
+            Some(existing_folder) => {
+                if folder.name.is_some() {
+                    existing_folder.name = folder.name;
+                }
+            }
+            None => folders.push(folder)
+        }
+    }
+
+    /// Removes the folder with a path or a name.
+    ///
+    /// Returns the folder that was removed, if there was one.
+    ///
+    /// # Arguments
+    ///
+    /// - `path_or_name` - The path of the folder, relative to the workspace
+    ///   file, or its name.
+    pub fn remove_folder(
+        &mut self,
+        path_or_name: &str
+    ) -> Option<WorkspaceFolder> {
+        let folders = self.folders.as_mut()?;
+        let path = normalize_folder_path(path_or_name);
+
+        let folder_index = folders
+            .iter()
+            .position(|folder| normalize_folder_path(&folder.path) == path)
+            .or_else(|| {
+                folders
+                    .iter()
+                    .position(|folder| folder.name.as_deref() == Some(path_or_name))
+            })?;
+
+        Some(folders.remove(folder_index))
+    }
+
+    /// Merges settings into the settings for the whole workspace.
+    ///
+    /// Settings that are already in the workspace win, so merging a folder's
+    /// settings never changes what the workspace has set.
+    ///
+    /// # Arguments
+    ///
+    /// - `settings` - The settings to merge.
+    pub fn merge_settings(
+        &mut self,
+        settings: Map<String, Value>
+    ) {
+        let mut merged_settings = Value::Object(settings);
+
+        if let Some(existing_settings) = self.settings.take() {
+            merge_json_values(&mut merged_settings, Value::Object(existing_settings));
+        }
+
+        if let Value::Object(merged_settings) = merged_settings {
+            self.settings = Some(merged_settings);
+        }
+    }
+}
+
+/// A root folder of a multi-root workspace.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceFolder {
+    /// The path of the folder, relative to the workspace file.
+    pub path: String,
+
+    /// The name of the folder shown in the explorer, instead of the name of
+    /// the directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The fields that aren't modeled.
+    #[serde(flatten)]
+    pub other: Map<String, Value>
+}
+
+impl WorkspaceFolder {
+    /// Creates a workspace folder.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the folder, relative to the workspace file.
+    /// - `name` - The name of the folder shown in the explorer.
+    pub fn new(
+        path: &str,
+        name: Option<&str>
+    ) -> Self {
+        Self {
+            path: path.to_string(),
+            name: name.map(|name| name.to_string()),
+            other: Map::new()
+        }
+    }
+}
+
+/// Initializes a `<name>.code-workspace` file in a directory, or updates it if
+/// it already exists.
+///
+/// Each folder is added to the workspace, and the settings in its
+/// `.vscode/settings.json` file are merged into the workspace's settings.
+/// VSCode ignores the window-wide settings of a folder in a multi-root
+/// workspace, so they only apply if they're in the workspace file. The
+/// workspace file gets tasks and launch sections if it doesn't have them.
+///
+/// # Arguments
+///
+/// - `output_directory` - The directory of the workspace file.
+/// - `workspace_name` - The name of the workspace file, without its
+///   extension.
+/// - `folders` - The folders of the workspace.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// Initializes `my-project.code-workspace` with the `rust` and `dotnet`
+/// folders.
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let output_directory = std::env::temp_dir().join("my-project");
+/// let folders = vec![
+///     WorkspaceFolder::new("rust", Some("Rust")),
+///     WorkspaceFolder::new("dotnet", Some(".NET")),
+/// ];
+/// let mut logger = ConsoleLogger::new();
+///
+/// init_workspace(&output_directory, "my-project", &folders, logger);
+/// ```
+pub fn init_workspace(
+    output_directory: &Path,
+    workspace_name: &str,
+    folders: &[WorkspaceFolder],
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_file_name = format!("{}.{}", workspace_name, WORKSPACE_FILE_EXTENSION);
+
+    logger.write_operation_log(
+        format!("Writing '{}'...", workspace_file_name).as_str(),
+        OutputEmoji::Document
+    )?;
+
+    let mut vscode_workspace =
+        VSCodeWorkspaceFile::new_or_default(output_directory.join(&workspace_file_name))?;
+
+    let new_workspace = WorkspaceConfig::new_workspace();
+    let workspace = &mut vscode_workspace.values;
+
+    workspace.folders.get_or_insert_with(Vec::new);
+    workspace.settings.get_or_insert_with(Map::new);
+
+    if workspace.tasks.is_none() {
+        workspace.tasks = new_workspace.tasks;
+    }
+
+    if workspace.launch.is_none() {
+        workspace.launch = new_workspace.launch;
+    }
+
+    for folder in folders {
+        add_folder(output_directory, workspace, folder.clone())?;
+    }
+
+    vscode_workspace.save(output_directory, logger)?;
+
+    Ok(())
+}
+
+/// Adds a folder to a `.code-workspace` file, and merges the settings in its
+/// `.vscode/settings.json` file into the workspace's settings.
+///
+/// If the workspace already has the folder, only its name is changed.
+///
+/// # Arguments
+///
+/// - `workspace_file_path` - The path to the workspace file.
+/// - `folder` - The folder to add.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let workspace_file_path = std::env::temp_dir().join("my-project/my-project.code-workspace");
+/// let folder = WorkspaceFolder::new("dotnet", Some(".NET"));
+/// let mut logger = ConsoleLogger::new();
+///
+/// add_folder_to_workspace(&workspace_file_path, folder, logger);
+/// ```
+pub fn add_folder_to_workspace(
+    workspace_file_path: &Path,
+    folder: WorkspaceFolder,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        format!("Adding '{}' to the workspace...", folder.path).as_str(),
+        OutputEmoji::Folder
+    )?;
+
+    let workspace_directory = workspace_directory(workspace_file_path);
+
+    let mut vscode_workspace = read_existing_workspace_file(workspace_file_path)?;
+
+    add_folder(&workspace_directory, &mut vscode_workspace.values, folder)?;
+
+    vscode_workspace.save(&workspace_directory, logger)?;
+
+    Ok(())
+}
+
+/// Removes a folder from a `.code-workspace` file.
+///
+/// The settings that were merged from the folder are kept, since they can't
+/// be told apart from the workspace's own settings.
+///
+/// # Arguments
+///
+/// - `workspace_file_path` - The path to the workspace file.
+/// - `path_or_name` - The path of the folder, relative to the workspace file,
+///   or its name.
+/// - `logger` - The
+///   [`ConsoleLogger`](crate::logging::ConsoleLogger) instance
+///   for logging.
+///
+/// # Examples
+///
+/// ## Example 01
+///
+/// ```rust,ignore
+/// use vscodeconfigurator::logger::ConsoleLogger;
+///
+/// let workspace_file_path = std::env::temp_dir().join("my-project/my-project.code-workspace");
+/// let mut logger = ConsoleLogger::new();
+///
+/// remove_folder_from_workspace(&workspace_file_path, "dotnet", logger);
+/// ```
+pub fn remove_folder_from_workspace(
+    workspace_file_path: &Path,
+    path_or_name: &str,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    logger.write_operation_log(
+        format!("Removing '{}' from the workspace...", path_or_name).as_str(),
+        OutputEmoji::Folder
+    )?;
+
+    let workspace_directory = workspace_directory(workspace_file_path);
+
+    let mut vscode_workspace = read_existing_workspace_file(workspace_file_path)?;
+
+    let relative_path = relative_folder_path(&workspace_directory, Path::new(path_or_name));
+
+    let removed_folder = vscode_workspace
+        .values
+        .remove_folder(&relative_path)
+        .or_else(|| vscode_workspace.values.remove_folder(path_or_name));
+
+    if removed_folder.is_none() {
+        return Err(CliError::new(
+            format!(
+                "There's no folder with the path or name '{}' in the workspace.",
+                path_or_name
+            )
+            .as_str(),
+            CliErrorKind::VSCodeItemNotFound
+        )
+        .in_file(&workspace_file_path.to_string_lossy())
+        .into());
+    }
+
+    vscode_workspace.save(&workspace_directory, logger)?;
+
+    Ok(())
+}
+
+/// Finds the `.code-workspace` file in a directory.
+///
+/// Returns an error if there isn't one, or if there's more than one.
+///
+/// # Arguments
+///
+/// - `directory` - The directory to look in.
+pub fn find_workspace_file(directory: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut workspace_file_paths = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|extension| extension == WORKSPACE_FILE_EXTENSION)
+        })
+        .collect::<Vec<PathBuf>>();
+
+    match workspace_file_paths.len() {
+        0 => Err(CliError::new(
+            "No '.code-workspace' file found in the directory.",
+            CliErrorKind::FilePathDoesNotExist
+        )
+        .in_file(&directory.to_string_lossy())
+        .into()),
+        1 => Ok(workspace_file_paths.remove(0)),
+        _ => Err(CliError::new(
+            "More than one '.code-workspace' file found in the directory. Choose one with '--workspace-file'.",
+            CliErrorKind::MultipleWorkspaceFiles
+        )
+        .in_file(&directory.to_string_lossy())
+        .into())
+    }
+}
+
+/// Adds a folder to a workspace and merges its settings into the workspace's
+/// settings.
+fn add_folder(
+    workspace_directory: &Path,
+    workspace: &mut WorkspaceConfig,
+    mut folder: WorkspaceFolder
+) -> Result<(), Box<dyn std::error::Error>> {
+    folder.path = relative_folder_path(workspace_directory, Path::new(&folder.path));
+
+    let folder_settings_path = workspace_directory
+        .join(&folder.path)
+        .join(".vscode/settings.json");
+
+    if folder_settings_path.exists() {
+        let folder_settings = VSCodeSettingsFile::new(folder_settings_path)?;
+
+        workspace.merge_settings(folder_settings.values);
+    }
+
+    workspace.add_folder(folder);
+
+    Ok(())
+}
+
+/// Reads a workspace file that has to exist.
+fn read_existing_workspace_file(
+    workspace_file_path: &Path
+) -> Result<VSCodeWorkspaceFile, Box<dyn std::error::Error>> {
+    if !workspace_file_path.exists() {
+        return Err(CliError::new(
+            "The workspace file does not exist.",
+            CliErrorKind::FilePathDoesNotExist
+        )
+        .in_file(&workspace_file_path.to_string_lossy())
+        .into());
+    }
+
+    VSCodeWorkspaceFile::new(workspace_file_path.to_path_buf())
+}
+
+/// Gets the directory a workspace file is in, which its folder paths are
+/// relative to.
+fn workspace_directory(workspace_file_path: &Path) -> PathBuf {
+    match workspace_file_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from(".")
+    }
+}
+
+/// Gets the path of a folder relative to the workspace directory, with `/`
+/// separators like VSCode writes them.
+///
+/// Absolute paths inside the workspace directory are made relative, and other
+/// absolute paths are kept.
+fn relative_folder_path(
+    workspace_directory: &Path,
+    folder_path: &Path
+) -> String {
+    let folder_path = match folder_path.strip_prefix(workspace_directory) {
+        Ok(relative_path) if folder_path.is_absolute() => relative_path,
+        _ if folder_path.is_absolute() => {
+            return folder_path.to_string_lossy().replace('\\', "/");
+        }
+        _ => folder_path
+    };
+
+    let path = folder_path
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<String>>()
+        .join("/");
+
+    match path.is_empty() {
+        true => ".".to_string(),
+        false => path
+    }
+}
+
+/// Normalizes the path of a folder so it can be compared with another one.
+fn normalize_folder_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let path = path.trim_end_matches('/');
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    match path.is_empty() {
+        true => ".".to_string(),
+        false => path.to_string()
+    }
+}
@@ -4,9 +4,10 @@ use std::{error::Error, process};
 
 use clap::{crate_version, CommandFactory, Parser};
 use clap_complete::aot::generate;
+use crossterm::tty::IsTty;
 use vscodeconfigurator_lib::{
     error::{CliError, CliErrorKind},
-    logging::ConsoleLogger
+    logging::{ConflictPolicy, ConsoleLogger}
 };
 
 use crate::subcommands::{ConfiguratorSubcommand, RootSubcommands};
@@ -23,7 +24,37 @@ use crate::subcommands::{ConfiguratorSubcommand, RootSubcommands};
 )]
 struct Cli {
     #[command(subcommand)]
-    command: Option<RootSubcommands>
+    command: Option<RootSubcommands>,
+
+    /// Print what the command would do without writing files or running
+    /// external commands.
+    #[arg(
+        long = "dry-run",
+        global = true,
+        required = false,
+        default_value = "false"
+    )]
+    dry_run: bool,
+
+    /// Show a diff of each existing file that is changed.
+    #[arg(
+        long = "diff",
+        global = true,
+        required = false,
+        default_value = "false"
+    )]
+    diff: bool,
+
+    /// What to do with existing files that would be replaced. Defaults to
+    /// `prompt` in a terminal and `fail` otherwise.
+    #[arg(
+        long = "on-conflict",
+        global = true,
+        required = false,
+        value_enum,
+        env = "VSCODECONFIGURATOR_ON_CONFLICT"
+    )]
+    on_conflict: Option<ConflictPolicy>
 }
 
 /// The entry point for the CLI.
@@ -32,6 +63,16 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let cli = Cli::parse();
 
+    logger.dry_run = cli.dry_run;
+    logger.show_diff = cli.diff;
+    logger.on_conflict = match cli.on_conflict {
+        Some(on_conflict) => on_conflict,
+        None => match logger.stdout.is_tty() {
+            true => ConflictPolicy::Prompt,
+            false => ConflictPolicy::Fail
+        }
+    };
+
     let result = match &cli.command {
         Some(RootSubcommands::Completions(command)) => {
             generate(
@@ -47,10 +88,22 @@ fn main() -> Result<(), Box<dyn Error>> {
             command.as_ref().unwrap().match_subcommand(&mut logger)
         }
 
+        Some(RootSubcommands::Devcontainer { command }) => {
+            command.as_ref().unwrap().match_subcommand(&mut logger)
+        }
+
         Some(RootSubcommands::Rust { command }) => {
             command.as_ref().unwrap().match_subcommand(&mut logger)
         }
 
+        Some(RootSubcommands::Template { command }) => {
+            command.as_ref().unwrap().match_subcommand(&mut logger)
+        }
+
+        Some(RootSubcommands::Workspace { command }) => {
+            command.as_ref().unwrap().match_subcommand(&mut logger)
+        }
+
         None => Err(CliError::new(
             "No subcommand provided.",
             CliErrorKind::NoSubcommandProvided
@@ -1,7 +1,18 @@
-use std::{env, io::ErrorKind, path::PathBuf, process};
+use std::{
+    env,
+    error::Error,
+    io::ErrorKind,
+    path::{Path, PathBuf}
+};
 
 use clap::{Args, ValueHint};
-use vscodeconfigurator_lib::{external_procs::dotnet, logging::ConsoleLogger, vscode_ops};
+use vscodeconfigurator_lib::{
+    dotnet::solution,
+    error::{CliError, CliErrorKind},
+    external_procs::dotnet,
+    logging::ConsoleLogger,
+    vscode_ops
+};
 
 use crate::subcommands::ConfiguratorSubcommandArgs;
 
@@ -47,59 +58,36 @@ impl ConfiguratorSubcommandArgs for AddCommandArgs {
         &self,
         logger: &mut ConsoleLogger
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let solution_file_path: PathBuf;
-
-        if self.solution_file_path.is_none() {
-            solution_file_path = match get_solution_file_path_default_value() {
-                Ok(path) => path,
-                Err(_) => {
-                    logger
-                        .write_error(format!("No solution file found in the current directory."))?;
-
-                    process::exit(1);
-                }
-            };
-        } else {
-            solution_file_path = self.solution_file_path.clone().unwrap();
-            if !&solution_file_path.exists() {
-                logger.write_error(format!(
-                    "The solution file path '{}' does not exist.",
-                    &solution_file_path.display()
-                ))?;
-
-                process::exit(1);
-            }
+        let (solution_file_path, solution_directory) =
+            resolve_solution_file_path(&self.solution_file_path)?;
+
+        if !self.project_path.exists() {
+            return Err(CliError::new(
+                format!(
+                    "The project path '{}' does not exist.",
+                    self.project_path.display()
+                )
+                .as_str(),
+                CliErrorKind::FilePathDoesNotExist
+            )
+            .into());
         }
 
-        if !&self.project_path.exists() {
-            logger.write_error(format!(
-                "The project path '{}' does not exist.",
-                &self.project_path.display()
-            ))?;
-
-            process::exit(1);
-        }
-
-        let project_friendly_name: String;
-
-        if self.project_friendly_name.is_none() {
-            project_friendly_name = match get_project_friendly_name(&self.project_path) {
-                Ok(name) => name,
-                Err(_) => {
-                    logger
-                        .write_error(format!("No project file found in the project directory."))?;
-
-                    process::exit(1);
-                }
-            };
-        } else {
-            project_friendly_name = self.project_friendly_name.clone().unwrap();
-        }
+        let project_friendly_name = match &self.project_friendly_name {
+            Some(name) => name.clone(),
+            None => get_project_friendly_name(&self.project_path).map_err(|_| {
+                CliError::new(
+                    "No project file found in the project directory.",
+                    CliErrorKind::InvalidProjectFile
+                )
+                .in_file(&self.project_path.to_string_lossy())
+            })?
+        };
 
         logger.write_operation_category("Add project")?;
         dotnet::add_project_to_solution(&solution_file_path, &self.project_path, logger)?;
         vscode_ops::csharp::add_csharp_project_to_tasks(
-            &PathBuf::from(solution_file_path.parent().unwrap()),
+            &solution_directory,
             &self.project_path,
             &project_friendly_name,
             self.is_runnable,
@@ -107,38 +95,90 @@ impl ConfiguratorSubcommandArgs for AddCommandArgs {
             logger
         )?;
 
+        if self.is_runnable {
+            vscode_ops::csharp::add_csharp_project_to_launch(
+                &solution_directory,
+                &self.project_path,
+                &project_friendly_name,
+                logger
+            )?;
+        }
+
+        let csharp_lsp = vscode_ops::csharp::read_csharp_lsp(&solution_directory)?;
+        vscode_ops::csharp::update_csharp_extension_recommendations(
+            &solution_directory,
+            csharp_lsp,
+            logger
+        )?;
+
         Ok(())
     }
 }
 
 /// Gets the default value for the `solution_file_path` (`--solution-file-path`)
-/// argument if it is not provided by the user.
-fn get_solution_file_path_default_value() -> Result<PathBuf, ErrorKind> {
-    let current_dir = env::current_dir().unwrap();
-
-    for file_item in current_dir.read_dir().unwrap() {
-        let file_item = file_item.unwrap();
+/// argument if it is not provided by the user, which is the only solution file
+/// in the current directory.
+pub(super) fn get_solution_file_path_default_value() -> Result<PathBuf, Box<dyn Error>> {
+    solution::find_solution_file(&env::current_dir()?)
+}
 
-        let file_path = file_item.path();
+/// Gets the solution file to use and the directory it's in. The solution file
+/// is the one provided by the user, or the only solution file in the current
+/// directory.
+pub(super) fn resolve_solution_file_path(
+    solution_file_path: &Option<PathBuf>
+) -> Result<(PathBuf, PathBuf), Box<dyn Error>> {
+    let solution_file_path = match solution_file_path {
+        Some(solution_file_path) => solution_file_path.clone(),
+        None => return get_solution_file_path_default_value().and_then(with_directory)
+    };
+
+    with_directory(solution_file_path)
+}
 
-        if file_path.extension().is_none() {
-            continue;
-        }
+/// Pairs a solution file path with the directory it's in, which is the current
+/// directory for a bare file name.
+fn with_directory(solution_file_path: PathBuf) -> Result<(PathBuf, PathBuf), Box<dyn Error>> {
+    let solution_directory = match solution_file_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => env::current_dir()?
+    };
+
+    if !solution_directory.is_dir() {
+        return Err(CliError::new(
+            format!(
+                "The directory of the solution file, '{}', does not exist.",
+                solution_directory.display()
+            )
+            .as_str(),
+            CliErrorKind::OutputDirectoryDoesNotExist
+        )
+        .into());
+    }
 
-        if file_path.extension().unwrap() == "sln" {
-            return Ok(PathBuf::from(file_path));
-        }
+    if !solution_file_path.is_file() {
+        return Err(CliError::new(
+            format!(
+                "The solution file path '{}' does not exist.",
+                solution_file_path.display()
+            )
+            .as_str(),
+            CliErrorKind::FilePathDoesNotExist
+        )
+        .into());
     }
 
-    Err(ErrorKind::NotFound)
+    Ok((solution_file_path, solution_directory))
 }
 
 /// Gets the friendly name of the project, if it is not provided by the user.
-fn get_project_friendly_name(project_path: &PathBuf) -> Result<String, ErrorKind> {
-    for file_item in project_path.read_dir().unwrap() {
-        let file_item = file_item.unwrap();
+pub(super) fn get_project_friendly_name(project_path: &Path) -> Result<String, ErrorKind> {
+    let file_items = project_path
+        .read_dir()
+        .map_err(|e| e.kind())?;
 
-        let file_path = file_item.path();
+    for file_item in file_items {
+        let file_path = file_item.map_err(|e| e.kind())?.path();
 
         if file_path.extension().is_none() {
             continue;
@@ -1,15 +1,19 @@
 use clap::{builder::TypedValueParser, Args, ValueHint};
 use vscodeconfigurator_lib::{
     error::{CliError, CliErrorKind},
-    external_procs::{dotnet, git},
     io::OutputDirectory,
     lang_options::CsharpLspOption,
     logging::ConsoleLogger,
-    template_ops,
+    template_ops::pack::TemplatePack,
     vscode_ops
 };
 
-use crate::subcommands::ConfiguratorSubcommandArgs;
+use crate::subcommands::{
+    apply_force_option,
+    create_output_directory,
+    ConfiguratorSubcommandArgs,
+    TemplateVarsArgs
+};
 
 /// Defines the arguments for the `csharp init` command and the logic to run the
 /// command.
@@ -62,7 +66,15 @@ pub struct InitCommandArgs {
     )]
     csharp_lsp: CsharpLspOption,
 
-    /// Force the command to run without prompting for confirmation.
+    /// Add a dev container to the project.
+    #[arg(long = "add-devcontainer", required = false, default_value = "false")]
+    add_devcontainer: bool,
+
+    #[command(flatten)]
+    template_vars: TemplateVarsArgs,
+
+    /// Overwrite existing files without asking. Same as `--on-conflict
+    /// overwrite`.
     #[arg(short = 'f', long = "force", required = false, default_value = "false")]
     force: bool
 }
@@ -72,13 +84,15 @@ impl ConfiguratorSubcommandArgs for InitCommandArgs {
         &self,
         logger: &mut ConsoleLogger
     ) -> Result<(), Box<dyn std::error::Error>> {
+        apply_force_option(self.force, logger);
+
         let mut output_directory = self.output_directory.clone();
 
         output_directory = output_directory
             .resolve_home_dir()?
             .trim_trailing_slashes()?;
 
-        output_directory.create_if_not_exists()?;
+        create_output_directory(&output_directory, logger)?;
 
         let output_directory_absolute = output_directory.to_absolute();
 
@@ -94,61 +108,34 @@ impl ConfiguratorSubcommandArgs for InitCommandArgs {
 
         let solution_name = parsed_solution_name.unwrap();
 
-        logger.write_operation_category("Basic")?;
-        dotnet::add_dotnet_globaljson(&output_directory_absolute, self.force, logger)?;
-        logger.write_newline()?;
-
-        logger.write_operation_category("Git")?;
-        git::initialize_git_repo(&output_directory_absolute, logger)?;
-        dotnet::add_dotnet_gitignore(&output_directory_absolute, self.force, logger)?;
-        logger.write_newline()?;
-
-        logger.write_operation_category(".NET")?;
-        dotnet::initalize_dotnet_solution(
-            &output_directory_absolute,
-            &solution_name,
-            self.force,
-            logger
-        )?;
-        dotnet::add_dotnet_buildprops(&output_directory_absolute, self.force, logger)?;
-
-        if self.add_nuget_config {
-            dotnet::add_dotnet_nugetconfig(&output_directory_absolute, self.force, logger)?;
-        }
+        let template_context = self
+            .template_vars
+            .to_template_context(&output_directory_absolute)?
+            .with("solutionName", solution_name)
+            .with("addGitversion", self.add_gitversion)
+            .with("addNugetConfig", self.add_nuget_config)
+            .with(
+                "enableCentrallyManagedPackages",
+                self.enable_centrally_managed_packages
+            )
+            .with(
+                "useOmnisharp",
+                self.csharp_lsp == CsharpLspOption::OmniSharp
+            );
 
-        if self.enable_centrally_managed_packages {
-            dotnet::add_dotnet_packagesprops(&output_directory_absolute, self.force, logger)?;
-        }
+        let template_pack = TemplatePack::load("csharp", &output_directory_absolute)?;
 
-        logger.write_newline()?;
+        template_pack.run(&output_directory_absolute, &template_context, logger)?;
 
-        if self.add_gitversion {
-            logger.write_operation_category("GitVersion")?;
-            dotnet::add_dotnet_tool(&output_directory_absolute, "GitVersion.Tool", logger)?;
-            template_ops::csharp::csharp_copy_gitversion(
+        if self.add_devcontainer {
+            vscode_ops::devcontainer::add_csharp_devcontainer(
                 &output_directory_absolute,
-                self.force,
+                &template_context,
+                self.csharp_lsp,
                 logger
             )?;
-            logger.write_newline()?;
         }
 
-        logger.write_operation_category("VSCode")?;
-        template_ops::csharp::csharp_copy_vscode_settings(
-            &output_directory_absolute,
-            &solution_name,
-            self.force,
-            logger
-        )?;
-        vscode_ops::csharp::update_csharp_lsp(&output_directory_absolute, self.csharp_lsp, logger)?;
-        template_ops::csharp::csharp_copy_vscode_tasks(
-            &output_directory_absolute,
-            &solution_name,
-            self.force,
-            logger
-        )?;
-        logger.write_newline()?;
-
         logger.write_project_initialized_log()?;
 
         Ok(())
@@ -165,10 +152,7 @@ impl InitCommandArgs {
 
                 let output_directory_name = output_directory_pathbuf.file_name();
 
-                match output_directory_name {
-                    Some(name) => Some(name.to_string_lossy().to_string()),
-                    None => None
-                }
+                output_directory_name.map(|name| name.to_string_lossy().to_string())
             }
 
             false => Some(self.solution_name.as_ref().unwrap().clone())
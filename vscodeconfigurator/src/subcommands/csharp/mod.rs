@@ -1,13 +1,19 @@
 mod add;
 mod init;
+mod remove;
+mod sync;
 
 use std::error::Error;
 
 use clap::Subcommand;
 use vscodeconfigurator_lib::logging::ConsoleLogger;
 
-use self::{add::AddCommandArgs, init::InitCommandArgs};
-
+use self::{
+    add::AddCommandArgs,
+    init::InitCommandArgs,
+    remove::RemoveCommandArgs,
+    sync::SyncCommandArgs
+};
 use super::{ConfiguratorSubcommand, ConfiguratorSubcommandArgs};
 
 /// Subcommands for C# projects.
@@ -29,7 +35,21 @@ pub enum CsharpSubcommands {
         about = "Add a new project to a C# solution.",
         long_about = None
     )]
-    Add(AddCommandArgs)
+    Add(AddCommandArgs),
+
+    /// Remove a project from a C# solution.
+    #[command(
+        about = "Remove a project from a C# solution.",
+        long_about = None
+    )]
+    Remove(RemoveCommandArgs),
+
+    /// Sync the projects in tasks.json with a C# solution.
+    #[command(
+        about = "Sync the projects in tasks.json with the projects in a C# solution.",
+        long_about = None
+    )]
+    Sync(SyncCommandArgs)
 }
 
 impl ConfiguratorSubcommand for CsharpSubcommands {
@@ -40,7 +60,13 @@ impl ConfiguratorSubcommand for CsharpSubcommands {
         match self {
             CsharpSubcommands::Init(init_args) => InitCommandArgs::run_command(init_args, logger)?,
 
-            CsharpSubcommands::Add(add_args) => AddCommandArgs::run_command(add_args, logger)?
+            CsharpSubcommands::Add(add_args) => AddCommandArgs::run_command(add_args, logger)?,
+
+            CsharpSubcommands::Remove(remove_args) => {
+                RemoveCommandArgs::run_command(remove_args, logger)?
+            }
+
+            CsharpSubcommands::Sync(sync_args) => SyncCommandArgs::run_command(sync_args, logger)?
         };
 
         Ok(())
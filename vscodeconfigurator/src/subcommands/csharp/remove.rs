@@ -0,0 +1,105 @@
+use std::{env, path::PathBuf};
+
+use clap::{Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    external_procs::dotnet,
+    logging::ConsoleLogger,
+    vscode_ops
+};
+
+use super::add::{get_project_friendly_name, resolve_solution_file_path};
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `csharp remove` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct RemoveCommandArgs {
+    /// The solution file to remove the project from.
+    #[arg(
+        long = "solution-file-path",
+        required = false,
+        value_hint = ValueHint::FilePath
+    )]
+    solution_file_path: Option<PathBuf>,
+
+    /// The path to the project.
+    #[arg(
+        long = "project-path",
+        required = true,
+        value_hint = ValueHint::AnyPath
+    )]
+    project_path: PathBuf,
+
+    /// The friendly name the project was added with. Defaults to its label in
+    /// `.vscode/tasks.json`.
+    #[arg(
+        long = "project-friendly-name",
+        required = false,
+        value_hint = ValueHint::Other
+    )]
+    project_friendly_name: Option<String>
+}
+
+impl ConfiguratorSubcommandArgs for RemoveCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (solution_file_path, solution_directory) =
+            resolve_solution_file_path(&self.solution_file_path)?;
+
+        // The project may have been deleted already, so its friendly name is
+        // looked up in tasks.json before falling back to its project file.
+        let project_friendly_name = match &self.project_friendly_name {
+            Some(name) => name.clone(),
+            None => match vscode_ops::csharp::read_csharp_project_friendly_name(
+                &solution_directory,
+                &self.project_path
+            )? {
+                Some(name) => name,
+                None => self
+                    .project_path
+                    .is_dir()
+                    .then(|| get_project_friendly_name(&self.project_path).ok())
+                    .flatten()
+                    .or_else(|| {
+                        self.project_path
+                            .file_stem()
+                            .map(|name| name.to_string_lossy().to_string())
+                    })
+                    .ok_or_else(|| {
+                        CliError::new(
+                            format!(
+                                "The friendly name of '{}' couldn't be found. Use '--project-friendly-name' to provide it.",
+                                self.project_path.display()
+                            )
+                            .as_str(),
+                            CliErrorKind::InvalidProjectFile
+                        )
+                    })?
+            }
+        };
+
+        let project_file_path = match self.project_path.is_absolute() {
+            true => self.project_path.clone(),
+            false => env::current_dir()?.join(&self.project_path)
+        };
+
+        logger.write_operation_category("Remove project")?;
+        dotnet::remove_project_from_solution(&solution_file_path, &project_file_path, logger)?;
+        vscode_ops::csharp::remove_csharp_project_from_tasks(
+            &solution_directory,
+            &self.project_path,
+            &project_friendly_name,
+            logger
+        )?;
+        vscode_ops::csharp::remove_csharp_project_from_launch(
+            &solution_directory,
+            &project_friendly_name,
+            logger
+        )?;
+
+        Ok(())
+    }
+}
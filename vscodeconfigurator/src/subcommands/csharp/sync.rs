@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    logging::ConsoleLogger,
+    vscode_ops
+};
+
+use super::add::resolve_solution_file_path;
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `csharp sync` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct SyncCommandArgs {
+    /// The solution file to sync the projects from.
+    #[arg(
+        long = "solution-file-path",
+        required = false,
+        value_hint = ValueHint::FilePath
+    )]
+    solution_file_path: Option<PathBuf>,
+
+    /// Only check whether tasks.json is in sync, and fail if it isn't.
+    #[arg(long = "check", required = false, default_value = "false")]
+    check: bool
+}
+
+impl ConfiguratorSubcommandArgs for SyncCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (solution_file_path, solution_directory) =
+            resolve_solution_file_path(&self.solution_file_path)?;
+
+        let projects = vscode_ops::csharp::read_csharp_solution_projects(&solution_file_path)?;
+
+        logger.write_operation_category("Sync projects")?;
+        let in_sync = vscode_ops::csharp::sync_csharp_projects_in_tasks(
+            &solution_directory,
+            &projects,
+            self.check,
+            logger
+        )?;
+
+        if self.check && !in_sync {
+            return Err(CliError::new(
+                "The projects in tasks.json don't match the solution. Run 'csharp sync' to update them.",
+                CliErrorKind::VSCodeFileOutOfSync
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    io::OutputDirectory,
+    lang_options::{CsharpLspOption, LanguageOption},
+    logging::ConsoleLogger,
+    template_ops::capture::CapturedProjectKind,
+    vscode_ops
+};
+
+use crate::subcommands::{apply_force_option, ConfiguratorSubcommandArgs, TemplateVarsArgs};
+
+/// Defines the arguments for the `devcontainer add` command and the logic to
+/// run the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct DevcontainerAddCommandArgs {
+    /// The output directory of the project.
+    #[arg(
+        short = 'o',
+        long = "output-directory",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    output_directory: OutputDirectory,
+
+    /// The language of the project.
+    ///
+    /// If not provided, it's detected from the project's `Cargo.toml` or
+    /// solution file.
+    #[arg(long = "language", required = false, value_enum)]
+    language: Option<LanguageOption>,
+
+    /// The C# language server to recommend extensions for.
+    ///
+    /// If not provided, the one in `.vscode/settings.json` is used.
+    #[arg(long = "csharp-lsp", required = false, value_enum)]
+    csharp_lsp: Option<CsharpLspOption>,
+
+    #[command(flatten)]
+    template_vars: TemplateVarsArgs,
+
+    /// Overwrite existing files without asking. Same as `--on-conflict
+    /// overwrite`.
+    #[arg(short = 'f', long = "force", required = false, default_value = "false")]
+    force: bool
+}
+
+impl ConfiguratorSubcommandArgs for DevcontainerAddCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        apply_force_option(self.force, logger);
+
+        let mut output_directory = self.output_directory.clone();
+
+        output_directory = output_directory
+            .resolve_home_dir()?
+            .trim_trailing_slashes()?;
+
+        if !output_directory.as_pathbuf().exists() {
+            return Err(CliError::new(
+                "The specified output directory does not exist.",
+                CliErrorKind::OutputDirectoryDoesNotExist
+            )
+            .into());
+        }
+
+        let output_directory_absolute = output_directory.to_absolute();
+
+        let language = match self.language {
+            Some(language) => language,
+            None => match CapturedProjectKind::detect(&output_directory_absolute) {
+                Some(CapturedProjectKind::Rust) => LanguageOption::Rust,
+                Some(CapturedProjectKind::CSharp) => LanguageOption::Csharp,
+                None => {
+                    return Err(CliError::new(
+                        "The language of the project could not be detected. Use '--language' to set it.",
+                        CliErrorKind::UnsupportedProjectLanguage
+                    )
+                    .into())
+                }
+            }
+        };
+
+        let template_context = self
+            .template_vars
+            .to_template_context(&output_directory_absolute)?;
+
+        match language {
+            LanguageOption::Rust => vscode_ops::devcontainer::add_rust_devcontainer(
+                &output_directory_absolute,
+                &template_context,
+                logger
+            )?,
+
+            LanguageOption::Csharp => {
+                let csharp_lsp = match self.csharp_lsp {
+                    Some(csharp_lsp) => csharp_lsp,
+                    None => vscode_ops::csharp::read_csharp_lsp(&output_directory_absolute)?
+                };
+
+                vscode_ops::devcontainer::add_csharp_devcontainer(
+                    &output_directory_absolute,
+                    &template_context,
+                    csharp_lsp,
+                    logger
+                )?
+            }
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+mod add;
+
+use std::error::Error;
+
+use clap::Subcommand;
+use vscodeconfigurator_lib::logging::ConsoleLogger;
+
+use self::add::DevcontainerAddCommandArgs;
+use crate::subcommands::{ConfiguratorSubcommand, ConfiguratorSubcommandArgs};
+
+/// Subcommands for dev containers.
+#[derive(Subcommand, Debug, PartialEq)]
+#[command(
+    about = "Commands for adding dev containers to projects.",
+    arg_required_else_help = true
+)]
+pub enum DevcontainerSubcommands {
+    /// Add a dev container to a project.
+    #[command(about = "Add a dev container to a project.", long_about = None)]
+    Add(DevcontainerAddCommandArgs)
+}
+
+impl ConfiguratorSubcommand for DevcontainerSubcommands {
+    fn match_subcommand(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            DevcontainerSubcommands::Add(add_args) => add_args.run_command(logger)?
+        };
+
+        Ok(())
+    }
+}
@@ -1,11 +1,28 @@
 pub mod csharp;
+pub mod devcontainer;
 pub mod rust;
+pub mod template;
+pub mod workspace;
 
-use clap::{Args, Subcommand};
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand, ValueHint};
 use clap_complete::Shell;
 use csharp::CsharpSubcommands;
+use devcontainer::DevcontainerSubcommands;
 use rust::RustSubcommands;
-use vscodeconfigurator_lib::logging::ConsoleLogger;
+use serde_json::Value;
+use template::TemplateSubcommands;
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    io::{describe_planned_write, OutputDirectory},
+    logging::{ConflictPolicy, ConsoleLogger, OutputEmoji},
+    template_ops::{
+        engine::TemplateContext,
+        variables::{builtin_variables, load_vars_file, parse_var_assignment}
+    }
+};
+use workspace::WorkspaceSubcommands;
 
 /// The root subcommands for the CLI.
 #[derive(Subcommand, Debug, PartialEq)]
@@ -16,12 +33,31 @@ pub enum RootSubcommands {
         command: Option<CsharpSubcommands>
     },
 
+    /// Subcommands for dev containers.
+    Devcontainer {
+        #[command(subcommand)]
+        command: Option<DevcontainerSubcommands>
+    },
+
     /// Subcommands for Rust projects.
     Rust {
         #[command(subcommand)]
         command: Option<RustSubcommands>
     },
 
+    /// Subcommands for templates.
+    #[command(visible_alias = "templates")]
+    Template {
+        #[command(subcommand)]
+        command: Option<TemplateSubcommands>
+    },
+
+    /// Subcommands for multi-root workspaces.
+    Workspace {
+        #[command(subcommand)]
+        command: Option<WorkspaceSubcommands>
+    },
+
     /// Generate completion scripts for the shell of your choice.
     Completions(Completions)
 }
@@ -33,6 +69,126 @@ pub struct Completions {
     pub shell: Shell
 }
 
+/// Arguments for supplying custom values to templates.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateVarsArgs {
+    /// Set a template variable. Can be used multiple times.
+    #[arg(
+        long = "var",
+        value_name = "KEY=VALUE",
+        required = false,
+        value_parser = parse_var_assignment,
+        value_hint = ValueHint::Other
+    )]
+    vars: Vec<(String, Value)>,
+
+    /// A TOML file with template variables.
+    #[arg(long = "vars-file", required = false, value_hint = ValueHint::FilePath)]
+    vars_file: Option<PathBuf>
+}
+
+impl TemplateVarsArgs {
+    /// Creates the template context for a project.
+    ///
+    /// The built-in variables are overridden by the variables file, which are
+    /// overridden by the `--var` arguments.
+    ///
+    /// # Arguments
+    ///
+    /// - `output_directory` - The output directory of the project.
+    pub fn to_template_context(
+        &self,
+        output_directory: &Path
+    ) -> Result<TemplateContext, Box<dyn std::error::Error>> {
+        let mut template_context = builtin_variables(output_directory);
+
+        if let Some(vars_file) = &self.vars_file {
+            template_context.extend(load_vars_file(vars_file)?);
+        }
+
+        for (name, value) in &self.vars {
+            template_context.set(name, value.clone());
+        }
+
+        Ok(template_context)
+    }
+}
+
+/// Creates the output directory of a project if it doesn't exist.
+///
+/// On a dry run, the directory isn't created and the log says that it would
+/// be.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the project.
+/// - `logger` - The
+///   [`ConsoleLogger`](vscodeconfigurator_lib::logging::ConsoleLogger) to use
+///   for logging.
+pub fn create_output_directory(
+    output_directory: &OutputDirectory,
+    logger: &mut ConsoleLogger
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !logger.dry_run {
+        output_directory.create_if_not_exists()?;
+
+        return Ok(());
+    }
+
+    let output_directory_absolute = output_directory.to_absolute();
+
+    if !output_directory_absolute.exists() {
+        logger.write_operation_log("Creating output directory...", OutputEmoji::Folder)?;
+        logger.write_dry_run_log(&[describe_planned_write(
+            &output_directory_absolute,
+            logger.on_conflict
+        )])?;
+        logger.write_newline()?;
+    }
+
+    Ok(())
+}
+
+/// Makes the run overwrite existing files and directories without asking, for
+/// commands with a `--force` option.
+///
+/// # Arguments
+///
+/// - `force` - Whether `--force` was used.
+/// - `logger` - The
+///   [`ConsoleLogger`](vscodeconfigurator_lib::logging::ConsoleLogger) for the
+///   run.
+pub fn apply_force_option(
+    force: bool,
+    logger: &mut ConsoleLogger
+) {
+    if force {
+        logger.on_conflict = ConflictPolicy::Overwrite;
+    }
+}
+
+/// Returns an error if the run is a dry run, for commands that don't support
+/// `--dry-run`.
+///
+/// # Arguments
+///
+/// - `command_name` - The name of the command (e.g. `template add`).
+/// - `logger` - The
+///   [`ConsoleLogger`](vscodeconfigurator_lib::logging::ConsoleLogger) for the
+///   run.
+pub fn ensure_not_dry_run(
+    command_name: &str,
+    logger: &ConsoleLogger
+) -> Result<(), CliError> {
+    match logger.dry_run {
+        false => Ok(()),
+        true => Err(CliError::new(
+            format!("'{}' does not support '--dry-run'.", command_name).as_str(),
+            CliErrorKind::DryRunNotSupported
+        ))
+    }
+}
+
 /// A trait for subcommands used in the VSCode Configurator CLI.
 pub trait ConfiguratorSubcommand {
     /// Matches the subcommand provided by the user and runs the corresponding
@@ -62,7 +62,7 @@ impl ConfiguratorSubcommandArgs for RustAddCommandArgs {
         let output_directory_absolute = output_directory.to_absolute();
 
         let package_friendly_name = match &self.package_friendly_name {
-            Some(ref name) => &name.as_str(),
+            Some(ref name) => name.as_str(),
             None => self.package_name.as_str()
         };
 
@@ -73,6 +73,12 @@ impl ConfiguratorSubcommandArgs for RustAddCommandArgs {
             package_friendly_name,
             logger
         )?;
+        vscode_ops::rust::add_package_to_launch(
+            &output_directory_absolute,
+            self.package_name.as_str(),
+            logger
+        )?;
+        vscode_ops::rust::add_extension_recommendations(&output_directory_absolute, logger)?;
 
         Ok(())
     }
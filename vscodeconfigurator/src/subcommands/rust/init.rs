@@ -1,13 +1,18 @@
 use clap::{builder::TypedValueParser, Args, ValueHint};
 use vscodeconfigurator_lib::{
-    external_procs::{cargo, git},
     io::OutputDirectory,
     lang_options::CargoPackageTemplateOption,
     logging::ConsoleLogger,
-    template_ops
+    template_ops::pack::TemplatePack,
+    vscode_ops
 };
 
-use crate::subcommands::ConfiguratorSubcommandArgs;
+use crate::subcommands::{
+    apply_force_option,
+    create_output_directory,
+    ConfiguratorSubcommandArgs,
+    TemplateVarsArgs
+};
 
 /// Defines the arguments for the `rust init` command and the logic to run the
 /// command.
@@ -37,7 +42,15 @@ pub struct RustInitCommandArgs {
     )]
     base_package_template: CargoPackageTemplateOption,
 
-    /// Force the command to run without prompting for confirmation.
+    /// Add a dev container to the project.
+    #[arg(long = "add-devcontainer", required = false, default_value = "false")]
+    add_devcontainer: bool,
+
+    #[command(flatten)]
+    template_vars: TemplateVarsArgs,
+
+    /// Overwrite existing files without asking. Same as `--on-conflict
+    /// overwrite`.
     #[arg(short = 'f', long = "force", required = false, default_value = "false")]
     force: bool
 }
@@ -47,47 +60,48 @@ impl ConfiguratorSubcommandArgs for RustInitCommandArgs {
         &self,
         logger: &mut ConsoleLogger
     ) -> Result<(), Box<dyn std::error::Error>> {
+        apply_force_option(self.force, logger);
+
         let mut output_directory = self.output_directory.clone();
 
         output_directory = output_directory
             .resolve_home_dir()?
             .trim_trailing_slashes()?;
 
-        output_directory.create_if_not_exists()?;
+        create_output_directory(&output_directory, logger)?;
 
         let output_directory_absolute = output_directory.to_absolute();
 
-        logger.write_operation_category("Git")?;
-        git::initialize_git_repo(&output_directory_absolute, logger)?;
-        template_ops::rust::copy_gitignore(&output_directory_absolute, self.force, logger)?;
-        logger.write_newline()?;
+        let package_template = match self.base_package_template {
+            CargoPackageTemplateOption::Binary => "bin",
+            CargoPackageTemplateOption::Library => "lib"
+        };
+
+        let template_context = self
+            .template_vars
+            .to_template_context(&output_directory_absolute)?
+            .with("basePackageName", self.base_package_name.as_str())
+            .with("basePackageTemplate", package_template);
+
+        let template_pack = TemplatePack::load("rust", &output_directory_absolute)?;
+
+        template_pack.run(&output_directory_absolute, &template_context, logger)?;
 
         logger.write_operation_category("VSCode")?;
-        template_ops::rust::copy_vscode_settings(&output_directory_absolute, self.force, logger)?;
-        template_ops::rust::copy_vscode_tasks(
+        vscode_ops::rust::add_package_to_launch(
             &output_directory_absolute,
-            &self.base_package_name,
-            self.force,
+            self.base_package_name.as_str(),
             logger
         )?;
-        template_ops::rust::copy_build_pwsh_script(&output_directory_absolute, self.force, logger)?;
-        template_ops::rust::copy_clean_pwsh_script(&output_directory_absolute, self.force, logger)?;
-        logger.write_newline()?;
 
-        logger.write_operation_category("Rust")?;
-        template_ops::rust::copy_cargo_workspace_file(
-            &output_directory_absolute,
-            self.force,
-            logger
-        )?;
-        cargo::initalize_package(
-            &output_directory_absolute,
-            &self.base_package_name,
-            self.base_package_template,
-            self.force,
-            logger
-        )?;
-        logger.write_newline()?;
+        if self.add_devcontainer {
+            logger.write_newline()?;
+            vscode_ops::devcontainer::add_rust_devcontainer(
+                &output_directory_absolute,
+                &template_context,
+                logger
+            )?;
+        }
 
         logger.write_project_initialized_log()?;
 
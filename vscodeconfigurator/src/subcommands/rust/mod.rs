@@ -1,12 +1,19 @@
 mod add;
 mod init;
+mod remove;
+mod sync;
 
 use std::error::Error;
 
 use clap::Subcommand;
 use vscodeconfigurator_lib::logging::ConsoleLogger;
 
-use self::{add::RustAddCommandArgs, init::RustInitCommandArgs};
+use self::{
+    add::RustAddCommandArgs,
+    init::RustInitCommandArgs,
+    remove::RustRemoveCommandArgs,
+    sync::RustSyncCommandArgs
+};
 use crate::subcommands::{ConfiguratorSubcommand, ConfiguratorSubcommandArgs};
 
 /// Subcommands for Rust projects.
@@ -28,7 +35,21 @@ pub enum RustSubcommands {
         about = "Add a new package to a Rust project.",
         long_about = None
     )]
-    Add(RustAddCommandArgs)
+    Add(RustAddCommandArgs),
+
+    /// Remove a package from a Rust project.
+    #[command(
+        about = "Remove a package from a Rust project.",
+        long_about = None
+    )]
+    Remove(RustRemoveCommandArgs),
+
+    /// Sync the packages in tasks.json with the workspace.
+    #[command(
+        about = "Sync the packages in tasks.json with the packages in the workspace.",
+        long_about = None
+    )]
+    Sync(RustSyncCommandArgs)
 }
 
 impl ConfiguratorSubcommand for RustSubcommands {
@@ -39,7 +60,11 @@ impl ConfiguratorSubcommand for RustSubcommands {
         match self {
             RustSubcommands::Init(init_args) => init_args.run_command(logger)?,
 
-            RustSubcommands::Add(add_args) => add_args.run_command(logger)?
+            RustSubcommands::Add(add_args) => add_args.run_command(logger)?,
+
+            RustSubcommands::Remove(remove_args) => remove_args.run_command(logger)?,
+
+            RustSubcommands::Sync(sync_args) => sync_args.run_command(logger)?
         };
 
         Ok(())
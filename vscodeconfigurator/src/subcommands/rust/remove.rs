@@ -0,0 +1,125 @@
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    io::OutputDirectory,
+    logging::ConsoleLogger,
+    vscode_ops
+};
+
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `rust remove` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct RustRemoveCommandArgs {
+    /// The output directory for the project.
+    #[arg(
+        short = 'o',
+        long = "output-directory",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    output_directory: OutputDirectory,
+
+    /// The name of the package.
+    #[arg(
+        long = "package-name",
+        required = true,
+        value_hint = ValueHint::AnyPath
+    )]
+    package_name: String,
+
+    /// Remove the package from the workspace members in the root
+    /// `Cargo.toml`.
+    #[arg(long = "remove-from-workspace", required = false, default_value = "false")]
+    remove_from_workspace: bool,
+
+    /// Delete the package's directory.
+    #[arg(long = "delete-package", required = false, default_value = "false")]
+    delete_package: bool,
+
+    /// Delete the package's directory without asking.
+    #[arg(short = 'f', long = "force", required = false, default_value = "false")]
+    force: bool
+}
+
+impl ConfiguratorSubcommandArgs for RustRemoveCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut output_directory = self.output_directory.clone();
+
+        output_directory = output_directory
+            .resolve_home_dir()?
+            .trim_trailing_slashes()?;
+
+        if !output_directory.as_pathbuf().exists() {
+            return Err(CliError::new(
+                "The specified output directory does not exist.",
+                CliErrorKind::OutputDirectoryDoesNotExist
+            )
+            .into());
+        }
+
+        let output_directory_absolute = output_directory.to_absolute();
+
+        // Check the package directory before changing anything, so an invalid
+        // package name doesn't leave the package half removed.
+        let package_directory = match self.delete_package {
+            true => vscode_ops::rust::find_package_directory(
+                &output_directory_absolute,
+                self.package_name.as_str()
+            )?,
+            false => None
+        };
+
+        if let Some(package_directory) = package_directory {
+            if !self.force
+                && !logger.dry_run
+                && !logger.confirm(
+                    format!("Delete '{}' and everything in it?", package_directory.display())
+                        .as_str()
+                )?
+            {
+                return Err(CliError::new(
+                    "The package wasn't removed, since deleting it wasn't confirmed.",
+                    CliErrorKind::OperationCancelled
+                )
+                .into());
+            }
+        }
+
+        logger.write_operation_category("Remove package")?;
+        vscode_ops::rust::remove_package_from_tasks(
+            &output_directory_absolute,
+            self.package_name.as_str(),
+            logger
+        )?;
+        vscode_ops::rust::remove_package_from_launch(
+            &output_directory_absolute,
+            self.package_name.as_str(),
+            logger
+        )?;
+
+        if self.remove_from_workspace {
+            vscode_ops::rust::remove_package_from_workspace(
+                &output_directory_absolute,
+                self.package_name.as_str(),
+                logger
+            )?;
+        }
+
+        if self.delete_package {
+            vscode_ops::rust::delete_package(
+                &output_directory_absolute,
+                self.package_name.as_str(),
+                logger
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    external_procs::cargo,
+    io::OutputDirectory,
+    logging::ConsoleLogger,
+    vscode_ops
+};
+
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `rust sync` command and the logic to run the
+/// command.
+#[derive(Args, Debug, PartialEq)]
+pub struct RustSyncCommandArgs {
+    /// The output directory for the project.
+    #[arg(
+        short = 'o',
+        long = "output-directory",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    output_directory: OutputDirectory,
+
+    /// Only check whether tasks.json is in sync, and fail if it isn't.
+    #[arg(long = "check", required = false, default_value = "false")]
+    check: bool
+}
+
+impl ConfiguratorSubcommandArgs for RustSyncCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut output_directory = self.output_directory.clone();
+
+        output_directory = output_directory
+            .resolve_home_dir()?
+            .trim_trailing_slashes()?;
+
+        if !output_directory.as_pathbuf().exists() {
+            return Err(CliError::new(
+                "The specified output directory does not exist.",
+                CliErrorKind::OutputDirectoryDoesNotExist
+            )
+            .into());
+        }
+
+        let output_directory_absolute = output_directory.to_absolute();
+
+        let package_names = cargo::read_workspace_package_names(&output_directory_absolute)?;
+
+        logger.write_operation_category("Sync packages")?;
+        let in_sync = vscode_ops::rust::sync_packages_in_tasks(
+            &output_directory_absolute,
+            &package_names,
+            self.check,
+            logger
+        )?;
+
+        if self.check && !in_sync {
+            return Err(CliError::new(
+                "The packages in tasks.json don't match the workspace. Run 'rust sync' to update them.",
+                CliErrorKind::VSCodeFileOutOfSync
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
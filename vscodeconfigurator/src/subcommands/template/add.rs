@@ -0,0 +1,50 @@
+use clap::{Args, ValueHint};
+use vscodeconfigurator_lib::{
+    logging::ConsoleLogger,
+    template_ops::installed::InstalledTemplateSets
+};
+
+use super::format_pack_names;
+use crate::subcommands::{ensure_not_dry_run, ConfiguratorSubcommandArgs};
+
+/// Defines the arguments for the `template add` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateAddCommandArgs {
+    /// The path or `file://` URL of the template set (a directory or a Git
+    /// repository).
+    #[arg(required = true, value_hint = ValueHint::AnyPath)]
+    source: String,
+
+    /// The name to install the template set as.
+    ///
+    /// If not provided, the name of the source directory is used.
+    #[arg(short = 'n', long = "name", required = false, value_hint = ValueHint::Other)]
+    name: Option<String>
+}
+
+impl ConfiguratorSubcommandArgs for TemplateAddCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        ensure_not_dry_run("template add", logger)?;
+
+        let mut installed_sets = InstalledTemplateSets::load()?;
+
+        logger.write_operation_category("Template sets")?;
+
+        let installed_set = installed_sets.add(&self.source, self.name.as_deref(), logger)?;
+
+        logger.write_newline()?;
+
+        logger.write_info(format!(
+            "Installed template set '{}' ({}) with packs: {}\n",
+            installed_set.name,
+            installed_set.version(),
+            format_pack_names(&installed_set.pack_names())
+        ))?;
+
+        Ok(())
+    }
+}
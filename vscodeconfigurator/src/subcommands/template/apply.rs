@@ -0,0 +1,72 @@
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    io::OutputDirectory,
+    logging::ConsoleLogger,
+    template_ops::pack::TemplatePack
+};
+
+use crate::subcommands::{
+    apply_force_option,
+    create_output_directory,
+    ConfiguratorSubcommandArgs,
+    TemplateVarsArgs
+};
+
+/// Defines the arguments for the `template apply` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateApplyCommandArgs {
+    /// The name of the template pack (e.g. `rust`).
+    #[arg(required = true, value_hint = ValueHint::Other)]
+    pack_name: String,
+
+    /// The output directory for the project.
+    #[arg(
+        short = 'o',
+        long = "output-directory",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    output_directory: OutputDirectory,
+
+    #[command(flatten)]
+    template_vars: TemplateVarsArgs,
+
+    /// Overwrite existing files without asking. Same as `--on-conflict
+    /// overwrite`.
+    #[arg(short = 'f', long = "force", required = false, default_value = "false")]
+    force: bool
+}
+
+impl ConfiguratorSubcommandArgs for TemplateApplyCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        apply_force_option(self.force, logger);
+
+        let mut output_directory = self.output_directory.clone();
+
+        output_directory = output_directory
+            .resolve_home_dir()?
+            .trim_trailing_slashes()?;
+
+        create_output_directory(&output_directory, logger)?;
+
+        let output_directory_absolute = output_directory.to_absolute();
+
+        let template_context = self
+            .template_vars
+            .to_template_context(&output_directory_absolute)?;
+
+        let template_pack = TemplatePack::load(&self.pack_name, &output_directory_absolute)?;
+
+        template_pack.run(&output_directory_absolute, &template_context, logger)?;
+
+        logger.write_project_initialized_log()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    io::OutputDirectory,
+    logging::ConsoleLogger,
+    template_ops::{capture::capture_template_pack, search_path::get_user_templates_dir}
+};
+
+use crate::subcommands::{apply_force_option, ensure_not_dry_run, ConfiguratorSubcommandArgs};
+
+/// Defines the arguments for the `template capture` command and the logic to
+/// run the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateCaptureCommandArgs {
+    /// The root directory of the project to capture.
+    #[arg(
+        long = "from",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    from: OutputDirectory,
+
+    /// The name of the template pack to write (e.g. `rust`).
+    #[arg(short = 'n', long = "name", required = true, value_hint = ValueHint::Other)]
+    name: String,
+
+    /// The templates directory to write the pack to.
+    ///
+    /// If not provided, the user's templates directory is used.
+    #[arg(
+        short = 't',
+        long = "templates-directory",
+        required = false,
+        value_hint = ValueHint::DirPath
+    )]
+    templates_directory: Option<PathBuf>,
+
+    /// Overwrite existing files without asking. Same as `--on-conflict
+    /// overwrite`.
+    #[arg(short = 'f', long = "force", required = false, default_value = "false")]
+    force: bool
+}
+
+impl ConfiguratorSubcommandArgs for TemplateCaptureCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        apply_force_option(self.force, logger);
+
+        ensure_not_dry_run("template capture", logger)?;
+
+        let mut project_directory = self.from.clone();
+
+        project_directory = project_directory
+            .resolve_home_dir()?
+            .trim_trailing_slashes()?;
+
+        let templates_directory = match &self.templates_directory {
+            Some(templates_directory) => templates_directory.clone(),
+            None => match get_user_templates_dir() {
+                Some(user_templates_dir) => user_templates_dir,
+                None => {
+                    return Err(CliError::new(
+                        "The configuration directory could not be found.",
+                        CliErrorKind::UnsupportedOperatingSystem
+                    )
+                    .into())
+                }
+            }
+        };
+
+        let pack_directory = capture_template_pack(
+            &project_directory.to_absolute(),
+            &self.name,
+            &templates_directory,
+            logger
+        )?;
+
+        if let Some(pack_directory) = pack_directory {
+            logger.write_info(format!(
+                "Captured template pack '{}' to '{}'.\n",
+                self.name,
+                pack_directory.display()
+            ))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use clap::Args;
+use vscodeconfigurator_lib::{
+    logging::{ConsoleLogger, OutputEmoji},
+    template_ops::installed::InstalledTemplateSets
+};
+
+use super::format_pack_names;
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `template list` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateListCommandArgs {}
+
+impl ConfiguratorSubcommandArgs for TemplateListCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let installed_sets = InstalledTemplateSets::load()?;
+
+        if installed_sets.sets.is_empty() {
+            logger.write_warning("No template sets are installed.\n".to_string())?;
+            return Ok(());
+        }
+
+        logger.write_operation_category("Installed template sets")?;
+
+        for installed_set in &installed_sets.sets {
+            logger.write_operation_log(&installed_set.name, OutputEmoji::Package)?;
+            logger.write_newline()?;
+
+            logger.write_info(format!("    Version:   {}\n", installed_set.version()))?;
+            logger.write_info(format!(
+                "    Source:    {} ({})\n",
+                installed_set.source, installed_set.kind
+            ))?;
+            logger.write_info(format!(
+                "    Languages: {}\n",
+                format_pack_names(&installed_set.pack_names())
+            ))?;
+        }
+
+        logger.write_newline()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+mod add;
+mod apply;
+mod capture;
+mod list;
+mod remove;
+mod update;
+mod validate;
+mod which;
+
+use std::error::Error;
+
+use clap::Subcommand;
+use vscodeconfigurator_lib::logging::ConsoleLogger;
+
+use self::{
+    add::TemplateAddCommandArgs,
+    apply::TemplateApplyCommandArgs,
+    capture::TemplateCaptureCommandArgs,
+    list::TemplateListCommandArgs,
+    remove::TemplateRemoveCommandArgs,
+    update::TemplateUpdateCommandArgs,
+    validate::TemplateValidateCommandArgs,
+    which::TemplateWhichCommandArgs
+};
+use crate::subcommands::{ConfiguratorSubcommand, ConfiguratorSubcommandArgs};
+
+/// Subcommands for managing templates.
+#[derive(Subcommand, Debug, PartialEq)]
+#[command(
+    about = "Commands for inspecting and managing templates.",
+    arg_required_else_help = true
+)]
+pub enum TemplateSubcommands {
+    /// Install a template set from a local directory or Git repository.
+    #[command(
+        about = "Install a template set from a local directory or Git repository.",
+        long_about = None
+    )]
+    Add(TemplateAddCommandArgs),
+
+    /// Run a template pack in a project.
+    #[command(about = "Run a template pack in a project.", long_about = None)]
+    Apply(TemplateApplyCommandArgs),
+
+    /// Capture a project's `.vscode` files as a template pack.
+    #[command(
+        about = "Capture a project's `.vscode` files as a template pack.",
+        long_about = None
+    )]
+    Capture(TemplateCaptureCommandArgs),
+
+    /// List the installed template sets.
+    #[command(about = "List the installed template sets.", long_about = None)]
+    List(TemplateListCommandArgs),
+
+    /// Remove an installed template set.
+    #[command(about = "Remove an installed template set.", long_about = None)]
+    Remove(TemplateRemoveCommandArgs),
+
+    /// Update installed template sets from their source.
+    #[command(
+        about = "Update installed template sets from their source.",
+        long_about = None
+    )]
+    Update(TemplateUpdateCommandArgs),
+
+    /// Check a templates directory for problems.
+    #[command(about = "Check a templates directory for problems.", long_about = None)]
+    Validate(TemplateValidateCommandArgs),
+
+    /// Show which layer of the search path a template resolves from.
+    #[command(
+        about = "Show which layer of the search path a template resolves from.",
+        long_about = None
+    )]
+    Which(TemplateWhichCommandArgs)
+}
+
+impl ConfiguratorSubcommand for TemplateSubcommands {
+    fn match_subcommand(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            TemplateSubcommands::Add(add_args) => add_args.run_command(logger)?,
+            TemplateSubcommands::Apply(apply_args) => apply_args.run_command(logger)?,
+            TemplateSubcommands::Capture(capture_args) => capture_args.run_command(logger)?,
+            TemplateSubcommands::List(list_args) => list_args.run_command(logger)?,
+            TemplateSubcommands::Remove(remove_args) => remove_args.run_command(logger)?,
+            TemplateSubcommands::Update(update_args) => update_args.run_command(logger)?,
+            TemplateSubcommands::Validate(validate_args) => validate_args.run_command(logger)?,
+            TemplateSubcommands::Which(which_args) => which_args.run_command(logger)?
+        };
+
+        Ok(())
+    }
+}
+
+/// Formats the names of the packs in a template set for display.
+pub(super) fn format_pack_names(pack_names: &[String]) -> String {
+    match pack_names.is_empty() {
+        true => "(none)".to_string(),
+        false => pack_names.join(", ")
+    }
+}
@@ -0,0 +1,35 @@
+use clap::{Args, ValueHint};
+use vscodeconfigurator_lib::{
+    logging::ConsoleLogger,
+    template_ops::installed::InstalledTemplateSets
+};
+
+use crate::subcommands::{ensure_not_dry_run, ConfiguratorSubcommandArgs};
+
+/// Defines the arguments for the `template remove` command and the logic to
+/// run the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateRemoveCommandArgs {
+    /// The name of the template set to remove.
+    #[arg(required = true, value_hint = ValueHint::Other)]
+    name: String
+}
+
+impl ConfiguratorSubcommandArgs for TemplateRemoveCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        ensure_not_dry_run("template remove", logger)?;
+
+        let mut installed_sets = InstalledTemplateSets::load()?;
+
+        logger.write_operation_category("Template sets")?;
+
+        installed_sets.remove(&self.name, logger)?;
+
+        logger.write_newline()?;
+
+        Ok(())
+    }
+}
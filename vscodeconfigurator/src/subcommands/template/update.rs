@@ -0,0 +1,53 @@
+use clap::{Args, ValueHint};
+use vscodeconfigurator_lib::{
+    logging::ConsoleLogger,
+    template_ops::installed::InstalledTemplateSets
+};
+
+use crate::subcommands::{ensure_not_dry_run, ConfiguratorSubcommandArgs};
+
+/// Defines the arguments for the `template update` command and the logic to
+/// run the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateUpdateCommandArgs {
+    /// The name of the template set to update.
+    ///
+    /// If not provided, every installed template set is updated.
+    #[arg(required = false, value_hint = ValueHint::Other)]
+    name: Option<String>
+}
+
+impl ConfiguratorSubcommandArgs for TemplateUpdateCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        ensure_not_dry_run("template update", logger)?;
+
+        let mut installed_sets = InstalledTemplateSets::load()?;
+
+        let set_names = match &self.name {
+            Some(name) => vec![name.clone()],
+            None => installed_sets
+                .sets
+                .iter()
+                .map(|set| set.name.clone())
+                .collect()
+        };
+
+        if set_names.is_empty() {
+            logger.write_warning("No template sets are installed.\n".to_string())?;
+            return Ok(());
+        }
+
+        logger.write_operation_category("Template sets")?;
+
+        for set_name in set_names {
+            installed_sets.update(&set_name, logger)?;
+        }
+
+        logger.write_newline()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    logging::{ConsoleLogger, OutputEmoji},
+    template_ops::validate::validate_templates_dir
+};
+
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `template validate` command and the logic to
+/// run the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateValidateCommandArgs {
+    /// The templates directory to validate.
+    #[arg(required = true, value_hint = ValueHint::DirPath)]
+    templates_dir: PathBuf
+}
+
+impl ConfiguratorSubcommandArgs for TemplateValidateCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let pack_validations = validate_templates_dir(&self.templates_dir)?;
+
+        logger.write_operation_category(
+            format!("Validating '{}'", self.templates_dir.display()).as_str()
+        )?;
+
+        let mut problem_count = 0;
+
+        for pack_validation in &pack_validations {
+            logger.write_operation_log(
+                format!("Checking the '{}' pack...", pack_validation.name).as_str(),
+                OutputEmoji::Package
+            )?;
+
+            if pack_validation.problems.is_empty() {
+                logger.write_operation_success_log()?;
+                continue;
+            }
+
+            logger.write_error(format!(
+                "{} problem(s) {}\n",
+                pack_validation.problems.len(),
+                OutputEmoji::Stop
+            ))?;
+
+            for problem in &pack_validation.problems {
+                logger.write_error(format!("    {}\n", problem))?;
+            }
+
+            problem_count += pack_validation.problems.len();
+        }
+
+        logger.write_newline()?;
+
+        if problem_count > 0 {
+            return Err(CliError::new(
+                format!(
+                    "Found {} problem(s) in '{}'.",
+                    problem_count,
+                    self.templates_dir.display()
+                )
+                .as_str(),
+                CliErrorKind::TemplateValidationFailed
+            )
+            .into());
+        }
+
+        logger.write_success(format!("No problems found {}\n", OutputEmoji::CheckMark))?;
+
+        Ok(())
+    }
+}
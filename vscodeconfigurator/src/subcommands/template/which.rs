@@ -0,0 +1,96 @@
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    io::OutputDirectory,
+    logging::{ConsoleLogger, OutputEmoji},
+    template_ops::{search_path::TemplateSearchPath, TemplateSource}
+};
+
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `template which` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct TemplateWhichCommandArgs {
+    /// The relative path to the template (e.g. `rust/VSCode/settings.json`).
+    #[arg(required = true, value_hint = ValueHint::Other)]
+    relative_path: String,
+
+    /// The directory of the project to resolve the template for.
+    #[arg(
+        short = 'o',
+        long = "output-directory",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    output_directory: OutputDirectory
+}
+
+impl ConfiguratorSubcommandArgs for TemplateWhichCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut output_directory = self.output_directory.clone();
+
+        output_directory = output_directory
+            .resolve_home_dir()?
+            .trim_trailing_slashes()?;
+
+        let relative_path = self.relative_path.trim_start_matches("./");
+
+        let search_path = TemplateSearchPath::new(&output_directory.to_absolute());
+
+        logger.write_operation_category("Search path")?;
+
+        for layer in &search_path.layers {
+            let layer_location = match &layer.source {
+                TemplateSource::Embedded => format!("[{}]", layer.kind),
+                TemplateSource::Directory(templates_dir) => {
+                    format!("[{}] {}", layer.kind, templates_dir.display())
+                }
+            };
+
+            logger.write_operation_log(&layer_location, OutputEmoji::Folder)?;
+
+            match layer.contains(relative_path) {
+                true => logger.write_success("Found\n".to_string())?,
+                false => logger.write_warning("Not found\n".to_string())?
+            };
+        }
+
+        logger.write_newline()?;
+
+        let resolved_layer = match search_path.resolve(relative_path) {
+            Some(layer) => layer,
+            None => {
+                return Err(CliError::new(
+                    format!(
+                        "The template '{}' was not found in any layer of the search path.",
+                        relative_path
+                    )
+                    .as_str(),
+                    CliErrorKind::TemplateNotFound
+                )
+                .into())
+            }
+        };
+
+        let resolved_location = match &resolved_layer.source {
+            TemplateSource::Embedded => relative_path.to_string(),
+            TemplateSource::Directory(templates_dir) => templates_dir
+                .join(relative_path)
+                .to_string_lossy()
+                .to_string()
+        };
+
+        logger.write_info(format!(
+            "'{}' resolves from the {} layer: {}\n",
+            relative_path, resolved_layer.kind, resolved_location
+        ))?;
+
+        Ok(())
+    }
+}
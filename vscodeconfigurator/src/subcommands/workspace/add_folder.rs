@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    io::OutputDirectory,
+    logging::ConsoleLogger,
+    vscode_ops::{self, workspace::WorkspaceFolder}
+};
+
+use super::resolve_workspace_file;
+use crate::subcommands::ConfiguratorSubcommandArgs;
+
+/// Defines the arguments for the `workspace add-folder` command and the logic
+/// to run the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct WorkspaceAddFolderCommandArgs {
+    /// The path of the folder, relative to the workspace file.
+    #[arg(required = true, value_hint = ValueHint::DirPath)]
+    path: String,
+
+    /// The name of the folder shown in the explorer.
+    #[arg(long = "name", required = false, value_hint = ValueHint::Other)]
+    name: Option<String>,
+
+    /// The output directory of the workspace.
+    #[arg(
+        short = 'o',
+        long = "output-directory",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    output_directory: OutputDirectory,
+
+    /// The workspace file to change, relative to the output directory.
+    ///
+    /// If not provided, the only `.code-workspace` file in the output
+    /// directory is used.
+    #[arg(
+        long = "workspace-file",
+        required = false,
+        value_hint = ValueHint::FilePath
+    )]
+    workspace_file: Option<PathBuf>
+}
+
+impl ConfiguratorSubcommandArgs for WorkspaceAddFolderCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let workspace_file_path =
+            resolve_workspace_file(&self.output_directory, &self.workspace_file)?;
+
+        logger.write_operation_category("Workspace")?;
+        vscode_ops::workspace::add_folder_to_workspace(
+            &workspace_file_path,
+            WorkspaceFolder::new(&self.path, self.name.as_deref()),
+            logger
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use clap::{builder::TypedValueParser, Args, ValueHint};
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    io::OutputDirectory,
+    logging::ConsoleLogger,
+    vscode_ops::{self, workspace::WorkspaceFolder}
+};
+
+use crate::subcommands::{create_output_directory, ConfiguratorSubcommandArgs};
+
+/// Defines the arguments for the `workspace init` command and the logic to run
+/// the command.
+#[derive(Args, Debug, PartialEq)]
+pub struct WorkspaceInitCommandArgs {
+    /// The output directory for the workspace file.
+    #[arg(
+        short = 'o',
+        long = "output-directory",
+        required = false,
+        value_parser = clap::builder::OsStringValueParser::new().map(|s| OutputDirectory::from_os_string(s).unwrap()),
+        default_value = OutputDirectory::from_current_dir(),
+        value_hint = ValueHint::DirPath
+    )]
+    output_directory: OutputDirectory,
+
+    /// The name of the workspace file, without `.code-workspace`.
+    ///
+    /// If not provided, the name of the output directory is used.
+    #[arg(
+        short = 'n',
+        long = "name",
+        required = false,
+        value_hint = ValueHint::Other
+    )]
+    name: Option<String>,
+
+    /// A folder to add to the workspace, relative to the output directory. Can
+    /// be used multiple times.
+    ///
+    /// If not provided, the output directory itself is added.
+    #[arg(long = "folder", required = false, value_hint = ValueHint::DirPath)]
+    folders: Vec<String>
+}
+
+impl ConfiguratorSubcommandArgs for WorkspaceInitCommandArgs {
+    fn run_command(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut output_directory = self.output_directory.clone();
+
+        output_directory = output_directory
+            .resolve_home_dir()?
+            .trim_trailing_slashes()?;
+
+        create_output_directory(&output_directory, logger)?;
+
+        let output_directory_absolute = output_directory.to_absolute();
+
+        let workspace_name = match &self.name {
+            Some(name) => name.clone(),
+            None => match output_directory_absolute.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => {
+                    return Err(CliError::new(
+                        "The workspace name could not be determined. Use '--name' to set it.",
+                        CliErrorKind::InvalidVSCodeFile
+                    )
+                    .into())
+                }
+            }
+        };
+
+        let folders = match self.folders.is_empty() {
+            true => vec![WorkspaceFolder::new(".", None)],
+            false => self
+                .folders
+                .iter()
+                .map(|folder| WorkspaceFolder::new(folder, None))
+                .collect()
+        };
+
+        logger.write_operation_category("Workspace")?;
+        vscode_ops::workspace::init_workspace(
+            &output_directory_absolute,
+            &workspace_name,
+            &folders,
+            logger
+        )?;
+
+        logger.write_project_initialized_log()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+mod add_folder;
+mod init;
+mod remove_folder;
+
+use std::{error::Error, path::PathBuf};
+
+use clap::Subcommand;
+use vscodeconfigurator_lib::{
+    error::{CliError, CliErrorKind},
+    io::OutputDirectory,
+    logging::ConsoleLogger,
+    vscode_ops::workspace::find_workspace_file
+};
+
+use self::{
+    add_folder::WorkspaceAddFolderCommandArgs,
+    init::WorkspaceInitCommandArgs,
+    remove_folder::WorkspaceRemoveFolderCommandArgs
+};
+use crate::subcommands::{ConfiguratorSubcommand, ConfiguratorSubcommandArgs};
+
+/// Subcommands for multi-root workspaces.
+#[derive(Subcommand, Debug, PartialEq)]
+#[command(
+    about = "Commands for creating and managing multi-root workspace files.",
+    arg_required_else_help = true
+)]
+pub enum WorkspaceSubcommands {
+    /// Initialize a `.code-workspace` file.
+    #[command(about = "Initialize a `.code-workspace` file.", long_about = None)]
+    Init(WorkspaceInitCommandArgs),
+
+    /// Add a folder to a `.code-workspace` file.
+    #[command(about = "Add a folder to a `.code-workspace` file.", long_about = None)]
+    AddFolder(WorkspaceAddFolderCommandArgs),
+
+    /// Remove a folder from a `.code-workspace` file.
+    #[command(
+        about = "Remove a folder from a `.code-workspace` file.",
+        long_about = None
+    )]
+    RemoveFolder(WorkspaceRemoveFolderCommandArgs)
+}
+
+impl ConfiguratorSubcommand for WorkspaceSubcommands {
+    fn match_subcommand(
+        &self,
+        logger: &mut ConsoleLogger
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            WorkspaceSubcommands::Init(init_args) => init_args.run_command(logger)?,
+            WorkspaceSubcommands::AddFolder(add_folder_args) => {
+                add_folder_args.run_command(logger)?
+            }
+            WorkspaceSubcommands::RemoveFolder(remove_folder_args) => {
+                remove_folder_args.run_command(logger)?
+            }
+        };
+
+        Ok(())
+    }
+}
+
+/// Gets the workspace file a command works on, which is the one given with
+/// `--workspace-file` or the only `.code-workspace` file in the output
+/// directory.
+///
+/// # Arguments
+///
+/// - `output_directory` - The output directory of the workspace.
+/// - `workspace_file` - The workspace file given with `--workspace-file`.
+pub(super) fn resolve_workspace_file(
+    output_directory: &OutputDirectory,
+    workspace_file: &Option<PathBuf>
+) -> Result<PathBuf, Box<dyn Error>> {
+    let mut output_directory = output_directory.clone();
+
+    output_directory = output_directory
+        .resolve_home_dir()?
+        .trim_trailing_slashes()?;
+
+    let output_directory_absolute = output_directory.to_absolute();
+
+    if !output_directory_absolute.exists() {
+        return Err(CliError::new(
+            "The specified output directory does not exist.",
+            CliErrorKind::OutputDirectoryDoesNotExist
+        )
+        .into());
+    }
+
+    match workspace_file {
+        Some(workspace_file) => Ok(output_directory_absolute.join(workspace_file)),
+        None => find_workspace_file(&output_directory_absolute)
+    }
+}